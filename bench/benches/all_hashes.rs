@@ -0,0 +1,113 @@
+//! Runs every algorithm through `Digest::digest` on the same inputs and
+//! prints a summary table once Criterion is done.
+use blake2::Blake2b;
+use criterion::measurement::WallTime;
+use criterion::{black_box, BenchmarkGroup, BenchmarkId, Criterion, Throughput};
+use groestl::Groestl256;
+use md5::Md5;
+use ripemd160::Ripemd160;
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
+use sha3::Sha3_256;
+use sm3::Sm3;
+use std::time::{Duration, Instant};
+use tiger::Tiger;
+
+const SIZES: [(usize, &str); 4] = [
+    (64, "64 B"),
+    (1 << 10, "1 KiB"),
+    (64 << 10, "64 KiB"),
+    (1 << 20, "1 MiB"),
+];
+
+type Bench = fn(&mut BenchmarkGroup<'_, WallTime>, &str, &[u8]) -> Option<f64>;
+
+const ALGORITHMS: [(&str, Bench); 10] = [
+    ("SHA-1", bench::<Sha1>),
+    ("SHA-256", bench::<Sha256>),
+    ("SHA-512", bench::<Sha512>),
+    ("SHA3-256", bench::<Sha3_256>),
+    ("BLAKE2b-512", bench::<Blake2b>),
+    ("RIPEMD-160", bench::<Ripemd160>),
+    ("MD5", bench::<Md5>),
+    ("SM3", bench::<Sm3>),
+    ("Tiger", bench::<Tiger>),
+    ("Groestl-256", bench::<Groestl256>),
+];
+
+/// Benchmark `D` on `data` and return the mean time per digest in
+/// nanoseconds, or `None` if the benchmark was filtered out.
+fn bench<D: Digest>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    name: &str,
+    data: &[u8],
+) -> Option<f64> {
+    let mut iters = 0u64;
+    let mut elapsed = Duration::from_secs(0);
+    group.bench_function(BenchmarkId::from_parameter(name), |b| {
+        b.iter_custom(|n| {
+            let start = Instant::now();
+            for _ in 0..n {
+                black_box(D::digest(black_box(data)));
+            }
+            let t = start.elapsed();
+            iters += n;
+            elapsed += t;
+            t
+        })
+    });
+    if iters == 0 {
+        None
+    } else {
+        Some(elapsed.as_nanos() as f64 / iters as f64)
+    }
+}
+
+fn format_cell(ns: Option<f64>, size: usize) -> String {
+    let ns = match ns {
+        Some(ns) => ns,
+        None => return "-".into(),
+    };
+    let gibs = size as f64 / ns * 1e9 / (1u64 << 30) as f64;
+    let time = if ns < 1e3 {
+        format!("{:.1} ns", ns)
+    } else if ns < 1e6 {
+        format!("{:.2} µs", ns / 1e3)
+    } else {
+        format!("{:.2} ms", ns / 1e6)
+    };
+    format!("{} ({:.2} GiB/s)", time, gibs)
+}
+
+fn print_table(results: &[[Option<f64>; SIZES.len()]]) {
+    let mut header = format!("| {:<12} |", "Algorithm");
+    let mut rule = format!("|{:-<14}|", "");
+    for (_, label) in SIZES.iter() {
+        header += &format!(" {:>22} |", label);
+        rule += &format!("{:-<23}:|", "");
+    }
+    println!("\n{}\n{}", header, rule);
+    for ((name, _), row) in ALGORITHMS.iter().zip(results) {
+        let mut line = format!("| {:<12} |", name);
+        for (ns, (size, _)) in row.iter().zip(SIZES.iter()) {
+            line += &format!(" {:>22} |", format_cell(*ns, *size));
+        }
+        println!("{}", line);
+    }
+}
+
+fn main() {
+    let mut c = Criterion::default().configure_from_args();
+    let mut results = vec![[None; SIZES.len()]; ALGORITHMS.len()];
+    for (i, &(size, label)) in SIZES.iter().enumerate() {
+        let data = vec![0x5a; size];
+        let mut group = c.benchmark_group(label);
+        group.throughput(Throughput::Bytes(size as u64));
+        for (row, (name, bench)) in results.iter_mut().zip(ALGORITHMS.iter()) {
+            row[i] = bench(&mut group, name, &data);
+        }
+        group.finish();
+    }
+    c.final_summary();
+    print_table(&results);
+}
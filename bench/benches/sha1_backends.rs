@@ -0,0 +1,115 @@
+//! SHA-1 throughput per backend at 64 B, 512 B, 4 KiB, 64 KiB and 1 MiB.
+//!
+//! The backend is selected at compile time by crate features and, on x86
+//! and aarch64, at run time by CPU feature detection. Run the benchmark once
+//! per configuration and compare the results:
+//!
+//! | Command                                                                      | Backend                                           |
+//! |------------------------------------------------------------------------------|---------------------------------------------------|
+//! | `cargo bench -p hashes-bench --bench sha1_backends`                          | SHA-NI if detected, else soft                     |
+//! | `cargo bench -p hashes-bench --bench sha1_backends --features sha1-soft`     | portable Rust (`soft`)                            |
+//! | `cargo bench -p hashes-bench --bench sha1_backends --features sha1-asm`      | SHA-NI or SHA1 if detected, else `sha1-asm`       |
+//! | `cargo bench -p hashes-bench --bench sha1_backends --features sha1-aarch64`  | SHA1 intrinsics on aarch64 if detected, else soft |
+//! | `cargo bench -p hashes-bench --bench sha1_backends --features sha1-compress` | as default, plus `compress4x`                     |
+//!
+//! The `compress4x` benchmarks hash four independent streams of blocks at
+//! once with SSE2 on x86-64; their throughput covers all four streams.
+//! Building with `RUSTFLAGS="-C target-feature=+sha,+sse4.1"` enables the
+//! SHA-NI path statically, skipping run time detection.
+//!
+//! The detected CPU features and the expected backend are printed before
+//! the first benchmark runs.
+use criterion::{black_box, Criterion, Throughput};
+use sha1::{Digest, Sha1};
+
+const SIZES: [(usize, &str); 5] = [
+    (64, "64 B"),
+    (512, "512 B"),
+    (4 << 10, "4 KiB"),
+    (64 << 10, "64 KiB"),
+    (1 << 20, "1 MiB"),
+];
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn report_backend() {
+    let sha = is_x86_feature_detected!("sha")
+        && is_x86_feature_detected!("sse2")
+        && is_x86_feature_detected!("ssse3")
+        && is_x86_feature_detected!("sse4.1");
+    let sse2 = is_x86_feature_detected!("sse2");
+    println!("CPU features: sha={} sse2={}", sha, sse2);
+    let backend = if cfg!(feature = "sha1-soft") {
+        "soft (forced)"
+    } else if sha {
+        "SHA-NI"
+    } else if cfg!(feature = "sha1-asm") {
+        "sha1-asm"
+    } else {
+        "soft"
+    };
+    println!("SHA-1 backend: {}", backend);
+}
+
+#[cfg(target_arch = "aarch64")]
+fn report_backend() {
+    let sha1 = std::arch::is_aarch64_feature_detected!("sha2");
+    println!("CPU features: sha1={}", sha1);
+    let backend = if cfg!(feature = "sha1-soft") {
+        "soft (forced)"
+    } else if sha1 && cfg!(feature = "sha1-aarch64") {
+        "SHA1 intrinsics"
+    } else if sha1 && cfg!(feature = "sha1-asm") {
+        "sha1-asm"
+    } else {
+        "soft"
+    };
+    println!("SHA-1 backend: {}", backend);
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+fn report_backend() {
+    let backend = if cfg!(feature = "sha1-soft") {
+        "soft (forced)"
+    } else {
+        "target default"
+    };
+    println!("SHA-1 backend: {}", backend);
+}
+
+fn bench_digest(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sha1/digest");
+    for &(size, label) in SIZES.iter() {
+        let data = vec![0x5a; size];
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_function(label, |b| b.iter(|| Sha1::digest(black_box(&data))));
+    }
+    group.finish();
+}
+
+#[cfg(all(feature = "sha1-compress", target_arch = "x86_64"))]
+fn bench_compress4x(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sha1/compress4x");
+    for &(size, label) in [SIZES[0], SIZES[2], SIZES[4]].iter() {
+        let blocks = vec![[[0x5a; 64]; 4]; size / 64];
+        let mut states = [[0u32; 5]; 4];
+        group.throughput(Throughput::Bytes(4 * size as u64));
+        group.bench_function(label, |b| {
+            b.iter(|| {
+                for block in blocks.iter() {
+                    sha1::compress4x(&mut states, block);
+                }
+                black_box(&states);
+            })
+        });
+    }
+    group.finish();
+}
+
+fn main() {
+    report_backend();
+    let mut c = Criterion::default().configure_from_args();
+    bench_digest(&mut c);
+    #[cfg(all(feature = "sha1-compress", target_arch = "x86_64"))]
+    bench_compress4x(&mut c);
+    c.final_summary();
+}
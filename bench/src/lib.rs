@@ -0,0 +1,18 @@
+//! Throughput comparison of the hash crates in this workspace.
+//!
+//! `benches/all_hashes.rs` hashes the same 64 B, 1 KiB, 64 KiB and
+//! 1 MiB inputs with each algorithm through `Digest::digest` and prints a
+//! table of mean times and GiB/s once all measurements are done:
+//!
+//! ```text
+//! cargo bench -p hashes-bench
+//! ```
+//!
+//! Criterion's usual filters apply, e.g. `cargo bench -p hashes-bench -- SHA`.
+//!
+//! `benches/sha1_backends.rs` compares the SHA-1 backends, which are chosen
+//! with the `sha1-*` features of this crate:
+//!
+//! ```text
+//! cargo bench -p hashes-bench --bench sha1_backends --features sha1-soft
+//! ```
@@ -1,8 +1,8 @@
 macro_rules! blake2_impl {
     (
         $state:ident, $fix_state:ident, $word:ident, $vec:ident, $bytes:ident,
-        $block_size:ident, $R1:expr, $R2:expr, $R3:expr, $R4:expr, $IV:expr,
-        $vardoc:expr, $doc:expr,
+        $block_size:ident, $R1:expr, $R2:expr, $R3:expr, $R4:expr, $IV:expr, $accel:path,
+        $var_alg_name:expr, $alg_name:expr, $vardoc:expr, $doc:expr,
     ) => {
         use $crate::as_bytes::AsBytes;
         use $crate::simd::{$vec, Vector4};
@@ -23,10 +23,13 @@ macro_rules! blake2_impl {
             h: [$vec; 2],
             t: u64,
             n: usize,
+            // `m` holds input which has not been compressed yet
+            pending: bool,
 
             h0: [$vec; 2],
             m0: [$word; 16],
             t0: u64,
+            pending0: bool,
         }
 
         #[inline(always)]
@@ -72,6 +75,9 @@ macro_rules! blake2_impl {
         }
 
         impl $state {
+            /// Canonical name of the algorithm, e.g. for logging.
+            pub const ALG_NAME: &'static str = $var_alg_name;
+
             /// Creates a new hashing context with a key.
             ///
             /// **WARNING!** If you plan to use it for variable output MAC, then
@@ -145,13 +151,55 @@ macro_rules! blake2_impl {
                 if kk > 0 {
                     copy(key, state.m.as_mut_bytes());
                     state.t = 2 * $bytes::to_u64();
+                    state.pending = true;
                 }
 
                 state.t0 = state.t;
                 state.m0 = state.m;
+                state.pending0 = state.pending;
                 state
             }
 
+            /// Creates a hashing context from a raw chaining value `h` after
+            /// `t` bytes of input have been compressed.
+            ///
+            /// Returns `None` if `t` is not a multiple of the block size, or if
+            /// `output_size` is zero or exceeds the maximum output size.
+            /// Resetting the returned context restores the given raw state.
+            pub fn from_raw_state(h: [$word; 8], t: u64, output_size: usize) -> Option<Self> {
+                let block = 2 * $bytes::to_u64();
+                if t % block != 0 || output_size == 0 || output_size > $bytes::to_usize() {
+                    return None;
+                }
+                let h = [
+                    $vec::new(h[0], h[1], h[2], h[3]),
+                    $vec::new(h[4], h[5], h[6], h[7]),
+                ];
+                Some($state {
+                    m: [0; 16],
+                    h,
+                    t,
+                    n: output_size,
+                    pending: false,
+
+                    h0: h,
+                    m0: [0; 16],
+                    t0: t,
+                    pending0: false,
+                })
+            }
+
+            /// Get the raw chaining value.
+            ///
+            /// The last block of input is compressed lazily, so the returned
+            /// value does not cover it.
+            pub fn get_state(&self) -> [$word; 8] {
+                let h = &self.h;
+                [
+                    h[0].0, h[0].1, h[0].2, h[0].3, h[1].0, h[1].1, h[1].2, h[1].3,
+                ]
+            }
+
             #[doc(hidden)]
             pub fn with_parameter_block(p: &[$word; 8]) -> Self {
                 let nn = p[0] as u8 as usize;
@@ -169,10 +217,12 @@ macro_rules! blake2_impl {
                     h: h0,
                     t: 0,
                     n: nn,
+                    pending: false,
 
                     t0: 0,
                     m0: [0; 16],
                     h0,
+                    pending0: false,
                 }
             }
 
@@ -183,7 +233,7 @@ macro_rules! blake2_impl {
                 let block = 2 * $bytes::to_usize();
 
                 let off = self.t as usize % block;
-                if off != 0 || self.t == 0 {
+                if off != 0 || !self.pending {
                     let len = cmp::min(block - off, rest.len());
 
                     let part = &rest[..len];
@@ -194,6 +244,7 @@ macro_rules! blake2_impl {
                         .t
                         .checked_add(part.len() as u64)
                         .expect("hash data length overflow");
+                    self.pending |= len > 0;
                 }
 
                 while rest.len() >= block {
@@ -221,7 +272,10 @@ macro_rules! blake2_impl {
                 }
             }
 
-            #[doc(hidden)]
+            /// Retrieves the result with the last node flag set, as needed
+            /// for the last node of each level in tree hashing mode.
+            ///
+            /// The result is not truncated to the configured output size.
             pub fn finalize_last_node(mut self) -> Output {
                 self.finalize_with_flag(!0)
             }
@@ -254,6 +308,10 @@ macro_rules! blake2_impl {
                     _ => unreachable!(),
                 };
 
+                if $accel(h, m, t0, t1, f0, f1) {
+                    return;
+                }
+
                 let mut v = [h[0], h[1], iv0(), iv1() ^ $vec::new(t0, t1, f0, f1)];
 
                 round(&mut v, m, &SIGMA[0]);
@@ -276,6 +334,12 @@ macro_rules! blake2_impl {
             }
         }
 
+        impl core::fmt::Display for $state {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Self::ALG_NAME)
+            }
+        }
+
         impl Default for $state {
             fn default() -> Self {
                 Self::new_keyed(&[], $bytes::to_usize())
@@ -316,6 +380,7 @@ macro_rules! blake2_impl {
                 self.t = self.t0;
                 self.m = self.m0;
                 self.h = self.h0;
+                self.pending = self.pending0;
             }
         }
 
@@ -329,6 +394,9 @@ macro_rules! blake2_impl {
         }
 
         impl $fix_state {
+            /// Canonical name of the algorithm, e.g. for logging.
+            pub const ALG_NAME: &'static str = $alg_name;
+
             /// Creates a new hashing context with the full set of sequential-mode parameters.
             pub fn with_params(key: &[u8], salt: &[u8], persona: &[u8]) -> Self {
                 let state = $state::with_params(key, salt, persona, $bytes::to_usize());
@@ -336,6 +404,12 @@ macro_rules! blake2_impl {
             }
         }
 
+        impl core::fmt::Display for $fix_state {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Self::ALG_NAME)
+            }
+        }
+
         impl Default for $fix_state {
             fn default() -> Self {
                 let state = $state::new_keyed(&[], $bytes::to_usize());
@@ -412,3 +486,11 @@ macro_rules! blake2_impl {
         }
     };
 }
+
+/// Accelerated compression hook for `blake2_impl!` which never applies.
+// unused if both BLAKE2b and BLAKE2s have a backend for the target
+#[allow(dead_code)]
+#[inline(always)]
+pub(crate) fn no_accel<V, W>(_: &mut [V; 2], _: &[W; 16], _: W, _: W, _: W, _: W) -> bool {
+    false
+}
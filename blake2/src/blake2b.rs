@@ -1,6 +1,24 @@
 use crate::consts::BLAKE2B_IV;
 use digest::generic_array::typenum::{U128, U64};
 
+// There is deliberately no AVX2 backend: a single BLAKE2b stream is bound by
+// the latency of the G function, and keeping a whole row in one `__m256i`
+// turned out slower than the portable code because of the message gathers.
+#[cfg(not(all(
+    feature = "neon",
+    target_arch = "aarch64",
+    target_feature = "neon",
+    target_endian = "little"
+)))]
+use crate::blake2::no_accel as accel;
+#[cfg(all(
+    feature = "neon",
+    target_arch = "aarch64",
+    target_feature = "neon",
+    target_endian = "little"
+))]
+use crate::neon_b::compress as accel;
+
 blake2_impl!(
     VarBlake2b,
     Blake2b,
@@ -13,6 +31,9 @@ blake2_impl!(
     16,
     63,
     BLAKE2B_IV,
+    accel,
+    "BLAKE2b",
+    "BLAKE2b-512",
     "Blake2b instance with a variable output.",
     "Blake2b instance with a fixed output.",
 );
@@ -1,6 +1,26 @@
 use crate::consts::BLAKE2S_IV;
 use digest::generic_array::typenum::{U32, U64};
 
+#[cfg(not(any(
+    all(feature = "sse41", any(target_arch = "x86", target_arch = "x86_64")),
+    all(
+        feature = "neon",
+        target_arch = "aarch64",
+        target_feature = "neon",
+        target_endian = "little"
+    )
+)))]
+use crate::blake2::no_accel as accel;
+#[cfg(all(
+    feature = "neon",
+    target_arch = "aarch64",
+    target_feature = "neon",
+    target_endian = "little"
+))]
+use crate::neon_s::compress as accel;
+#[cfg(all(feature = "sse41", any(target_arch = "x86", target_arch = "x86_64")))]
+use crate::sse41_s::compress as accel;
+
 blake2_impl!(
     VarBlake2s,
     Blake2s,
@@ -13,6 +33,9 @@ blake2_impl!(
     8,
     7,
     BLAKE2S_IV,
+    accel,
+    "BLAKE2s",
+    "BLAKE2s-256",
     "Blake2s instance with a variable output.",
     "Blake2s instance with a fixed output.",
 );
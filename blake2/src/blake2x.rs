@@ -0,0 +1,202 @@
+//! BLAKE2Xb extendable-output function.
+use crate::VarBlake2b;
+use core::convert::TryInto;
+use digest::{ExtendableOutputDirty, Reset, Update, VariableOutputDirty, XofReader};
+
+/// Output length (in bytes) of the root hash and of every output block.
+const BLOCK_LEN: usize = 64;
+
+/// BLAKE2Xb hasher, as specified in the [BLAKE2X paper][1].
+///
+/// The message is absorbed by a BLAKE2b-512 root hash whose parameter block
+/// carries the requested output length. Output is then produced in 64-byte
+/// blocks, block `i` being an unkeyed BLAKE2b hash of the root digest with
+/// node offset `i`, so reading can continue indefinitely up to the
+/// configured length.
+///
+/// ```rust
+/// use blake2::Blake2bX;
+/// use blake2::digest::{ExtendableOutput, Update, XofReader};
+///
+/// let mut hasher = Blake2bX::new(128);
+/// hasher.update(b"hello world");
+/// let mut reader = hasher.finalize_xof();
+/// let mut buf = [0u8; 128];
+/// reader.read(&mut buf[..128]);
+/// ```
+///
+/// [1]: https://blake2.net/blake2x.pdf
+#[derive(Clone)]
+pub struct Blake2bX {
+    root: VarBlake2b,
+    root0: VarBlake2b,
+    xof_len: u32,
+    salt_persona: [u64; 4],
+}
+
+impl Blake2bX {
+    /// Output length value signalling that the length is not known in
+    /// advance. Up to 2^32 output blocks can be read in this mode.
+    pub const UNKNOWN_LENGTH: u32 = !0;
+
+    /// Creates a new unkeyed hasher producing `xof_len` bytes of output, or
+    /// an output of unknown length if `xof_len` is [`Self::UNKNOWN_LENGTH`].
+    ///
+    /// # Panics
+    ///
+    /// If `xof_len` is zero.
+    pub fn new(xof_len: u32) -> Self {
+        Self::with_params(&[], &[], &[], xof_len)
+    }
+
+    /// Creates a new hasher with the given key, salt and personalization.
+    ///
+    /// # Panics
+    ///
+    /// If `xof_len` is zero, the key is longer than 64 bytes, or the salt or
+    /// persona are longer than 16 bytes.
+    pub fn with_params(key: &[u8], salt: &[u8], persona: &[u8], xof_len: u32) -> Self {
+        assert!(xof_len != 0, "output length must not be zero");
+        assert!(key.len() <= BLOCK_LEN);
+        assert!(salt.len() <= 16);
+        assert!(persona.len() <= 16);
+
+        let mut salt_persona = [0u64; 4];
+        salt_persona[..2].copy_from_slice(&pad_words(salt));
+        salt_persona[2..].copy_from_slice(&pad_words(persona));
+
+        let mut p = [0u64; 8];
+        p[0] = 0x0101_0000 ^ ((key.len() as u64) << 8) ^ (BLOCK_LEN as u64);
+        p[1] = u64::from(xof_len) << 32;
+        p[4..].copy_from_slice(&salt_persona);
+
+        let mut root = VarBlake2b::with_parameter_block(&p);
+        if !key.is_empty() {
+            let mut block = [0u8; 2 * BLOCK_LEN];
+            block[..key.len()].copy_from_slice(key);
+            root.update(&block[..]);
+        }
+
+        Self {
+            root0: root.clone(),
+            root,
+            xof_len,
+            salt_persona,
+        }
+    }
+}
+
+impl Default for Blake2bX {
+    fn default() -> Self {
+        Self::new(Self::UNKNOWN_LENGTH)
+    }
+}
+
+impl Update for Blake2bX {
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.root.update(data);
+    }
+}
+
+impl ExtendableOutputDirty for Blake2bX {
+    type Reader = Blake2bXofReader;
+
+    fn finalize_xof_dirty(&mut self) -> Self::Reader {
+        let mut h0 = [0u8; BLOCK_LEN];
+        self.root
+            .finalize_variable_dirty(|res| h0.copy_from_slice(res));
+        Blake2bXofReader {
+            h0,
+            xof_len: self.xof_len,
+            salt_persona: self.salt_persona,
+            node: 0,
+            block: [0; BLOCK_LEN],
+            pos: 0,
+            block_len: 0,
+        }
+    }
+}
+
+impl Reset for Blake2bX {
+    fn reset(&mut self) {
+        self.root = self.root0.clone();
+    }
+}
+
+opaque_debug::implement!(Blake2bX);
+digest::impl_write!(Blake2bX);
+
+/// Reader for the output of [`Blake2bX`].
+///
+/// Each output block is a BLAKE2b hash of the root digest at an incrementing
+/// node offset; blocks are computed lazily as they are read.
+#[derive(Clone)]
+pub struct Blake2bXofReader {
+    h0: [u8; BLOCK_LEN],
+    xof_len: u32,
+    salt_persona: [u64; 4],
+    node: u64,
+    block: [u8; BLOCK_LEN],
+    pos: usize,
+    block_len: usize,
+}
+
+impl Blake2bXofReader {
+    /// Computes the next output block.
+    fn read_block(&mut self) {
+        let len = if self.xof_len == Blake2bX::UNKNOWN_LENGTH {
+            assert!(self.node < 1 << 32, "BLAKE2X output limit exceeded");
+            BLOCK_LEN
+        } else {
+            let remaining = u64::from(self.xof_len).saturating_sub(self.node * BLOCK_LEN as u64);
+            assert!(remaining != 0, "BLAKE2X output length exceeded");
+            remaining.min(BLOCK_LEN as u64) as usize
+        };
+
+        let mut p = [0u64; 8];
+        p[0] = (len as u64) ^ ((BLOCK_LEN as u64) << 32);
+        p[1] = self.node ^ (u64::from(self.xof_len) << 32);
+        p[2] = (BLOCK_LEN as u64) << 8;
+        p[4..].copy_from_slice(&self.salt_persona);
+
+        let mut h = VarBlake2b::with_parameter_block(&p);
+        h.update(&self.h0[..]);
+        let block = &mut self.block;
+        h.finalize_variable_dirty(|res| block[..len].copy_from_slice(res));
+
+        self.node += 1;
+        self.pos = 0;
+        self.block_len = len;
+    }
+}
+
+impl XofReader for Blake2bXofReader {
+    /// Reads output bytes.
+    ///
+    /// # Panics
+    ///
+    /// If more bytes are read than the output length given to [`Blake2bX`].
+    fn read(&mut self, buffer: &mut [u8]) {
+        let mut buffer = buffer;
+        while !buffer.is_empty() {
+            if self.pos == self.block_len {
+                self.read_block();
+            }
+            let n = buffer.len().min(self.block_len - self.pos);
+            buffer[..n].copy_from_slice(&self.block[self.pos..self.pos + n]);
+            self.pos += n;
+            buffer = &mut buffer[n..];
+        }
+    }
+}
+
+opaque_debug::implement!(Blake2bXofReader);
+
+fn pad_words(bytes: &[u8]) -> [u64; 2] {
+    let mut padded = [0u8; 16];
+    padded[..bytes.len()].copy_from_slice(bytes);
+    [
+        u64::from_le_bytes(padded[..8].try_into().unwrap()),
+        u64::from_le_bytes(padded[8..].try_into().unwrap()),
+    ]
+}
@@ -0,0 +1,99 @@
+//! BLAKE2 instances with common fixed output sizes.
+use crate::{Blake2b, Blake2s, VarBlake2b, VarBlake2s};
+use digest::generic_array::typenum::{Unsigned, U128, U16, U20, U32, U48, U64};
+use digest::generic_array::GenericArray;
+use digest::{BlockInput, FixedOutputDirty, Reset, Update, VariableOutputDirty};
+
+/// BLAKE2b-512 hasher, i.e. [`Blake2b`].
+pub type Blake2b512 = Blake2b;
+/// BLAKE2s-256 hasher, i.e. [`Blake2s`].
+pub type Blake2s256 = Blake2s;
+
+macro_rules! blake2_fixed {
+    ($name:ident, $var:ident, $block_size:ident, $out_size:ident, $alg_name:expr, $doc:expr) => {
+        #[derive(Clone)]
+        #[doc=$doc]
+        pub struct $name {
+            state: $var,
+        }
+
+        impl $name {
+            /// Canonical name of the algorithm, e.g. for logging.
+            pub const ALG_NAME: &'static str = $alg_name;
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Self::ALG_NAME)
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                let state = $var::new_keyed(&[], $out_size::to_usize());
+                Self { state }
+            }
+        }
+
+        impl BlockInput for $name {
+            type BlockSize = $block_size;
+        }
+
+        impl Update for $name {
+            fn update(&mut self, data: impl AsRef<[u8]>) {
+                Update::update(&mut self.state, data);
+            }
+        }
+
+        impl FixedOutputDirty for $name {
+            type OutputSize = $out_size;
+
+            fn finalize_into_dirty(&mut self, out: &mut GenericArray<u8, $out_size>) {
+                self.state
+                    .finalize_variable_dirty(|res| out.copy_from_slice(res));
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                self.state.reset()
+            }
+        }
+
+        opaque_debug::implement!($name);
+        digest::impl_write!($name);
+    };
+}
+
+blake2_fixed!(
+    Blake2b256,
+    VarBlake2b,
+    U128,
+    U32,
+    "BLAKE2b-256",
+    "BLAKE2b-256 hasher, i.e. BLAKE2b with a 32 byte output."
+);
+blake2_fixed!(
+    Blake2b384,
+    VarBlake2b,
+    U128,
+    U48,
+    "BLAKE2b-384",
+    "BLAKE2b-384 hasher, i.e. BLAKE2b with a 48 byte output."
+);
+blake2_fixed!(
+    Blake2s128,
+    VarBlake2s,
+    U64,
+    U16,
+    "BLAKE2s-128",
+    "BLAKE2s-128 hasher, i.e. BLAKE2s with a 16 byte output."
+);
+blake2_fixed!(
+    Blake2s160,
+    VarBlake2s,
+    U64,
+    U20,
+    "BLAKE2s-160",
+    "BLAKE2s-160 hasher, i.e. BLAKE2s with a 20 byte output."
+);
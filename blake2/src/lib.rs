@@ -96,6 +96,7 @@ extern crate std;
 
 mod as_bytes;
 mod consts;
+mod fixed;
 
 mod simd;
 
@@ -104,9 +105,32 @@ mod blake2;
 
 mod blake2b;
 mod blake2s;
+mod blake2x;
+#[cfg(all(
+    feature = "neon",
+    target_arch = "aarch64",
+    target_feature = "neon",
+    target_endian = "little"
+))]
+mod neon_b;
+#[cfg(all(
+    feature = "neon",
+    target_arch = "aarch64",
+    target_feature = "neon",
+    target_endian = "little"
+))]
+mod neon_s;
+#[cfg(all(feature = "sse41", any(target_arch = "x86", target_arch = "x86_64")))]
+mod sse41_s;
+mod tree;
 
 pub use crypto_mac;
 pub use digest::{self, Digest};
 
 pub use crate::blake2b::{Blake2b, VarBlake2b};
 pub use crate::blake2s::{Blake2s, VarBlake2s};
+pub use crate::blake2x::{Blake2bX, Blake2bXofReader};
+pub use crate::fixed::{Blake2b256, Blake2b384, Blake2b512, Blake2s128, Blake2s160, Blake2s256};
+#[cfg(feature = "std")]
+pub use crate::tree::blake2b_tree_hash;
+pub use crate::tree::Blake2bTree;
@@ -0,0 +1,178 @@
+//! BLAKE2s compression using ARM NEON.
+//!
+//! Each row of the 4×4 working matrix fits into one `uint32x4_t`, so the
+//! column and diagonal steps of a round each run as a single G function
+//! over four registers, with `vext` rotating the rows in between. Only
+//! available on little-endian `aarch64`, where NEON is part of the baseline
+//! and its intrinsics are stable (Rust 1.59+).
+#![allow(unsafe_code)]
+
+use crate::consts::{BLAKE2S_IV, SIGMA};
+use crate::simd::u32x4;
+use core::arch::aarch64::*;
+
+#[inline(always)]
+unsafe fn load(x: [u32; 4]) -> uint32x4_t {
+    vld1q_u32(x.as_ptr())
+}
+
+#[inline(always)]
+unsafe fn gather(m: &[u32; 16], i0: usize, i1: usize, i2: usize, i3: usize) -> uint32x4_t {
+    load([m[i0], m[i1], m[i2], m[i3]])
+}
+
+#[inline(always)]
+unsafe fn rotr16(x: uint32x4_t) -> uint32x4_t {
+    vreinterpretq_u32_u16(vrev32q_u16(vreinterpretq_u16_u32(x)))
+}
+
+#[inline(always)]
+unsafe fn rotr12(x: uint32x4_t) -> uint32x4_t {
+    vsriq_n_u32::<12>(vshlq_n_u32::<20>(x), x)
+}
+
+#[inline(always)]
+unsafe fn rotr8(x: uint32x4_t) -> uint32x4_t {
+    vsriq_n_u32::<8>(vshlq_n_u32::<24>(x), x)
+}
+
+#[inline(always)]
+unsafe fn rotr7(x: uint32x4_t) -> uint32x4_t {
+    vsriq_n_u32::<7>(vshlq_n_u32::<25>(x), x)
+}
+
+#[inline(always)]
+unsafe fn g(v: &mut [uint32x4_t; 4], m0: uint32x4_t, m1: uint32x4_t) {
+    v[0] = vaddq_u32(vaddq_u32(v[0], v[1]), m0);
+    v[3] = rotr16(veorq_u32(v[3], v[0]));
+    v[2] = vaddq_u32(v[2], v[3]);
+    v[1] = rotr12(veorq_u32(v[1], v[2]));
+    v[0] = vaddq_u32(vaddq_u32(v[0], v[1]), m1);
+    v[3] = rotr8(veorq_u32(v[3], v[0]));
+    v[2] = vaddq_u32(v[2], v[3]);
+    v[1] = rotr7(veorq_u32(v[1], v[2]));
+}
+
+#[inline(always)]
+unsafe fn round(v: &mut [uint32x4_t; 4], m: &[u32; 16], s: &[usize; 16]) {
+    g(
+        v,
+        gather(m, s[0], s[2], s[4], s[6]),
+        gather(m, s[1], s[3], s[5], s[7]),
+    );
+    v[1] = vextq_u32::<1>(v[1], v[1]);
+    v[2] = vextq_u32::<2>(v[2], v[2]);
+    v[3] = vextq_u32::<3>(v[3], v[3]);
+    g(
+        v,
+        gather(m, s[8], s[10], s[12], s[14]),
+        gather(m, s[9], s[11], s[13], s[15]),
+    );
+    v[1] = vextq_u32::<3>(v[1], v[1]);
+    v[2] = vextq_u32::<2>(v[2], v[2]);
+    v[3] = vextq_u32::<1>(v[3], v[3]);
+}
+
+unsafe fn compress_neon(h: &mut [u32; 8], m: &[u32; 16], t0: u32, t1: u32, f0: u32, f1: u32) {
+    let h0 = vld1q_u32(h.as_ptr());
+    let h1 = vld1q_u32(h[4..].as_ptr());
+    let iv = &BLAKE2S_IV;
+    let mut v = [
+        h0,
+        h1,
+        load([iv[0], iv[1], iv[2], iv[3]]),
+        veorq_u32(load([iv[4], iv[5], iv[6], iv[7]]), load([t0, t1, f0, f1])),
+    ];
+    for s in SIGMA[..10].iter() {
+        round(&mut v, m, s);
+    }
+    vst1q_u32(h.as_mut_ptr(), veorq_u32(h0, veorq_u32(v[0], v[2])));
+    vst1q_u32(h[4..].as_mut_ptr(), veorq_u32(h1, veorq_u32(v[1], v[3])));
+}
+
+/// Compress the block `m` into the chaining value `h`.
+///
+/// Always returns `true`, i.e. the block has been compressed and the
+/// portable implementation is skipped.
+#[inline(always)]
+pub(crate) fn compress(
+    h: &mut [u32x4; 2],
+    m: &[u32; 16],
+    t0: u32,
+    t1: u32,
+    f0: u32,
+    f1: u32,
+) -> bool {
+    let mut state = [
+        h[0].0, h[0].1, h[0].2, h[0].3, h[1].0, h[1].1, h[1].2, h[1].3,
+    ];
+    // SAFETY: NEON is enabled at compile time, see the `cfg` on this module
+    unsafe { compress_neon(&mut state, m, t0, t1, f0, f1) };
+    h[0] = u32x4::new(state[0], state[1], state[2], state[3]);
+    h[1] = u32x4::new(state[4], state[5], state[6], state[7]);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scalar BLAKE2s compression as specified in RFC 7693 §3.2.
+    fn compress_ref(h: &mut [u32; 8], m: &[u32; 16], t0: u32, t1: u32, f0: u32, f1: u32) {
+        let mut v = [0u32; 16];
+        v[..8].copy_from_slice(h);
+        v[8..].copy_from_slice(&BLAKE2S_IV);
+        v[12] ^= t0;
+        v[13] ^= t1;
+        v[14] ^= f0;
+        v[15] ^= f1;
+        let g = |v: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, x: u32, y: u32| {
+            v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+            v[d] = (v[d] ^ v[a]).rotate_right(16);
+            v[c] = v[c].wrapping_add(v[d]);
+            v[b] = (v[b] ^ v[c]).rotate_right(12);
+            v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+            v[d] = (v[d] ^ v[a]).rotate_right(8);
+            v[c] = v[c].wrapping_add(v[d]);
+            v[b] = (v[b] ^ v[c]).rotate_right(7);
+        };
+        for s in SIGMA[..10].iter() {
+            g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+            g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+            g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+            g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+            g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+            g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+            g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+            g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+        }
+        for i in 0..8 {
+            h[i] ^= v[i] ^ v[i + 8];
+        }
+    }
+
+    #[test]
+    fn neon_matches_scalar() {
+        let mut x = 0x9E37_79B9u32;
+        let mut next = || {
+            x ^= x << 13;
+            x ^= x >> 17;
+            x ^= x << 5;
+            x
+        };
+        for _ in 0..256 {
+            let mut h = [0u32; 8];
+            h.iter_mut().for_each(|w| *w = next());
+            let mut m = [0u32; 16];
+            m.iter_mut().for_each(|w| *w = next());
+            let (t0, t1) = (next(), next());
+            let f0 = if next() & 1 == 0 { 0 } else { !0 };
+
+            let mut expected = h;
+            compress_ref(&mut expected, &m, t0, t1, f0, 0);
+            let mut state = h;
+            unsafe { compress_neon(&mut state, &m, t0, t1, f0, 0) };
+            assert_eq!(state, expected);
+        }
+    }
+}
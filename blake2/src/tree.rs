@@ -0,0 +1,207 @@
+//! BLAKE2b tree hashing.
+use crate::VarBlake2b;
+#[cfg(feature = "std")]
+use digest::{Update, VariableOutputDirty};
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Builder for a single node of a BLAKE2b hash tree.
+///
+/// The tree parameters are encoded into the node's parameter block as
+/// described in section 2.10 of the [BLAKE2 paper][1]. The defaults
+/// (fanout and maximal depth of 1, all other parameters zero) describe
+/// sequential mode. The last node of each level must be finalized with
+/// [`VarBlake2b::finalize_last_node`].
+///
+/// ```rust
+/// use blake2::Blake2bTree;
+/// use blake2::digest::Update;
+///
+/// let mut leaf = Blake2bTree::new(64)
+///     .fanout(2)
+///     .max_depth(2)
+///     .inner_length(64)
+///     .node_offset(1)
+///     .build();
+/// leaf.update(b"second leaf");
+/// let digest = leaf.finalize_last_node();
+/// ```
+///
+/// [1]: https://blake2.net/blake2.pdf
+#[derive(Clone, Debug)]
+pub struct Blake2bTree {
+    output_size: usize,
+    fanout: u8,
+    max_depth: u8,
+    leaf_size: u32,
+    node_offset: u64,
+    node_depth: u8,
+    inner_length: u8,
+}
+
+impl Blake2bTree {
+    /// Creates a builder for a node with a digest of `output_size` bytes.
+    pub fn new(output_size: usize) -> Self {
+        Self {
+            output_size,
+            fanout: 1,
+            max_depth: 1,
+            leaf_size: 0,
+            node_offset: 0,
+            node_depth: 0,
+            inner_length: 0,
+        }
+    }
+
+    /// Sets the fanout, zero meaning unlimited.
+    pub fn fanout(mut self, fanout: u8) -> Self {
+        self.fanout = fanout;
+        self
+    }
+
+    /// Sets the maximal depth of the tree, zero meaning unlimited.
+    pub fn max_depth(mut self, max_depth: u8) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the maximal leaf length in bytes, zero meaning unlimited.
+    pub fn leaf_size(mut self, leaf_size: u32) -> Self {
+        self.leaf_size = leaf_size;
+        self
+    }
+
+    /// Sets the offset of the node within its level.
+    pub fn node_offset(mut self, node_offset: u64) -> Self {
+        self.node_offset = node_offset;
+        self
+    }
+
+    /// Sets the depth of the node, leaves being at depth zero.
+    pub fn node_depth(mut self, node_depth: u8) -> Self {
+        self.node_depth = node_depth;
+        self
+    }
+
+    /// Sets the digest length of the inner nodes.
+    pub fn inner_length(mut self, inner_length: u8) -> Self {
+        self.inner_length = inner_length;
+        self
+    }
+
+    /// Creates the hashing context for the node.
+    ///
+    /// # Panics
+    ///
+    /// If the output size or the inner length is not between 1 and 64,
+    /// except for an inner length of zero.
+    pub fn build(&self) -> VarBlake2b {
+        assert!((1..=64).contains(&self.output_size), "invalid output size");
+        assert!(self.inner_length <= 64, "invalid inner length");
+        let p = [
+            self.output_size as u64
+                ^ (u64::from(self.fanout) << 16)
+                ^ (u64::from(self.max_depth) << 24)
+                ^ (u64::from(self.leaf_size) << 32),
+            self.node_offset,
+            u64::from(self.node_depth) ^ (u64::from(self.inner_length) << 8),
+            0,
+            0,
+            0,
+            0,
+            0,
+        ];
+        VarBlake2b::with_parameter_block(&p)
+    }
+}
+
+/// Compute the root of a BLAKE2b hash tree over pre-split `leaves`.
+///
+/// Leaves are hashed at node depth 0 with their index as node offset, and
+/// every `fanout` consecutive node digests are hashed into a parent node one
+/// level up until a single root remains. A `fanout` of zero means unlimited,
+/// i.e. all nodes of a level go into one parent. The last node of each level
+/// has the last node flag set. All nodes use `output_size` as both digest
+/// and inner length, and the tree parameters are encoded into every node's
+/// parameter block as described in section 2.10 of the [BLAKE2 paper][1].
+///
+/// An empty slice of leaves is hashed as a single empty leaf.
+///
+/// # Panics
+///
+/// If `max_depth` is zero, `output_size` is not between 1 and 64, a leaf is
+/// longer than a non-zero `leaf_size`, or the leaves do not fit into a tree
+/// of the given fanout and depth.
+///
+/// [1]: https://blake2.net/blake2.pdf
+#[cfg(feature = "std")]
+pub fn blake2b_tree_hash<T: AsRef<[u8]>>(
+    leaves: &[T],
+    fanout: u8,
+    max_depth: u8,
+    leaf_size: u32,
+    output_size: usize,
+) -> Vec<u8> {
+    assert!(max_depth >= 1, "tree depth must be at least 1");
+    assert!((1..=64).contains(&output_size), "invalid output size");
+
+    let node = |data: &[&[u8]], offset: u64, depth: u8, last: bool| {
+        let mut h = Blake2bTree::new(output_size)
+            .fanout(fanout)
+            .max_depth(max_depth)
+            .leaf_size(leaf_size)
+            .node_offset(offset)
+            .node_depth(depth)
+            .inner_length(output_size as u8)
+            .build();
+        for d in data {
+            Update::update(&mut h, d);
+        }
+        if last {
+            h.finalize_last_node()[..output_size].to_vec()
+        } else {
+            let mut out = Vec::new();
+            h.finalize_variable_dirty(|res| out.extend_from_slice(res));
+            out
+        }
+    };
+
+    let empty: [&[u8]; 1] = [&[]];
+    let mut level: Vec<&[u8]> = leaves.iter().map(|l| l.as_ref()).collect();
+    if level.is_empty() {
+        level.extend_from_slice(&empty);
+    }
+    if leaf_size != 0 {
+        assert!(
+            level.iter().all(|l| l.len() <= leaf_size as usize),
+            "leaf is longer than leaf_size"
+        );
+    }
+
+    let n = level.len();
+    let mut digests: Vec<Vec<u8>> = level
+        .iter()
+        .enumerate()
+        .map(|(i, leaf)| node(&[leaf], i as u64, 0, i == n - 1))
+        .collect();
+
+    let mut depth = 0;
+    while digests.len() > 1 {
+        depth += 1;
+        assert!(depth < max_depth, "leaves do not fit into the tree");
+        let group = match fanout {
+            0 => digests.len(),
+            f => f as usize,
+        };
+        let n = digests.chunks(group).len();
+        digests = digests
+            .chunks(group)
+            .enumerate()
+            .map(|(i, children)| {
+                let data: Vec<&[u8]> = children.iter().map(|d| d.as_slice()).collect();
+                node(&data, i as u64, depth, i == n - 1)
+            })
+            .collect();
+    }
+    digests.pop().unwrap()
+}
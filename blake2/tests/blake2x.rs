@@ -0,0 +1,185 @@
+//! BLAKE2Xb tests.
+//!
+//! Keyed vectors follow the layout of the BLAKE2X known-answer tests
+//! (input `00..ff`, key `00..3f`). Expected values were computed with an
+//! independent BLAKE2b implementation cross-checked against Python's
+//! `hashlib.blake2b`.
+use blake2::digest::{ExtendableOutput, Reset, Update, XofReader};
+use blake2::Blake2bX;
+use hex_literal::hex;
+
+fn kat_input() -> ([u8; 256], [u8; 64]) {
+    let mut input = [0u8; 256];
+    for (i, b) in input.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    let mut key = [0u8; 64];
+    key.copy_from_slice(&input[..64]);
+    (input, key)
+}
+
+fn keyed_kat(xof_len: u32, expected: &[u8]) {
+    let (input, key) = kat_input();
+    let mut hasher = Blake2bX::with_params(&key, &[], &[], xof_len);
+    hasher.update(&input[..]);
+    let mut reader = hasher.finalize_xof();
+    let mut buf = [0u8; 256];
+    reader.read(&mut buf[..expected.len()]);
+    assert_eq!(&buf[..expected.len()], expected);
+}
+
+#[test]
+fn blake2xb_keyed_kat() {
+    keyed_kat(1, &hex!("64"));
+    keyed_kat(
+        32,
+        &hex!("29f6bb55de7f8868e053176c878c9fe6c2055c4c5413b51ab0386c277fdbac75"),
+    );
+    keyed_kat(
+        64,
+        &hex!(
+            "4324561d76c370ef35ac36a4adf8f3773a50d86504bd284f71f7ce9e2bc4c1f1
+            d34a7fb2d67561d101955d448b67577eb30dfee96a95c7f921ef53e20be8bc44"
+        ),
+    );
+    keyed_kat(
+        65,
+        &hex!(
+            "78f0ed6e220b3da3cc9381563b2f72c8dc830cb0f39a48c6ae479a6a78dcfa94
+            002631dec467e9e9b47cc8f0887eb680e340aec3ec009d4a33d241533c76c8ca
+            8c"
+        ),
+    );
+    keyed_kat(
+        128,
+        &hex!(
+            "2d7dc80c19a1d12d5fe3963569547a5d1d3e821e6f06c5d5e2c09401f946c9f7
+            e13cd019f2f9a878b62dd850453b6294b99ccaa068e542993524b0f63832d48e
+            865be31e8ec1ee103c718340c904b32efb69170b67f038d50a3252794b1b4076
+            c0620621ab3d91215d55ffea99f23d54e161a90d8d4902fda5931d9f6a27146a"
+        ),
+    );
+    keyed_kat(
+        200,
+        &hex!(
+            "8ca704fe7208fe5f9c23110c0b3b4eee0ef632cae82bda68d8db2436ad409aa0
+            5cf159223586e1e6d8bdae9f316ea786809fbe7fe81ec61c61552d3a83cd6bea
+            f652d1263862664df6aae321d0323440430f400f291c3efbe5d5c690b0cc6b0b
+            f871b3933befb40bc870e2ee1ebb68025a2dcc11b68daadef6be29b5f21e4403
+            74301bde1e80dcfade4c9d681480e65ec494a6af48df232c3d51447b9d06be71
+            4949249c44c43cf73ed13ef0d533e770284e51369d94ae241a5fb2f163893071
+            b2b4c118aeaf9eae"
+        ),
+    );
+    keyed_kat(
+        255,
+        &hex!(
+            "6e85c2f8e1fdc3aaeb969da1258cb504bbf0070cd03d23b3fb5ee08feea5ee2e
+            0ee1c71a5d0f4f701b351f4e4b4d74cb1e2ae6184814f77b62d2f08134b7236e
+            bf6b67d8a6c9f01b4248b30667c555f5d8646dbfe291151b23c9c9857e33a4d5
+            c847be29a5ee7b402e03bac02d1a4319acc0dd8f25e9c7a266f5e5c896cc11b5
+            b238df96a0963ae806cb277abc515c298a3e61a3036b177acf87a56ca4478c4c
+            6d0d468913de602ec891318bbaf52c97a77c35c5b7d164816cf24e4c4b0b5f45
+            853882f716d61eb947a45ce2efa78f1c70a918512af1ad536cbe6148083385b3
+            4e207f5f690d7a954021e4b5f4258a385fd8a87809a481f34202af4caccb82"
+        ),
+    );
+    keyed_kat(
+        256,
+        &hex!(
+            "1e9b2c454e9de3a2d723d850331037dbf54133dbe27488ff757dd255833a27d8
+            eb8a128ad12d0978b6884e25737086a704fb289aaaccf930d5b582ab4df1f55f
+            0c429b6875edec3fe45464fa74164be056a55e243c4222c586bec5b18f39036a
+            a903d98180f24f83d09a454dfa1e03a60e6a3ba4613e99c35f874d790174ee48
+            a557f4f021ade4d1b278d7997ef094569b37b3db0505951e9ee8400adaea275c
+            6db51b325ee730c69df97745b556ae41cd98741e28aa3a49544541eeb3da1b1e
+            8fa4e8e9100d66dd0c7f5e2c271b1ecc077de79c462b9fe4c273543ecd82a5be
+            a63c5acc01eca5fb780c7d7c8c9fe208ae8bd50cad1769693d92c6c8649d20d8"
+        ),
+    );
+}
+
+#[test]
+fn blake2xb_unkeyed() {
+    let expected = hex!(
+        "e0f82b71c07860b65be612d2633becc46596a6c12a8772b561adec35721b7a5c
+        44a7e075e8a3bc8c4fc8390a197be2085b4aa4385c207f24e46415defc659afd
+        73bacb288080b10849aeea386c60cd3fa04c9bcbfeebaed6e98634d696b9d5bd
+        ef0ad2c5"
+    );
+    let mut hasher = Blake2bX::new(100);
+    hasher.update(b"abc");
+    let mut buf = [0u8; 100];
+    hasher.finalize_xof().read(&mut buf);
+    assert_eq!(buf[..], expected[..]);
+
+    // Reading in small pieces crosses output block boundaries.
+    let mut hasher = Blake2bX::new(100);
+    hasher.update(b"abc");
+    let mut reader = hasher.finalize_xof();
+    let mut buf = [0u8; 100];
+    for chunk in buf.chunks_mut(7) {
+        reader.read(chunk);
+    }
+    assert_eq!(buf[..], expected[..]);
+}
+
+#[test]
+fn blake2xb_unknown_length() {
+    let mut hasher = Blake2bX::new(Blake2bX::UNKNOWN_LENGTH);
+    hasher.update(b"abc");
+    let mut buf = [0u8; 130];
+    hasher.finalize_xof().read(&mut buf);
+    assert_eq!(
+        buf[..],
+        hex!(
+            "ae080c1efbcf7f60ed52a04161d02b7ee63bed362534f0661da02c6e40cd2089
+            46d066b86b3dff620e57acea9cd72d3056cf6cb0c18341452a17ce2cced67b70
+            2669bf0bed358c1b708e97de2533b294cdd5e9e229678be36399b5b28d6541c4
+            bc4e3079fb8a0fbdf6023a65f36c654947ce7c114a243670dad347f03275b5c5
+            bd38"
+        )[..]
+    );
+}
+
+#[test]
+fn blake2xb_salt_persona() {
+    let mut hasher = Blake2bX::with_params(&[], b"0123456789abcdef", b"persona", 70);
+    hasher.update(b"abc");
+    let mut buf = [0u8; 70];
+    hasher.finalize_xof().read(&mut buf);
+    assert_eq!(
+        buf[..],
+        hex!(
+            "d06fed23fb8d343a8fdf29722625408545074f6cce64c8336fb0a159f78426ac
+            21382d52891bb4058a0217823f3ac6b98c06513d2c31f1d78392efd47d90c85b
+            802b1eda1ef2"
+        )[..]
+    );
+}
+
+#[test]
+fn blake2xb_reset() {
+    let (input, key) = kat_input();
+    let mut hasher = Blake2bX::with_params(&key, &[], &[], 64);
+    hasher.update(b"garbage");
+    hasher.reset();
+    hasher.update(&input[..]);
+    let mut buf = [0u8; 64];
+    hasher.finalize_xof().read(&mut buf);
+    assert_eq!(
+        buf[..],
+        hex!(
+            "4324561d76c370ef35ac36a4adf8f3773a50d86504bd284f71f7ce9e2bc4c1f1
+            d34a7fb2d67561d101955d448b67577eb30dfee96a95c7f921ef53e20be8bc44"
+        )[..]
+    );
+}
+
+#[test]
+#[should_panic]
+fn blake2xb_read_past_length() {
+    let mut reader = Blake2bX::new(10).finalize_xof();
+    let mut buf = [0u8; 11];
+    reader.read(&mut buf);
+}
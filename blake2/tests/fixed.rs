@@ -0,0 +1,61 @@
+//! Fixed output size aliases. The 512-bit BLAKE2b and 256-bit BLAKE2s
+//! vectors are from RFC 7693 Appendix A and B, the truncated ones were
+//! computed with Python's `hashlib` using the `digest_size` parameter.
+use blake2::{Blake2b256, Blake2b384, Blake2b512, Blake2s128, Blake2s160, Blake2s256, Digest};
+use hex_literal::hex;
+
+fn check<D: Digest>(expected_empty: &[u8], expected_abc: &[u8]) {
+    assert_eq!(D::output_size(), expected_abc.len());
+    assert_eq!(D::digest(b"")[..], expected_empty[..]);
+    assert_eq!(D::digest(b"abc")[..], expected_abc[..]);
+}
+
+#[test]
+fn blake2b_fixed_sizes() {
+    check::<Blake2b256>(
+        &hex!("0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"),
+        &hex!("bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"),
+    );
+    check::<Blake2b384>(
+        &hex!(
+            "b32811423377f52d7862286ee1a72ee540524380fda1724a
+            6f25d7978c6fd3244a6caf0498812673c5e05ef583825100"
+        ),
+        &hex!(
+            "6f56a82c8e7ef526dfe182eb5212f7db9df1317e57815dbd
+            a46083fc30f54ee6c66ba83be64b302d7cba6ce15bb556f4"
+        ),
+    );
+    check::<Blake2b512>(
+        &hex!(
+            "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419
+            d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce"
+        ),
+        &hex!(
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1
+            7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+        ),
+    );
+}
+
+#[test]
+fn blake2s_fixed_sizes() {
+    check::<Blake2s128>(
+        &hex!("64550d6ffe2c0a01a14aba1eade0200c"),
+        &hex!("aa4938119b1dc7b87cbad0ffd200d0ae"),
+    );
+    check::<Blake2s160>(
+        &hex!("354c9c33f735962418bdacb9479873429c34916f"),
+        &hex!("5ae3b99be29b01834c3b508521ede60438f8de17"),
+    );
+    check::<Blake2s256>(
+        &hex!("69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9"),
+        &hex!("508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982"),
+    );
+}
+
+#[test]
+fn blake2_fixed_alg_name() {
+    assert_eq!(Blake2b256::ALG_NAME, "BLAKE2b-256");
+    assert_eq!(Blake2s160::new().to_string(), "BLAKE2s-160");
+}
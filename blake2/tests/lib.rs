@@ -16,3 +16,20 @@ new_test!(
     blake2::VarBlake2s,
     variable_test
 );
+
+#[test]
+fn blake2_alg_name() {
+    assert_eq!(blake2::Blake2b::ALG_NAME, "BLAKE2b-512");
+    assert_eq!(blake2::Blake2s::ALG_NAME, "BLAKE2s-256");
+    assert_eq!(blake2::VarBlake2b::ALG_NAME, "BLAKE2b");
+    assert_eq!(blake2::VarBlake2s::ALG_NAME, "BLAKE2s");
+}
+
+#[test]
+fn blake2_display() {
+    extern crate std;
+    use std::format;
+
+    assert_eq!(format!("{}", blake2::Blake2b::default()), "BLAKE2b-512");
+    assert_eq!(format!("{}", blake2::Blake2s::default()), "BLAKE2s-256");
+}
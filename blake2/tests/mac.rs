@@ -4,3 +4,70 @@ use crypto_mac::new_test;
 
 new_test!(blake2b_mac, "blake2b/mac", blake2::Blake2b);
 new_test!(blake2s_mac, "blake2s/mac", blake2::Blake2s);
+
+// BLAKE2 reference KAT: key = 00 01 .. 3f (BLAKE2b) or 00 .. 1f (BLAKE2s),
+// input = 00 01 .. (n - 1)
+#[test]
+fn blake2b_keyed_kat() {
+    use blake2::digest::generic_array::GenericArray;
+    use crypto_mac::{Mac, NewMac};
+    use hex_literal::hex;
+
+    let mut key = [0u8; 64];
+    let mut input = [0u8; 255];
+    for (i, b) in key.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    for (i, b) in input.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+
+    let vectors: [(usize, [u8; 64]); 3] = [
+        (
+            0,
+            hex!(
+                "10ebb67700b1868efb4417987acf4690ae9d972fb7a590c2f02871799aaa4786
+                b5e996e8f0f4eb981fc214b005f42d2ff4233499391653df7aefcbc13fc51568"
+            ),
+        ),
+        (
+            1,
+            hex!(
+                "961f6dd1e4dd30f63901690c512e78e4b45e4742ed197c3c5e45c549fd25f2e4
+                187b0bc9fe30492b16b0d0bc4ef9b0f34c7003fac09a5ef1532e69430234cebd"
+            ),
+        ),
+        (
+            255,
+            hex!(
+                "142709d62e28fcccd0af97fad0f8465b971e82201dc51070faa0372aa43e9248
+                4be1c1e73ba10906d5d1853db6a4106e0a7bf9800d373d6dee2d46d62ef2a461"
+            ),
+        ),
+    ];
+    for (len, output) in vectors.iter() {
+        let mut mac = blake2::Blake2b::new(GenericArray::from_slice(&key));
+        mac.update(&input[..*len]);
+        assert_eq!(mac.finalize().into_bytes()[..], output[..]);
+    }
+
+    assert!(blake2::Blake2b::new_varkey(&[0; 65]).is_err());
+    assert!(blake2::Blake2s::new_varkey(&[0; 33]).is_err());
+}
+
+#[test]
+fn blake2s_keyed_kat() {
+    use crypto_mac::{Mac, NewMac};
+    use hex_literal::hex;
+
+    let mut key = [0u8; 32];
+    for (i, b) in key.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+
+    let mac = blake2::Blake2s::new_varkey(&key).unwrap();
+    assert_eq!(
+        mac.finalize().into_bytes()[..],
+        hex!("48a8997da407876b3d79c0d92325ad3b89cbb754d86ab71aee047ad345fd2c49")[..]
+    );
+}
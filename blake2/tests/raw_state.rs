@@ -0,0 +1,34 @@
+use blake2::digest::{Update, VariableOutput};
+use blake2::VarBlake2b;
+
+fn finalize(hasher: VarBlake2b) -> [u8; 64] {
+    let mut out = [0u8; 64];
+    hasher.finalize_variable(|res| out[..res.len()].copy_from_slice(res));
+    out
+}
+
+#[test]
+fn blake2b_raw_state_roundtrip() {
+    let hasher = VarBlake2b::from_raw_state(VarBlake2b::default().get_state(), 0, 64).unwrap();
+    assert_eq!(finalize(hasher)[..], finalize(VarBlake2b::default())[..]);
+
+    let data = [0x42u8; 300];
+    let mut expected = VarBlake2b::new(64).unwrap();
+    expected.update(&data[..]);
+
+    // the last block is compressed lazily, so feed one more byte to make
+    // the first 256 bytes part of the chaining value
+    let mut hasher = VarBlake2b::new(64).unwrap();
+    hasher.update(&data[..257]);
+    let mut resumed = VarBlake2b::from_raw_state(hasher.get_state(), 256, 64).unwrap();
+    resumed.update(&data[256..]);
+    assert_eq!(finalize(resumed)[..], finalize(expected)[..]);
+}
+
+#[test]
+fn blake2b_raw_state_invalid() {
+    let h = VarBlake2b::default().get_state();
+    assert!(VarBlake2b::from_raw_state(h, 0, 0).is_none());
+    assert!(VarBlake2b::from_raw_state(h, 0, 65).is_none());
+    assert!(VarBlake2b::from_raw_state(h, 100, 64).is_none());
+}
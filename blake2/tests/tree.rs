@@ -0,0 +1,87 @@
+//! Expected values computed with the reference implementation as exposed by
+//! Python's `hashlib.blake2b` tree hashing parameters.
+#![cfg(feature = "std")]
+
+use blake2::digest::{Update, VariableOutputDirty};
+use blake2::{blake2b_tree_hash, Blake2bTree};
+use hex_literal::hex;
+
+#[test]
+fn blake2b_tree_4_leaves() {
+    let leaves: Vec<Vec<u8>> = (0..4).map(|i| vec![i; 4096]).collect();
+    let root = blake2b_tree_hash(&leaves, 4, 2, 4096, 64);
+    assert_eq!(
+        root[..],
+        hex!(
+            "aaba55994392e8a002988271b0193cde90109a9165a6a984939e23aa6fabeca1
+            d2a5169e9d80188437c6c3580b046113db2605638ec4235fb49409fee892643a"
+        )[..]
+    );
+}
+
+#[test]
+fn blake2b_tree_multi_level() {
+    let leaves: Vec<Vec<u8>> = (0..5).map(|i| vec![i; 100 + i as usize]).collect();
+    let root = blake2b_tree_hash(&leaves, 2, 4, 0, 32);
+    assert_eq!(
+        root[..],
+        hex!("9dcb895dfa5e20d1fa156b69a178fdde92d443af1af4e20207857d891b9f1cb1")[..]
+    );
+}
+
+#[test]
+#[should_panic]
+fn blake2b_tree_too_deep() {
+    let leaves: Vec<Vec<u8>> = (0..5).map(|i| vec![i; 16]).collect();
+    blake2b_tree_hash(&leaves, 4, 2, 0, 64);
+}
+
+#[test]
+fn blake2b_tree_builder_fanout2_depth2() {
+    let data: Vec<u8> = (0..512).map(|i| i as u8).collect();
+    let node = |offset, depth| {
+        Blake2bTree::new(64)
+            .fanout(2)
+            .max_depth(2)
+            .leaf_size(300)
+            .node_offset(offset)
+            .node_depth(depth)
+            .inner_length(64)
+            .build()
+    };
+
+    let mut left = node(0, 0);
+    left.update(&data[..300]);
+    let mut left_digest = Vec::new();
+    left.finalize_variable_dirty(|res| left_digest.extend_from_slice(res));
+    assert_eq!(
+        left_digest[..],
+        hex!(
+            "941a4d98781de618e693a53ba5028d756cddcd7aa20e435cadf6e493adbc8fe9
+            2d50aee4ec76b9ca38084aa563d5bbb8887cfb9b615684066afb9462df5a6278"
+        )[..]
+    );
+
+    let mut right = node(1, 0);
+    right.update(&data[300..]);
+    let right_digest = right.finalize_last_node();
+    assert_eq!(
+        right_digest[..],
+        hex!(
+            "c3e79949ebab5578fe4e39aa47e8c069603caaea566fd562e848d3c25b4ffa96
+            229654cce15f82cf9e76ebdd2c44d7e96adb836efbbd199a9bb2ff2c1ff6d06b"
+        )[..]
+    );
+
+    let mut root = node(0, 1);
+    root.update(&left_digest);
+    root.update(right_digest);
+    let expected = hex!(
+        "c2b08851449d4b0d57a92030752c18a06212c3992e45e79da19e917a827e1fdf
+        e5f4d1722a91fb885c464e2887e5a79782ce5bf1ae9706ad22328fb0ee28bed1"
+    );
+    assert_eq!(root.finalize_last_node()[..], expected[..]);
+
+    let leaves = [&data[..300], &data[300..]];
+    assert_eq!(blake2b_tree_hash(&leaves, 2, 2, 300, 64)[..], expected[..]);
+}
@@ -0,0 +1,8 @@
+fn main() {
+    // C reference implementations used by `tests/*_reference.rs`
+    #[cfg(feature = "c-reference")]
+    {
+        println!("cargo:rerun-if-changed=c/sm3_ref.c");
+        cc::Build::new().file("c/sm3_ref.c").compile("sm3_ref");
+    }
+}
@@ -0,0 +1,95 @@
+/*
+ * SM3 reference implementation following the algorithm description in
+ * GM/T 0004-2012 (GB/T 32905-2016), written as a direct transcription of the
+ * standard's pseudocode. Only used for differential testing.
+ */
+#include <stddef.h>
+#include <stdint.h>
+#include <string.h>
+
+#define ROTL(x, n) (((x) << ((n) % 32)) | ((x) >> ((32 - (n) % 32) % 32)))
+#define P0(x) ((x) ^ ROTL((x), 9) ^ ROTL((x), 17))
+#define P1(x) ((x) ^ ROTL((x), 15) ^ ROTL((x), 23))
+
+static const uint32_t IV[8] = {
+    0x7380166f, 0x4914b2b9, 0x172442d7, 0xda8a0600,
+    0xa96f30bc, 0x163138aa, 0xe38dee4d, 0xb0fb0e4e,
+};
+
+static uint32_t FF(int j, uint32_t x, uint32_t y, uint32_t z) {
+    return j < 16 ? x ^ y ^ z : (x & y) | (x & z) | (y & z);
+}
+
+static uint32_t GG(int j, uint32_t x, uint32_t y, uint32_t z) {
+    return j < 16 ? x ^ y ^ z : (x & y) | (~x & z);
+}
+
+static void CF(uint32_t V[8], const uint8_t B[64]) {
+    uint32_t W[68], W1[64];
+    uint32_t A, B_, C, D, E, F, G, H, SS1, SS2, TT1, TT2, T;
+    int j;
+
+    for (j = 0; j < 16; j++) {
+        W[j] = (uint32_t)B[4 * j] << 24 | (uint32_t)B[4 * j + 1] << 16 |
+               (uint32_t)B[4 * j + 2] << 8 | (uint32_t)B[4 * j + 3];
+    }
+    for (j = 16; j < 68; j++) {
+        W[j] = P1(W[j - 16] ^ W[j - 9] ^ ROTL(W[j - 3], 15)) ^
+               ROTL(W[j - 13], 7) ^ W[j - 6];
+    }
+    for (j = 0; j < 64; j++) {
+        W1[j] = W[j] ^ W[j + 4];
+    }
+
+    A = V[0]; B_ = V[1]; C = V[2]; D = V[3];
+    E = V[4]; F = V[5]; G = V[6]; H = V[7];
+    for (j = 0; j < 64; j++) {
+        T = j < 16 ? 0x79cc4519 : 0x7a879d8a;
+        SS1 = ROTL(ROTL(A, 12) + E + ROTL(T, j), 7);
+        SS2 = SS1 ^ ROTL(A, 12);
+        TT1 = FF(j, A, B_, C) + D + SS2 + W1[j];
+        TT2 = GG(j, E, F, G) + H + SS1 + W[j];
+        D = C;
+        C = ROTL(B_, 9);
+        B_ = A;
+        A = TT1;
+        H = G;
+        G = ROTL(F, 19);
+        F = E;
+        E = P0(TT2);
+    }
+    V[0] ^= A; V[1] ^= B_; V[2] ^= C; V[3] ^= D;
+    V[4] ^= E; V[5] ^= F; V[6] ^= G; V[7] ^= H;
+}
+
+void sm3_ref(const uint8_t *msg, size_t len, uint8_t out[32]) {
+    uint32_t V[8];
+    uint8_t block[64];
+    uint64_t bits = (uint64_t)len * 8;
+    size_t i, rem;
+
+    memcpy(V, IV, sizeof(V));
+    for (i = 0; i + 64 <= len; i += 64) {
+        CF(V, msg + i);
+    }
+
+    rem = len - i;
+    memset(block, 0, sizeof(block));
+    memcpy(block, msg + i, rem);
+    block[rem] = 0x80;
+    if (rem >= 56) {
+        CF(V, block);
+        memset(block, 0, sizeof(block));
+    }
+    for (i = 0; i < 8; i++) {
+        block[63 - i] = (uint8_t)(bits >> (8 * i));
+    }
+    CF(V, block);
+
+    for (i = 0; i < 8; i++) {
+        out[4 * i] = (uint8_t)(V[i] >> 24);
+        out[4 * i + 1] = (uint8_t)(V[i] >> 16);
+        out[4 * i + 2] = (uint8_t)(V[i] >> 8);
+        out[4 * i + 3] = (uint8_t)V[i];
+    }
+}
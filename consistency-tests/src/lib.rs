@@ -0,0 +1,14 @@
+//! Differential tests comparing the hash crates in this workspace against
+//! the `openssl dgst` command line tool and against C reference
+//! implementations.
+//!
+//! The tests live in `tests/`. Those using OpenSSL are enabled with the
+//! `has-openssl` feature, and those building the C code in `c/` with the
+//! `c-reference` feature:
+//!
+//! ```text
+//! cargo test -p consistency-tests --features has-openssl
+//! cargo test -p consistency-tests --features c-reference
+//! ```
+
+#![no_std]
@@ -0,0 +1,74 @@
+#![cfg(feature = "has-openssl")]
+
+use sha2::Digest;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Input sizes around the padding and block boundaries of the tested hashes
+const SIZES: &[usize] = &[0, 1, 55, 56, 64, 127, 128, 511, 512, 1023];
+
+fn openssl_dgst(alg: &str, data: &[u8]) -> Vec<u8> {
+    let mut child = Command::new("openssl")
+        .args(["dgst", &format!("-{}", alg), "-r"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run openssl");
+    child.stdin.take().unwrap().write_all(data).unwrap();
+    let out = child.wait_with_output().unwrap();
+    assert!(out.status.success(), "openssl dgst -{} failed", alg);
+
+    // output has the `<hex digest> *stdin` format
+    let out = String::from_utf8(out.stdout).unwrap();
+    let hex = out.split_whitespace().next().unwrap();
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn check<D: Digest>(alg: &str) {
+    for &n in SIZES {
+        let data: Vec<u8> = (0..n).map(|i| i as u8).collect();
+        assert_eq!(
+            D::digest(&data)[..],
+            openssl_dgst(alg, &data)[..],
+            "{} mismatch for {}-byte input",
+            alg,
+            n
+        );
+    }
+}
+
+#[test]
+fn md5() {
+    check::<md5::Md5>("md5");
+}
+
+#[test]
+fn ripemd160() {
+    check::<ripemd160::Ripemd160>("ripemd160");
+}
+
+#[test]
+fn sha1() {
+    check::<sha1::Sha1>("sha1");
+}
+
+#[test]
+fn sha2() {
+    check::<sha2::Sha224>("sha224");
+    check::<sha2::Sha256>("sha256");
+    check::<sha2::Sha384>("sha384");
+    check::<sha2::Sha512>("sha512");
+    check::<sha2::Sha512Trunc224>("sha512-224");
+    check::<sha2::Sha512Trunc256>("sha512-256");
+}
+
+#[test]
+fn sha3() {
+    check::<sha3::Sha3_224>("sha3-224");
+    check::<sha3::Sha3_256>("sha3-256");
+    check::<sha3::Sha3_384>("sha3-384");
+    check::<sha3::Sha3_512>("sha3-512");
+}
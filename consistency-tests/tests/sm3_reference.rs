@@ -0,0 +1,57 @@
+//! Differential tests of SM3 against the C reference implementation in
+//! `c/sm3_ref.c`
+#![cfg(feature = "c-reference")]
+
+use sm3::{Digest, Sm3};
+
+// built by `build.rs`
+#[link(name = "sm3_ref", kind = "static")]
+extern "C" {
+    fn sm3_ref(msg: *const u8, len: usize, out: *mut u8);
+}
+
+fn reference(msg: &[u8]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    unsafe { sm3_ref(msg.as_ptr(), msg.len(), out.as_mut_ptr()) };
+    out
+}
+
+/// xorshift64 generator, good enough to produce test inputs
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+#[test]
+fn sm3_cross_check() {
+    let mut rng = Rng(0x0123_4567_89ab_cdef);
+    let mut buf = [0u8; 256];
+    for _ in 0..1000 {
+        let len = (rng.next() % 257) as usize;
+        for b in buf[..len].iter_mut() {
+            *b = rng.next() as u8;
+        }
+        let msg = &buf[..len];
+        assert_eq!(
+            Sm3::digest(msg)[..],
+            reference(msg)[..],
+            "input {:02x?}",
+            msg
+        );
+    }
+}
+
+#[test]
+fn sm3_cross_check_lengths() {
+    let buf = [0xa5u8; 256];
+    for len in 0..=buf.len() {
+        let msg = &buf[..len];
+        assert_eq!(Sm3::digest(msg)[..], reference(msg)[..], "length {}", len);
+    }
+}
@@ -79,6 +79,7 @@ fsb_impl!(
     640,
     653,
     1120,
+    "FSB-160",
     "FSB-160 hash function."
 );
 
@@ -93,6 +94,7 @@ fsb_impl!(
     896,
     907,
     1568,
+    "FSB-224",
     "FSB-224 hash function."
 );
 
@@ -107,6 +109,7 @@ fsb_impl!(
     1024,
     1061,
     1792,
+    "FSB-256",
     "FSB-256 hash function."
 );
 
@@ -121,6 +124,7 @@ fsb_impl!(
     1472,
     1483,
     2392,
+    "FSB-384",
     "FSB-384 hash function."
 );
 
@@ -135,5 +139,6 @@ fsb_impl!(
     1984,
     1987,
     3224,
+    "FSB-512",
     "FSB-512 hash function."
 );
@@ -1,7 +1,7 @@
 macro_rules! fsb_impl {
     (
         $state:ident, $state_num:expr, $blocksize:ident, $outputsize:ident, $n:expr, $w:expr,
-        $r:expr, $p:expr, $s:expr, $doc:expr
+        $r:expr, $p:expr, $s:expr, $alg_name:expr, $doc:expr
     ) => {
         use digest::consts::{$blocksize, $outputsize};
 
@@ -18,6 +18,9 @@ macro_rules! fsb_impl {
         }
 
         impl $state {
+            /// Canonical name of the algorithm, e.g. for logging.
+            pub const ALG_NAME: &'static str = $alg_name;
+
             // constants
             const SIZE_OUTPUT_COMPRESS: usize = $r / 8;
             const SIZE_INPUT_COMPRESS: usize = $s / 8;
@@ -288,6 +291,12 @@ macro_rules! fsb_impl {
             }
         }
 
+        impl core::fmt::Display for $state {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Self::ALG_NAME)
+            }
+        }
+
         impl Default for $state {
             fn default() -> Self {
                 Self {
@@ -6,3 +6,17 @@ new_test!(fsb224_main, "fsb224", fsb::Fsb224, digest_test);
 new_test!(fsb256_main, "fsb256", fsb::Fsb256, digest_test);
 new_test!(fsb384_main, "fsb384", fsb::Fsb384, digest_test);
 new_test!(fsb512_main, "fsb512", fsb::Fsb512, digest_test);
+
+#[test]
+fn fsb_alg_name() {
+    assert_eq!(fsb::Fsb160::ALG_NAME, "FSB-160");
+    assert_eq!(fsb::Fsb224::ALG_NAME, "FSB-224");
+    assert_eq!(fsb::Fsb256::ALG_NAME, "FSB-256");
+    assert_eq!(fsb::Fsb384::ALG_NAME, "FSB-384");
+    assert_eq!(fsb::Fsb512::ALG_NAME, "FSB-512");
+}
+
+#[test]
+fn fsb_display() {
+    assert_eq!(format!("{}", fsb::Fsb256::default()), "FSB-256");
+}
@@ -9,4 +9,4 @@ const S_CRYPTO_PRO: SBox = [
     [1, 3, 10, 9, 5, 11, 4, 15, 8, 6, 7, 14, 13, 0, 2, 12],
 ];
 
-gost94_impl!(Gost94CryptoPro, S_CRYPTO_PRO);
+gost94_impl!(Gost94CryptoPro, S_CRYPTO_PRO, "GOST R 34.11-94 (CryptoPro)");
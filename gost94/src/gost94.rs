@@ -2,6 +2,7 @@
 use block_buffer::block_padding::ZeroPadding;
 use block_buffer::BlockBuffer;
 use core::convert::TryInto;
+use core::fmt;
 use digest::{consts::U32, generic_array::GenericArray};
 use digest::{BlockInput, FixedOutputDirty, Reset, Update};
 
@@ -194,6 +195,9 @@ pub struct Gost94 {
 }
 
 impl Gost94 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "GOST R 34.11-94";
+
     /// Create new [`Gost94`] instance with given S-Box and IV
     pub fn new(s: SBox, h: Block) -> Self {
         let n = Default::default();
@@ -206,6 +210,12 @@ impl Gost94 {
     }
 }
 
+impl fmt::Display for Gost94 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Gost94 {
     type BlockSize = U32;
 }
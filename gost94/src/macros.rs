@@ -1,5 +1,5 @@
 macro_rules! gost94_impl {
-    ($state:ident, $sbox:expr) => {
+    ($state:ident, $sbox:expr, $alg_name:expr) => {
         use digest::{consts::U32, BlockInput, FixedOutputDirty, Reset, Update};
         use $crate::gost94::{Block, Gost94, SBox};
 
@@ -9,6 +9,17 @@ macro_rules! gost94_impl {
             sh: Gost94,
         }
 
+        impl $state {
+            /// Canonical name of the algorithm, e.g. for logging.
+            pub const ALG_NAME: &'static str = $alg_name;
+        }
+
+        impl core::fmt::Display for $state {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Self::ALG_NAME)
+            }
+        }
+
         impl Default for $state {
             fn default() -> Self {
                 $state {
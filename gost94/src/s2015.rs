@@ -9,4 +9,8 @@ const S2015: SBox = [
     [1, 7, 14, 13, 0, 5, 8, 3, 4, 15, 10, 6, 9, 12, 11, 2],
 ];
 
-gost94_impl!(Gost94s2015, S2015);
+gost94_impl!(
+    Gost94s2015,
+    S2015,
+    "GOST R 34.11-94 (GOST R 34.12-2015 S-box)"
+);
@@ -9,4 +9,4 @@ const S_TEST: SBox = [
     [1, 15, 13, 0, 5, 7, 10, 4, 9, 2, 3, 14, 6, 11, 8, 12],
 ];
 
-gost94_impl!(Gost94Test, S_TEST);
+gost94_impl!(Gost94Test, S_TEST, "GOST R 34.11-94 (test parameters)");
@@ -76,3 +76,28 @@ fn arithmetic_overflow_regression() {
     h.update(&include_bytes!("data/arithmetic_overflow.bin")[..]);
     h.finalize().as_slice();
 }
+
+#[test]
+fn gost94_alg_name() {
+    assert_eq!(gost94::Gost94::ALG_NAME, "GOST R 34.11-94");
+    assert_eq!(
+        gost94::Gost94CryptoPro::ALG_NAME,
+        "GOST R 34.11-94 (CryptoPro)"
+    );
+    assert_eq!(
+        gost94::Gost94s2015::ALG_NAME,
+        "GOST R 34.11-94 (GOST R 34.12-2015 S-box)"
+    );
+    assert_eq!(
+        gost94::Gost94Test::ALG_NAME,
+        "GOST R 34.11-94 (test parameters)"
+    );
+}
+
+#[test]
+fn gost94_display() {
+    assert_eq!(
+        format!("{}", gost94::Gost94CryptoPro::default()),
+        "GOST R 34.11-94 (CryptoPro)"
+    );
+}
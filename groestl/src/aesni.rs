@@ -0,0 +1,249 @@
+//! Grøstl permutations using AES-NI.
+//!
+//! The state is kept row-wise in SSE registers: for the short variant one
+//! register holds a row of P in its low and the same row of Q in its high
+//! half, so both permutations run at once, while the long variant needs a
+//! full register per row and permutation. SubBytes is an `aesenclast` with
+//! an all-zero round key, preceded by a `pshufb` that both applies
+//! ShiftBytes and cancels the ShiftRows step of `aesenclast`. MixBytes is
+//! computed from the rows and their doublings in GF(2^8). Enabled with the
+//! `aesni` feature; support is detected at run time.
+#![allow(unsafe_code)]
+
+use crate::consts::{C_P, C_Q, SHIFTS_P, SHIFTS_P_WIDE, SHIFTS_Q, SHIFTS_Q_WIDE};
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+cpufeatures::new!(aes_cpuid, "aes", "ssse3");
+
+type Rows = [__m128i; 8];
+
+/// Parameters of one permutation over eight row registers.
+struct Permutation {
+    /// Round constant of the first round.
+    consts: Rows,
+    /// Bytes the round number is added to.
+    round_mask: Rows,
+    /// ShiftBytes followed by the inverse of AES ShiftRows.
+    shuffle: Rows,
+    rounds: u8,
+}
+
+/// Byte shuffle which applies the inverse of AES ShiftRows to the result of
+/// the ShiftBytes shuffle `shift`.
+fn cancel_shift_rows(shift: [u8; 16]) -> [u8; 16] {
+    let mut res = [0u8; 16];
+    for (i, r) in res.iter_mut().enumerate() {
+        let (col, row) = (i / 4, i % 4);
+        *r = shift[(col + 4 - row) % 4 * 4 + row];
+    }
+    res
+}
+
+unsafe fn load(bytes: &[u8; 16]) -> __m128i {
+    _mm_loadu_si128(bytes.as_ptr() as *const __m128i)
+}
+
+impl Permutation {
+    /// P and Q of the short variant, in the low and high register halves.
+    unsafe fn short() -> Self {
+        let mut p = Permutation::empty(10);
+        for row in 0..8 {
+            let mut consts = [0u8; 16];
+            consts[..8].copy_from_slice(&C_P[16 * row..][..8]);
+            consts[8..].copy_from_slice(&C_Q[16 * row..][..8]);
+            p.consts[row] = load(&consts);
+
+            let mut shift = [0u8; 16];
+            for (col, s) in shift[..8].iter_mut().enumerate() {
+                *s = ((col + SHIFTS_P[row] as usize) % 8) as u8;
+            }
+            for (col, s) in shift[8..].iter_mut().enumerate() {
+                *s = 8 + ((col + SHIFTS_Q[row] as usize) % 8) as u8;
+            }
+            p.shuffle[row] = load(&cancel_shift_rows(shift));
+        }
+        p.round_mask[0] = _mm_set_epi64x(0, -1);
+        p.round_mask[7] = _mm_set_epi64x(-1, 0);
+        p
+    }
+
+    /// P or Q of the long variant.
+    unsafe fn long(consts: &[u8; 128], shifts: [u8; 8], round_row: usize) -> Self {
+        let mut p = Permutation::empty(14);
+        for row in 0..8 {
+            let mut c = [0u8; 16];
+            c.copy_from_slice(&consts[16 * row..][..16]);
+            p.consts[row] = load(&c);
+
+            let mut shift = [0u8; 16];
+            for (col, s) in shift.iter_mut().enumerate() {
+                *s = ((col + shifts[row] as usize) % 16) as u8;
+            }
+            p.shuffle[row] = load(&cancel_shift_rows(shift));
+        }
+        p.round_mask[round_row] = _mm_set1_epi8(-1);
+        p
+    }
+
+    unsafe fn empty(rounds: u8) -> Self {
+        let zero = _mm_setzero_si128();
+        Permutation {
+            consts: [zero; 8],
+            round_mask: [zero; 8],
+            shuffle: [zero; 8],
+            rounds,
+        }
+    }
+
+    #[target_feature(enable = "aes,ssse3")]
+    unsafe fn apply(&self, x: &mut Rows) {
+        let zero = _mm_setzero_si128();
+        for round in 0..self.rounds {
+            let round = _mm_set1_epi8(round as i8);
+            let params = self.consts.iter().zip(self.round_mask.iter());
+            for ((x, shuffle), (c, mask)) in x.iter_mut().zip(self.shuffle.iter()).zip(params) {
+                let c = _mm_xor_si128(*c, _mm_and_si128(round, *mask));
+                let t = _mm_shuffle_epi8(_mm_xor_si128(*x, c), *shuffle);
+                *x = _mm_aesenclast_si128(t, zero);
+            }
+            mix_bytes(x);
+        }
+    }
+}
+
+/// Multiplication by 2 in GF(2^8).
+#[inline(always)]
+unsafe fn double(x: __m128i) -> __m128i {
+    let carry = _mm_cmplt_epi8(x, _mm_setzero_si128());
+    _mm_xor_si128(
+        _mm_add_epi8(x, x),
+        _mm_and_si128(carry, _mm_set1_epi8(0x1b)),
+    )
+}
+
+/// Multiplication by the circulant matrix with first row
+/// `[2, 2, 3, 4, 5, 3, 5, 7]`.
+#[inline(always)]
+unsafe fn mix_bytes(x: &mut Rows) {
+    let mut x2 = *x;
+    let mut x4 = *x;
+    for (x2, x4) in x2.iter_mut().zip(x4.iter_mut()) {
+        *x2 = double(*x2);
+        *x4 = double(*x2);
+    }
+    let x1 = *x;
+    let xor = _mm_xor_si128;
+    for (i, x) in x.iter_mut().enumerate() {
+        let at = |j: usize| (i + j) % 8;
+        let twos = xor(
+            xor(x2[at(0)], x2[at(1)]),
+            xor(xor(x2[at(2)], x2[at(5)]), x2[at(7)]),
+        );
+        let fours = xor(xor(x4[at(3)], x4[at(4)]), xor(x4[at(6)], x4[at(7)]));
+        let ones = xor(
+            xor(x1[at(2)], x1[at(4)]),
+            xor(xor(x1[at(5)], x1[at(6)]), x1[at(7)]),
+        );
+        *x = xor(xor(twos, fours), ones);
+    }
+}
+
+/// Load the column-major bytes `a` and `b` into the low and high halves of
+/// the row registers.
+unsafe fn to_rows(a: &[u8], b: &[u8]) -> Rows {
+    let cols_a = a.len() / 8;
+    let mut rows = [_mm_setzero_si128(); 8];
+    for (row, reg) in rows.iter_mut().enumerate() {
+        let mut bytes = [0u8; 16];
+        for (col, byte) in bytes.iter_mut().enumerate() {
+            *byte = if col < cols_a {
+                a[8 * col + row]
+            } else {
+                b[8 * (col - cols_a) + row]
+            };
+        }
+        *reg = load(&bytes);
+    }
+    rows
+}
+
+/// Inverse of `to_rows`.
+unsafe fn from_rows(rows: &Rows, a: &mut [u8], b: &mut [u8]) {
+    let cols_a = a.len() / 8;
+    for (row, reg) in rows.iter().enumerate() {
+        let mut bytes = [0u8; 16];
+        _mm_storeu_si128(bytes.as_mut_ptr() as *mut __m128i, *reg);
+        for (col, byte) in bytes.iter().enumerate() {
+            if col < cols_a {
+                a[8 * col + row] = *byte;
+            } else {
+                b[8 * (col - cols_a) + row] = *byte;
+            }
+        }
+    }
+}
+
+/// Compress `block` into the chaining value `state`, of either 64 or 128
+/// bytes.
+///
+/// Returns `false` without touching `state` if AES-NI is not available.
+pub(crate) fn compress(state: &mut [u8], block: &[u8]) -> bool {
+    if !aes_cpuid::get() {
+        return false;
+    }
+    let mut h_m = [0u8; 128];
+    let h_m = &mut h_m[..state.len()];
+    for (x, (h, m)) in h_m.iter_mut().zip(state.iter().zip(block.iter())) {
+        *x = h ^ m;
+    }
+    let mut p = [0u8; 128];
+    let mut q = [0u8; 128];
+    let (p, q) = (&mut p[..state.len()], &mut q[..state.len()]);
+    // SAFETY: AES-NI and SSSE3 support has been checked above
+    unsafe {
+        if state.len() == 64 {
+            let mut x = to_rows(h_m, block);
+            Permutation::short().apply(&mut x);
+            from_rows(&x, p, q);
+        } else {
+            let mut x = to_rows(&h_m[..64], &h_m[64..]);
+            Permutation::long(&C_P, SHIFTS_P_WIDE, 0).apply(&mut x);
+            let (lo, hi) = p.split_at_mut(64);
+            from_rows(&x, lo, hi);
+            let mut x = to_rows(&block[..64], &block[64..]);
+            Permutation::long(&C_Q, SHIFTS_Q_WIDE, 7).apply(&mut x);
+            let (lo, hi) = q.split_at_mut(64);
+            from_rows(&x, lo, hi);
+        }
+    }
+    for (h, (p, q)) in state.iter_mut().zip(p.iter().zip(q.iter())) {
+        *h ^= p ^ q;
+    }
+    true
+}
+
+/// Apply the P permutation to `block`, of either 64 or 128 bytes.
+///
+/// Returns `false` without touching `out` if AES-NI is not available.
+pub(crate) fn p(block: &[u8], out: &mut [u8]) -> bool {
+    if !aes_cpuid::get() {
+        return false;
+    }
+    // SAFETY: AES-NI and SSSE3 support has been checked above
+    unsafe {
+        if block.len() == 64 {
+            let mut x = to_rows(block, &[0; 64]);
+            Permutation::short().apply(&mut x);
+            from_rows(&x, out, &mut [0; 64]);
+        } else {
+            let mut x = to_rows(&block[..64], &block[64..]);
+            Permutation::long(&C_P, SHIFTS_P_WIDE, 0).apply(&mut x);
+            let (lo, hi) = out.split_at_mut(64);
+            from_rows(&x, lo, hi);
+        }
+    }
+    true
+}
@@ -0,0 +1,54 @@
+use crate::state::GroestlState;
+use core::convert::TryInto;
+use core::ops::Div;
+use digest::consts::{U128, U64};
+use digest::generic_array::typenum::{Quot, U8};
+use digest::generic_array::{ArrayLength, GenericArray};
+
+fn compress<B>(state: &mut [u64], blocks: &[GenericArray<u8, B>])
+where
+    B: ArrayLength<u8> + Div<U8>,
+    B::ArrayType: Copy,
+    Quot<B, U8>: ArrayLength<u8>,
+{
+    // the output size only affects the initial chaining value, which is
+    // overwritten below
+    let mut s = GroestlState::<B>::new(B::to_usize() / 2);
+    for (chunk, w) in s.state.chunks_exact_mut(8).zip(state.iter()) {
+        chunk.copy_from_slice(&w.to_be_bytes());
+    }
+    for block in blocks {
+        s.compress(block);
+    }
+    for (w, chunk) in state.iter_mut().zip(s.state.chunks_exact(8)) {
+        *w = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+}
+
+/// Grøstl compression function for the short variant (output sizes up to
+/// 256 bits).
+///
+/// The chaining value is represented as big-endian 64-bit words, i.e. one
+/// word per column of the state matrix. The initial chaining value is all
+/// zeros except for `state[7]`, which holds the output size in bits.
+///
+/// This is a low-level "hazmat" API which provides direct access to the core
+/// functionality of Grøstl. The message must be padded by the caller, and
+/// the output transformation is not applied.
+pub fn compress_short(state: &mut [u64; 8], blocks: &[GenericArray<u8, U64>]) {
+    compress(state, blocks)
+}
+
+/// Grøstl compression function for the long variant (output sizes above
+/// 256 bits).
+///
+/// The chaining value is represented as big-endian 64-bit words, i.e. one
+/// word per column of the state matrix. The initial chaining value is all
+/// zeros except for `state[15]`, which holds the output size in bits.
+///
+/// This is a low-level "hazmat" API which provides direct access to the core
+/// functionality of Grøstl. The message must be padded by the caller, and
+/// the output transformation is not applied.
+pub fn compress_long(state: &mut [u64; 16], blocks: &[GenericArray<u8, U128>]) {
+    compress(state, blocks)
+}
@@ -1,4 +1,5 @@
-use block_buffer::BlockBuffer;
+use block_buffer::{block_padding::ZeroPadding, BlockBuffer};
+use core::convert::TryInto;
 use core::ops::Div;
 use digest::generic_array::typenum::{Quot, U8};
 use digest::generic_array::{ArrayLength, GenericArray};
@@ -69,6 +70,53 @@ where
         res
     }
 
+    /// Write the raw chaining value, then the number of processed blocks and
+    /// the output size as little-endian `u64`s, then the number of buffered
+    /// bytes and the buffered bytes zero-padded to the block size into
+    /// `out`, which must be `2 * BlockSize + 17` bytes long.
+    pub fn serialize(&self, out: &mut [u8]) {
+        let n = BlockSize::to_usize();
+        let (state, rest) = out.split_at_mut(n);
+        state.copy_from_slice(&self.state.state);
+        rest[..8].copy_from_slice(&self.state.num_blocks.to_le_bytes());
+        rest[8..16].copy_from_slice(&(self.output_size as u64).to_le_bytes());
+        rest[16] = self.buffer.position() as u8;
+        // zero padding a copy of the buffer yields the buffered bytes
+        // followed by zeros, which is how the snapshot stores them
+        let mut buffer = self.buffer.clone();
+        let block = buffer
+            .pad_with::<ZeroPadding>()
+            .expect("we never use input_lazy");
+        rest[17..].copy_from_slice(block);
+    }
+
+    /// Inverse of `serialize`.
+    ///
+    /// Returns `None` if `bytes` has the wrong length, the output size is
+    /// invalid for the block size or the buffered length is out of range.
+    pub fn deserialize(bytes: &[u8]) -> Option<Self> {
+        let n = BlockSize::to_usize();
+        if bytes.len() != 2 * n + 17 {
+            return None;
+        }
+        let (state, rest) = bytes.split_at(n);
+        let num_blocks = u64::from_le_bytes(rest[..8].try_into().unwrap());
+        let output_size = u64::from_le_bytes(rest[8..16].try_into().unwrap());
+        let pos = rest[16] as usize;
+        if output_size > 64 || pos >= n {
+            return None;
+        }
+        let mut groestl = Self::new(output_size as usize).ok()?;
+        groestl.state.state.copy_from_slice(state);
+        groestl.state.num_blocks = num_blocks;
+        // less than a block, so nothing gets compressed
+        let s = &mut groestl.state;
+        groestl
+            .buffer
+            .input_block(&rest[17..17 + pos], |b| s.compress(b));
+        Some(groestl)
+    }
+
     pub fn reset(&mut self) {
         self.state = GroestlState::new(self.output_size);
         self.buffer.reset();
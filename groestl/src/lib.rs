@@ -45,22 +45,50 @@ extern crate std;
 
 pub use digest::{self, Digest};
 
+#[cfg(all(feature = "aesni", any(target_arch = "x86", target_arch = "x86_64")))]
+mod aesni;
 mod consts;
 mod groestl;
 mod matrix;
 mod state;
 #[macro_use]
 mod macros;
+#[cfg(feature = "compress")]
+mod compress;
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+#[cfg(feature = "compress")]
+pub use crate::compress::{compress_long, compress_short};
 
 use crate::groestl::Groestl;
 use digest::consts::{U128, U28, U32, U48, U64};
 use digest::generic_array::typenum::Unsigned;
 use digest::{BlockInput, FixedOutputDirty, InvalidOutputSize, Reset, Update, VariableOutputDirty};
 
-impl_groestl!(Groestl512, U64, U128);
-impl_groestl!(Groestl384, U48, U128);
-impl_groestl!(Groestl256, U32, U64);
-impl_groestl!(Groestl224, U28, U64);
+impl_groestl!(Groestl512, U64, U128, "Groestl-512");
+impl_groestl!(Groestl384, U48, U128, "Groestl-384");
+impl_groestl!(Groestl256, U32, U64, "Groestl-256");
+impl_groestl!(Groestl224, U28, U64, "Groestl-224");
+
+/// Size in bytes of a snapshot created by [`GroestlBig::serialize_state`].
+pub const GROESTL_BIG_SNAPSHOT_LEN: usize = 128 + 8 + 8 + 1 + 128;
+/// Size in bytes of a snapshot created by [`GroestlSmall::serialize_state`].
+pub const GROESTL_SMALL_SNAPSHOT_LEN: usize = 64 + 8 + 8 + 1 + 64;
 
-impl_variable_groestl!(GroestlBig, U128, 32, 64);
-impl_variable_groestl!(GroestlSmall, U64, 0, 32);
+impl_variable_groestl!(
+    GroestlBig,
+    U128,
+    32,
+    64,
+    "Groestl",
+    GROESTL_BIG_SNAPSHOT_LEN
+);
+impl_variable_groestl!(
+    GroestlSmall,
+    U64,
+    0,
+    32,
+    "Groestl",
+    GROESTL_SMALL_SNAPSHOT_LEN
+);
@@ -1,10 +1,21 @@
 macro_rules! impl_groestl {
-    ($state:ident, $output:ident, $block:ident) => {
+    ($state:ident, $output:ident, $block:ident, $alg_name:expr) => {
         #[derive(Clone)]
         pub struct $state {
             groestl: Groestl<$block>,
         }
 
+        impl $state {
+            /// Canonical name of the algorithm, e.g. for logging.
+            pub const ALG_NAME: &'static str = $alg_name;
+        }
+
+        impl core::fmt::Display for $state {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Self::ALG_NAME)
+            }
+        }
+
         impl Default for $state {
             fn default() -> Self {
                 $state {
@@ -46,12 +57,48 @@ macro_rules! impl_groestl {
 }
 
 macro_rules! impl_variable_groestl {
-    ($state:ident, $block:ident, $min:expr, $max:expr) => {
+    (
+        $state:ident, $block:ident, $min:expr, $max:expr, $alg_name:expr, $snapshot_len:ident
+    ) => {
         #[derive(Clone)]
         pub struct $state {
             groestl: Groestl<$block>,
         }
 
+        impl $state {
+            /// Canonical name of the algorithm, e.g. for logging.
+            pub const ALG_NAME: &'static str = $alg_name;
+
+            /// Serialize the hasher state, including input which does not
+            /// fill a complete block yet.
+            ///
+            /// The snapshot consists of the chaining value as big-endian
+            /// column words, the number of processed blocks and the output
+            /// size as little-endian `u64`s, the number of buffered bytes as
+            /// a single byte and the buffered bytes zero-padded to the block
+            /// size.
+            pub fn serialize_state(&self) -> [u8; $snapshot_len] {
+                let mut out = [0u8; $snapshot_len];
+                self.groestl.serialize(&mut out);
+                out
+            }
+
+            /// Restore a hasher from a snapshot created by `serialize_state`.
+            ///
+            /// Returns `None` if `bytes` has the wrong length or is malformed,
+            /// e.g. if the output size is out of range.
+            pub fn deserialize_state(bytes: &[u8]) -> Option<Self> {
+                let groestl = Groestl::deserialize(bytes)?;
+                Some($state { groestl })
+            }
+        }
+
+        impl core::fmt::Display for $state {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Self::ALG_NAME)
+            }
+        }
+
         impl BlockInput for $state {
             type BlockSize = $block;
         }
@@ -0,0 +1,61 @@
+//! `serde` support for checkpointing hasher state.
+//!
+//! The state is encoded as the byte string created by `serialize_state`,
+//! so input which does not fill a whole block yet is preserved.
+use crate::{GroestlBig, GroestlSmall, GROESTL_BIG_SNAPSHOT_LEN, GROESTL_SMALL_SNAPSHOT_LEN};
+use core::fmt;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+macro_rules! impl_serde {
+    ($state:ident, $visitor:ident, $snapshot_len:ident) => {
+        impl Serialize for $state {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_bytes(&self.serialize_state())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $state {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_bytes($visitor)
+            }
+        }
+
+        /// Accepts the snapshot as bytes or, for formats without a byte
+        /// string type, as a sequence of bytes.
+        struct $visitor;
+
+        impl<'de> de::Visitor<'de> for $visitor {
+            type Value = $state;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    f,
+                    "a {} snapshot of {} bytes",
+                    stringify!($state),
+                    $snapshot_len
+                )
+            }
+
+            fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<$state, E> {
+                $state::deserialize_state(bytes)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Bytes(bytes), &self))
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<$state, A::Error> {
+                let mut bytes = [0u8; $snapshot_len];
+                for (i, b) in bytes.iter_mut().enumerate() {
+                    *b = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                if seq.next_element::<u8>()?.is_some() {
+                    return Err(de::Error::invalid_length($snapshot_len + 1, &self));
+                }
+                self.visit_bytes(&bytes)
+            }
+        }
+    };
+}
+
+impl_serde!(GroestlSmall, SmallVisitor, GROESTL_SMALL_SNAPSHOT_LEN);
+impl_serde!(GroestlBig, BigVisitor, GROESTL_BIG_SNAPSHOT_LEN);
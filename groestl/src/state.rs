@@ -70,14 +70,24 @@ where
     }
 
     pub fn compress(&mut self, input_block: &GenericArray<u8, BlockSize>) {
+        self.num_blocks += 1;
+        #[cfg(all(feature = "aesni", any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            if crate::aesni::compress(&mut self.state, input_block) {
+                return;
+            }
+        }
+        self.compress_soft(input_block);
+    }
+
+    fn compress_soft(&mut self, input_block: &GenericArray<u8, BlockSize>) {
         self.state = xor_generic_array(
             &xor_generic_array(
-                &self.p(&xor_generic_array(&self.state, input_block)),
+                &self.p_soft(&xor_generic_array(&self.state, input_block)),
                 &self.q(input_block),
             ),
             &self.state,
         );
-        self.num_blocks += 1;
     }
 
     fn block_to_matrix(
@@ -113,6 +123,17 @@ where
     }
 
     pub fn p(&self, block: &GenericArray<u8, BlockSize>) -> GenericArray<u8, BlockSize> {
+        #[cfg(all(feature = "aesni", any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            let mut res = GenericArray::default();
+            if crate::aesni::p(block, &mut res) {
+                return res;
+            }
+        }
+        self.p_soft(block)
+    }
+
+    fn p_soft(&self, block: &GenericArray<u8, BlockSize>) -> GenericArray<u8, BlockSize> {
         let shifts = if self.wide() { SHIFTS_P_WIDE } else { SHIFTS_P };
         let mut matrix = self.block_to_matrix(block);
         for round in 0..self.rounds {
@@ -251,6 +272,31 @@ mod test {
         assert_eq!(&q_block[..], &expected[..]);
     }
 
+    #[cfg(all(feature = "aesni", any(target_arch = "x86", target_arch = "x86_64")))]
+    #[test]
+    fn test_aesni_matches_soft() {
+        use digest::generic_array::typenum::U128;
+
+        let mut block = GenericArray::<u8, U128>::default();
+        for (i, b) in block.iter_mut().enumerate() {
+            *b = (i * 37) as u8;
+        }
+
+        let mut s = GroestlState::<U64>::new(32);
+        let mut expected = s;
+        s.compress(GenericArray::from_slice(&block[..64]));
+        expected.compress_soft(GenericArray::from_slice(&block[..64]));
+        assert_eq!(s.state, expected.state);
+        assert_eq!(s.p(&s.state), s.p_soft(&s.state));
+
+        let mut s = GroestlState::<U128>::new(64);
+        let mut expected = s;
+        s.compress(&block);
+        expected.compress_soft(&block);
+        assert_eq!(s.state, expected.state);
+        assert_eq!(s.p(&s.state), s.p_soft(&s.state));
+    }
+
     #[test]
     fn test_block_to_matrix() {
         let s = GroestlState::<U64>::new(32);
@@ -0,0 +1,76 @@
+#![cfg(feature = "compress")]
+
+use digest::generic_array::GenericArray;
+use groestl::{compress_long, compress_short};
+
+#[test]
+fn compress_short_empty_message() {
+    // Groestl-256 IV and the padded empty message
+    let mut state = [0u64; 8];
+    state[7] = 256;
+    let mut block = GenericArray::default();
+    block[0] = 0x80;
+    block[63] = 1;
+    compress_short(&mut state, &[block]);
+    assert_eq!(
+        state,
+        [
+            0x4a5b_e45c_998b_52fc,
+            0x53be_e13a_7d93_4897,
+            0xa708_aa40_a08d_9ec9,
+            0xe8e3_db4a_e010_4c2a,
+            0x6eb0_5f5e_fd6d_fa45,
+            0xab8f_fea1_5018_31f2,
+            0x9e07_65a0_eebd_f286,
+            0x0d35_f0c1_9a88_9019,
+        ]
+    );
+}
+
+#[test]
+fn compress_long_empty_message() {
+    // Groestl-512 IV and the padded empty message
+    let mut state = [0u64; 16];
+    state[15] = 512;
+    let mut block = GenericArray::default();
+    block[0] = 0x80;
+    block[127] = 1;
+    compress_long(&mut state, &[block]);
+    assert_eq!(
+        state,
+        [
+            0x0505_d727_6726_ade2,
+            0x4094_8625_dd9f_cc87,
+            0x9b2f_3b47_639d_e9d7,
+            0x8695_41da_e8a3_6aea,
+            0x62a1_e556_9085_fcd0,
+            0xb089_44a3_7b33_7735,
+            0xcf47_13a3_cc4e_17c1,
+            0x1f31_bf35_6a3c_4263,
+            0xd3dc_80d1_b0df_468f,
+            0xbe60_7616_1409_72f2,
+            0xf047_56d3_7244_703c,
+            0xffaf_0899_c246_f3f7,
+            0x3ce8_f552_cf31_a531,
+            0x0f8f_26a8_70f4_b486,
+            0x8c0b_1edd_7491_35c1,
+            0x30b0_87ea_2e2d_8f7b,
+        ]
+    );
+}
+
+#[test]
+fn compress_multiple_blocks() {
+    let mut state = [0u64; 16];
+    state[15] = 512;
+    let blocks = [
+        GenericArray::clone_from_slice(&[0x11; 128]),
+        GenericArray::clone_from_slice(&[0x22; 128]),
+    ];
+
+    let mut expected = state;
+    compress_long(&mut expected, &blocks[..1]);
+    compress_long(&mut expected, &blocks[1..]);
+    compress_long(&mut state, &blocks);
+    assert_eq!(state, expected);
+}
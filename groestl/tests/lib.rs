@@ -26,3 +26,49 @@ new_test!(
     groestl::Groestl512,
     digest_test
 );
+
+#[test]
+fn groestl_alg_name() {
+    assert_eq!(groestl::Groestl224::ALG_NAME, "Groestl-224");
+    assert_eq!(groestl::Groestl256::ALG_NAME, "Groestl-256");
+    assert_eq!(groestl::Groestl384::ALG_NAME, "Groestl-384");
+    assert_eq!(groestl::Groestl512::ALG_NAME, "Groestl-512");
+    assert_eq!(groestl::GroestlSmall::ALG_NAME, "Groestl");
+    assert_eq!(groestl::GroestlBig::ALG_NAME, "Groestl");
+}
+
+#[test]
+fn groestl_display() {
+    extern crate std;
+    use std::format;
+
+    assert_eq!(format!("{}", groestl::Groestl256::default()), "Groestl-256");
+}
+
+#[test]
+fn groestl_var_reset() {
+    use digest::{Reset, Update, VariableOutput};
+
+    fn check<D: VariableOutput + Update + Reset>(output_size: usize) {
+        let mut first = [0u8; 64];
+        let mut second = [0u8; 64];
+        let mut hasher = D::new(output_size).unwrap();
+        hasher.update(b"first message");
+        hasher.finalize_variable_reset(|res| first[..res.len()].copy_from_slice(res));
+        hasher.update(b"second message");
+        hasher.finalize_variable_reset(|res| second[..res.len()].copy_from_slice(res));
+
+        let mut expected = [0u8; 64];
+        let mut fresh = D::new(output_size).unwrap();
+        fresh.update(b"first message");
+        fresh.finalize_variable(|res| expected[..res.len()].copy_from_slice(res));
+        assert_eq!(first[..], expected[..]);
+        let mut fresh = D::new(output_size).unwrap();
+        fresh.update(b"second message");
+        fresh.finalize_variable(|res| expected[..res.len()].copy_from_slice(res));
+        assert_eq!(second[..], expected[..]);
+    }
+
+    check::<groestl::GroestlSmall>(20);
+    check::<groestl::GroestlBig>(48);
+}
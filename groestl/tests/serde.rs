@@ -0,0 +1,50 @@
+#![cfg(feature = "serde")]
+
+use digest::{Update, VariableOutput};
+use groestl::{GroestlBig, GroestlSmall};
+
+fn roundtrip<D>(output_size: usize, block_size: usize)
+where
+    D: Update + VariableOutput + serde::Serialize + serde::de::DeserializeOwned,
+{
+    let data = [0x5au8; 3 * 128];
+    let data = &data[..3 * block_size];
+    // on a block boundary and with part of a block buffered
+    for &split in [2 * block_size, block_size + 3].iter() {
+        let (head, tail) = data.split_at(split);
+
+        let mut h = D::new(output_size).unwrap();
+        h.update(head);
+        let encoded = bincode::serialize(&h).unwrap();
+        let mut h: D = bincode::deserialize(&encoded).unwrap();
+        h.update(tail);
+
+        let mut expected = D::new(output_size).unwrap();
+        expected.update(data);
+        assert_eq!(h.finalize_boxed(), expected.finalize_boxed());
+
+        let res: Result<D, _> = bincode::deserialize(&encoded[..encoded.len() - 1]);
+        assert!(res.is_err());
+    }
+}
+
+#[test]
+fn groestl_small_roundtrip() {
+    roundtrip::<GroestlSmall>(32, 64);
+}
+
+#[test]
+fn groestl_big_roundtrip() {
+    roundtrip::<GroestlBig>(64, 128);
+}
+
+#[test]
+fn reject_invalid_output_size() {
+    let h = GroestlSmall::new(32).unwrap();
+    let mut encoded = bincode::serialize(&h).unwrap();
+    // the output size follows the length prefix, the chaining value and the
+    // block count
+    encoded[8 + 64 + 8..8 + 64 + 16].copy_from_slice(&33u64.to_le_bytes());
+    let res: Result<GroestlSmall, _> = bincode::deserialize(&encoded);
+    assert!(res.is_err());
+}
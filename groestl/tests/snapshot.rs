@@ -0,0 +1,48 @@
+use digest::{Update, VariableOutput};
+use groestl::{GroestlBig, GroestlSmall, GROESTL_BIG_SNAPSHOT_LEN, GROESTL_SMALL_SNAPSHOT_LEN};
+
+macro_rules! snapshot_test {
+    ($name:ident, $hasher:ty, $len:expr, $output_size:expr, $block_size:expr) => {
+        #[test]
+        fn $name() {
+            let data = [0x5au8; 3 * 128 + 7];
+            for &split in &[0, 1, $block_size - 1, $block_size, 2 * $block_size + 7] {
+                let mut hasher = <$hasher>::new($output_size).unwrap();
+                hasher.update(&data[..split]);
+                let snapshot = hasher.serialize_state();
+                assert_eq!(snapshot.len(), $len);
+
+                let mut resumed = <$hasher>::deserialize_state(&snapshot).unwrap();
+                resumed.update(&data[split..]);
+                let mut expected = <$hasher>::new($output_size).unwrap();
+                expected.update(&data[..]);
+                assert_eq!(resumed.finalize_boxed(), expected.finalize_boxed());
+            }
+
+            let snapshot = <$hasher>::new($output_size).unwrap().serialize_state();
+            assert!(<$hasher>::deserialize_state(&snapshot[1..]).is_none());
+            // the output size follows the chaining value and the block count
+            let mut bad_size = snapshot;
+            bad_size[$block_size + 8] = 65;
+            assert!(<$hasher>::deserialize_state(&bad_size).is_none());
+            let mut bad_pos = snapshot;
+            bad_pos[$block_size + 16] = $block_size as u8;
+            assert!(<$hasher>::deserialize_state(&bad_pos).is_none());
+        }
+    };
+}
+
+snapshot_test!(
+    groestl_small_snapshot,
+    GroestlSmall,
+    GROESTL_SMALL_SNAPSHOT_LEN,
+    28,
+    64
+);
+snapshot_test!(
+    groestl_big_snapshot,
+    GroestlBig,
+    GROESTL_BIG_SNAPSHOT_LEN,
+    48,
+    128
+);
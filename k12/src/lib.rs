@@ -17,7 +17,6 @@
 #![warn(missing_docs, rust_2018_idioms)]
 
 // TODO(tarcieri): eliminate alloc requirement
-#[macro_use]
 extern crate alloc;
 
 pub use digest;
@@ -25,24 +24,108 @@ pub use digest;
 #[macro_use]
 mod lanes;
 
-// TODO(tarcieri): eliminate usage of `Vec`
+// TODO(tarcieri): don't store the customization in a `Vec`
 use alloc::vec::Vec;
+use core::fmt;
 use core::{cmp::min, convert::TryInto, mem};
 use digest::{ExtendableOutputDirty, Reset, Update, XofReader};
 
+/// Rate of TurboSHAKE128 in bytes.
+const RATE: usize = 168;
+/// Size of the chunks the input is cut into.
+const CHUNK_SIZE: usize = 8192;
+/// Size of the chaining value computed for each chunk after the first.
+const CV_SIZE: usize = 32;
+
+const SINGLE_NODE_DOMAIN_SEP: u8 = 0x07;
+const FINAL_NODE_DOMAIN_SEP: u8 = 0x06;
+const LEAF_DOMAIN_SEP: u8 = 0x0B;
+
+/// TurboSHAKE128 in the absorbing phase.
+#[derive(Clone, Debug, Default)]
+struct TurboShake128 {
+    state: [u64; 25],
+    pos: usize,
+}
+
+impl TurboShake128 {
+    fn xor_byte(&mut self, i: usize, b: u8) {
+        self.state[i / 8] ^= u64::from(b) << (8 * (i % 8));
+    }
+
+    fn absorb(&mut self, mut input: &[u8]) {
+        while !input.is_empty() {
+            if self.pos == 0 && input.len() >= RATE {
+                let (block, rest) = input.split_at(RATE);
+                for (lane, bytes) in self.state.iter_mut().zip(block.chunks_exact(8)) {
+                    *lane ^= u64::from_le_bytes(bytes.try_into().unwrap());
+                }
+                lanes::keccak(&mut self.state);
+                input = rest;
+                continue;
+            }
+
+            let n = min(RATE - self.pos, input.len());
+            for (i, &b) in input[..n].iter().enumerate() {
+                self.xor_byte(self.pos + i, b);
+            }
+            self.pos += n;
+            input = &input[n..];
+            if self.pos == RATE {
+                lanes::keccak(&mut self.state);
+                self.pos = 0;
+            }
+        }
+    }
+
+    /// Pad with the domain separation byte `ds` and start squeezing.
+    fn finalize(mut self, ds: u8) -> Reader {
+        self.xor_byte(self.pos, ds);
+        self.xor_byte(RATE - 1, 0x80);
+        lanes::keccak(&mut self.state);
+        Reader {
+            state: self.state,
+            pos: 0,
+        }
+    }
+}
+
+/// `length_encode(x)`: the big-endian bytes of `x`, without leading zeros,
+/// followed by their count.
+fn length_encode(x: u64, buf: &mut [u8; 9]) -> &[u8] {
+    let n = 8 - x.leading_zeros() as usize / 8;
+    buf[..n].copy_from_slice(&x.to_be_bytes()[8 - n..]);
+    buf[n] = n as u8;
+    &buf[..=n]
+}
+
 /// The KangarooTwelve extendable-output function (XOF).
-#[derive(Debug, Default)]
+///
+/// Chunks are hashed one after another and their chaining values are fed
+/// straight into the final node, so neither the input nor the chaining
+/// values are buffered.
+#[derive(Clone, Debug, Default)]
 pub struct KangarooTwelve {
-    /// Input to be processed
-    // TODO(tarcieri): don't store input in a `Vec`
-    buffer: Vec<u8>,
+    /// Receives the first chunk and then the chaining values
+    final_node: TurboShake128,
+
+    /// Hashes the current chunk after the first one
+    leaf: TurboShake128,
+
+    /// Bytes in the current chunk
+    chunk_len: usize,
+
+    /// Chaining values absorbed so far, `None` while in the first chunk
+    leaf_count: Option<u64>,
 
     /// Customization string to apply
-    // TODO(tarcieri): don't store customization in a `Vec`
     customization: Vec<u8>,
 }
 
 impl KangarooTwelve {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "KangarooTwelve";
+
     /// Create a new [`KangarooTwelve`] instance.
     pub fn new() -> Self {
         Self::default()
@@ -51,16 +134,59 @@ impl KangarooTwelve {
     /// Create a new [`KangarooTwelve`] instance with the given customization.
     pub fn new_with_customization(customization: impl AsRef<[u8]>) -> Self {
         Self {
-            buffer: Vec::new(),
             customization: customization.as_ref().into(),
+            ..Self::default()
+        }
+    }
+
+    fn absorb(&mut self, mut input: &[u8]) {
+        while !input.is_empty() {
+            // a chunk is only closed once more input follows it, since a
+            // message of a single chunk is hashed differently
+            if self.chunk_len == CHUNK_SIZE {
+                match self.leaf_count {
+                    None => {
+                        self.final_node.absorb(&[0x03, 0, 0, 0, 0, 0, 0, 0]);
+                        self.leaf_count = Some(0);
+                    }
+                    Some(_) => self.finish_leaf(),
+                }
+                self.chunk_len = 0;
+            }
+
+            let n = min(CHUNK_SIZE - self.chunk_len, input.len());
+            let (chunk, rest) = input.split_at(n);
+            match self.leaf_count {
+                None => self.final_node.absorb(chunk),
+                Some(_) => self.leaf.absorb(chunk),
+            }
+            self.chunk_len += n;
+            input = rest;
+        }
+    }
+
+    fn finish_leaf(&mut self) {
+        let mut cv = [0u8; CV_SIZE];
+        mem::take(&mut self.leaf)
+            .finalize(LEAF_DOMAIN_SEP)
+            .read(&mut cv);
+        self.final_node.absorb(&cv);
+        if let Some(n) = self.leaf_count.as_mut() {
+            *n += 1;
         }
     }
 }
 
+impl fmt::Display for KangarooTwelve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl Update for KangarooTwelve {
     /// Input data into the hash function
     fn update(&mut self, bytes: impl AsRef<[u8]>) {
-        self.buffer.extend_from_slice(bytes.as_ref());
+        self.absorb(bytes.as_ref());
     }
 }
 
@@ -68,179 +194,53 @@ impl ExtendableOutputDirty for KangarooTwelve {
     type Reader = Reader;
 
     fn finalize_xof_dirty(&mut self) -> Self::Reader {
-        let mut buffer = vec![];
-        let mut customization = vec![];
-
-        mem::swap(&mut self.buffer, &mut buffer);
-        mem::swap(&mut self.customization, &mut customization);
-
-        Reader {
-            buffer,
-            customization,
-            finished: false,
-        }
+        let customization = mem::take(&mut self.customization);
+        let mut buf = [0u8; 9];
+        self.absorb(&customization);
+        self.absorb(length_encode(customization.len() as u64, &mut buf));
+        self.customization = customization;
+
+        let ds = match self.leaf_count {
+            None => SINGLE_NODE_DOMAIN_SEP,
+            Some(leaf_count) => {
+                self.finish_leaf();
+                self.final_node
+                    .absorb(length_encode(leaf_count + 1, &mut buf));
+                self.final_node.absorb(b"\xFF\xFF");
+                FINAL_NODE_DOMAIN_SEP
+            }
+        };
+        mem::take(&mut self.final_node).finalize(ds)
     }
 }
 
 impl Reset for KangarooTwelve {
     fn reset(&mut self) {
-        self.buffer.clear();
+        let customization = mem::take(&mut self.customization);
+        *self = Self {
+            customization,
+            ..Self::default()
+        };
     }
 }
 
 /// Extensible output reader.
-///
-/// NOTE: this presently only supports one invocation and will *panic* if
-/// [`XofReader::read`] is invoked on it multiple times.
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct Reader {
-    /// Input to be processed
-    // TODO(tarcieri): don't store input in a `Vec`
-    buffer: Vec<u8>,
-
-    /// Customization string to apply
-    // TODO(tarcieri): don't store customization in a `Vec`
-    customization: Vec<u8>,
-
-    /// Has the XOF output already been consumed?
-    // TODO(tarcieri): allow `XofReader::result` to be called multiple times
-    finished: bool,
+    state: [u64; 25],
+    pos: usize,
 }
 
-// TODO(tarcieri): factor more of this logic into the `KangarooTwelve` type
 impl XofReader for Reader {
-    /// Get the resulting output of the function.
-    ///
-    /// Panics if called multiple times on the same instance (TODO: don't panic!)
+    /// Get the next bytes of the XOF output.
     fn read(&mut self, output: &mut [u8]) {
-        assert!(
-            !self.finished,
-            "not yet implemented: multiple XofReader::read invocations unsupported"
-        );
-
-        let b = 8192;
-        let c = 256;
-
-        let mut slice = Vec::new(); // S
-        slice.extend_from_slice(&self.buffer);
-        slice.extend_from_slice(&self.customization);
-        slice.extend_from_slice(&right_encode(self.customization.len())[..]);
-
-        // === Cut the input string into chunks of b bytes ===
-        let n = (slice.len() + b - 1) / b;
-        let mut slices = Vec::with_capacity(n); // Si
-        for i in 0..n {
-            let ub = min((i + 1) * b, slice.len());
-            slices.push(&slice[i * b..ub]);
-        }
-
-        // TODO(tarcieri): get rid of intermediate output buffer
-        let tmp_buffer = if n == 1 {
-            // === Process the tree with only a final node ===
-            f(slices[0], 0x07, output.len())
-        } else {
-            // === Process the tree with kangaroo hopping ===
-            // TODO: in parallel
-            let mut intermediate = Vec::with_capacity(n - 1); // CVi
-            for i in 0..n - 1 {
-                intermediate.push(f(slices[i + 1], 0x0B, c / 8));
+        for o in output.iter_mut() {
+            if self.pos == RATE {
+                lanes::keccak(&mut self.state);
+                self.pos = 0;
             }
-
-            let mut node_star = Vec::new();
-            node_star.extend_from_slice(slices[0]);
-            node_star.extend_from_slice(&[3, 0, 0, 0, 0, 0, 0, 0]);
-
-            #[allow(clippy::needless_range_loop)]
-            for i in 0..n - 1 {
-                node_star.extend_from_slice(&intermediate[i][..]);
-            }
-
-            node_star.extend_from_slice(&right_encode(n - 1));
-            node_star.extend_from_slice(b"\xFF\xFF");
-
-            f(&node_star[..], 0x06, output.len())
-        };
-
-        output.copy_from_slice(&tmp_buffer);
-        self.finished = true;
-    }
-}
-
-fn f(input: &[u8], suffix: u8, mut output_len: usize) -> Vec<u8> {
-    let mut state = [0u8; 200];
-    let max_block_size = 1344 / 8; // r, also known as rate in bytes
-
-    // === Absorb all the input blocks ===
-    // We unroll first loop, which allows simple copy
-    let mut block_size = min(input.len(), max_block_size);
-    state[0..block_size].copy_from_slice(&input[0..block_size]);
-
-    let mut offset = block_size;
-    while offset < input.len() {
-        keccak(&mut state);
-        block_size = min(input.len() - offset, max_block_size);
-        for i in 0..block_size {
-            // TODO: is this sufficiently optimisable or better to convert to u64 first?
-            state[i] ^= input[i + offset];
-        }
-        offset += block_size;
-    }
-    if block_size == max_block_size {
-        // TODO: condition is nearly always false; tests pass without this.
-        // Why is it here?
-        keccak(&mut state);
-        block_size = 0;
-    }
-
-    // === Do the padding and switch to the squeezing phase ===
-    state[block_size] ^= suffix;
-    if ((suffix & 0x80) != 0) && (block_size == (max_block_size - 1)) {
-        // TODO: condition is almost always false — in fact tests pass without
-        // this block! So why is it here?
-        keccak(&mut state);
-    }
-    state[max_block_size - 1] ^= 0x80;
-    keccak(&mut state);
-
-    // === Squeeze out all the output blocks ===
-    let mut output = Vec::with_capacity(output_len);
-    while output_len > 0 {
-        block_size = min(output_len, max_block_size);
-        output.extend_from_slice(&state[0..block_size]);
-        output_len -= block_size;
-        if output_len > 0 {
-            keccak(&mut state);
+            *o = (self.state[self.pos / 8] >> (8 * (self.pos % 8))) as u8;
+            self.pos += 1;
         }
     }
-    output
-}
-
-fn keccak(state: &mut [u8; 200]) {
-    let mut lanes = [0u64; 25];
-    let mut y;
-    for x in 0..5 {
-        FOR5!(y, 5, {
-            let pos = 8 * (x + y);
-            lanes[x + y] = u64::from_le_bytes(state[pos..(pos + 8)].try_into().unwrap());
-        });
-    }
-    lanes::keccak(&mut lanes);
-    for x in 0..5 {
-        FOR5!(y, 5, {
-            let i = 8 * (x + y);
-            state[i..i + 8].copy_from_slice(&lanes[x + y].to_le_bytes());
-        });
-    }
-}
-
-fn right_encode(mut x: usize) -> Vec<u8> {
-    let mut slice = Vec::new();
-    while x > 0 {
-        slice.push((x % 256) as u8);
-        x /= 256;
-    }
-    slice.reverse();
-    let len = slice.len();
-    slice.push(len as u8);
-    slice
 }
@@ -1,6 +1,6 @@
 use core::iter;
 use k12::{
-    digest::{ExtendableOutput, Update},
+    digest::{ExtendableOutput, Reset, Update, XofReader},
     KangarooTwelve,
 };
 
@@ -111,3 +111,77 @@ fn pat_c() {
         assert_eq!(result, read_bytes(expected[i as usize]));
     }
 }
+
+#[test]
+fn chunk_boundaries() {
+    // Source: RFC 9861, Section 5
+    let ptn = |n: usize| -> Vec<u8> { (0..n).map(|j| (j % 251) as u8).collect() };
+    let cases = [
+        (
+            8191,
+            0,
+            "1b 57 76 36 f7 23 64 3e 99 0c c7 d6 a6 59 83 74 36 fd 6a 10
+                36 26 60 0e b8 30 1c d1 db e5 53 d6",
+        ),
+        (
+            8192,
+            0,
+            "48 f2 56 f6 77 2f 9e df b6 a8 b6 61 ec 92 dc 93 b9 5e bd 05
+                a0 8a 17 b3 9a e3 49 08 70 c9 26 c3",
+        ),
+        (
+            8192,
+            8189,
+            "3e d1 2f 70 fb 05 dd b5 86 89 51 0a b3 e4 d2 3c 6c 60 33 84
+                9a a0 1e 1d 8c 22 0a 29 7f ed cd 0b",
+        ),
+        (
+            8192,
+            8190,
+            "6a 7c 1b 6a 5c d0 d8 c9 ca 94 3a 4a 21 6c c6 46 04 55 9a 2e
+                a4 5f 78 57 0a 15 25 3d 67 ba 00 ae",
+        ),
+    ];
+    for &(m, c, expected) in cases.iter() {
+        let result = KangarooTwelve::new_with_customization(ptn(c))
+            .chain(ptn(m))
+            .finalize_boxed(32);
+        assert_eq!(result, read_bytes(expected));
+    }
+}
+
+#[test]
+fn incremental_reset_and_reads() {
+    let m: Vec<u8> = (0..17 * 17 * 17).map(|j| (j % 251) as u8).collect();
+    let mut hasher = KangarooTwelve::new_with_customization(b"abc");
+    hasher.update(b"garbage");
+    Reset::reset(&mut hasher);
+    for chunk in m.chunks(1000) {
+        hasher.update(chunk);
+    }
+
+    let mut expected = [0u8; 300];
+    KangarooTwelve::new_with_customization(b"abc")
+        .chain(&m)
+        .finalize_xof()
+        .read(&mut expected);
+    let mut reader = hasher.finalize_xof();
+    let mut out = [0u8; 300];
+    for chunk in out.chunks_mut(7) {
+        reader.read(chunk);
+    }
+    assert_eq!(out[..], expected[..]);
+}
+
+#[test]
+fn k12_alg_name() {
+    assert_eq!(k12::KangarooTwelve::ALG_NAME, "KangarooTwelve");
+}
+
+#[test]
+fn k12_display() {
+    assert_eq!(
+        format!("{}", k12::KangarooTwelve::default()),
+        "KangarooTwelve"
+    );
+}
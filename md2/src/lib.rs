@@ -36,7 +36,9 @@ extern crate std;
 
 pub use digest::{self, Digest};
 
-use block_buffer::{block_padding::Pkcs7, BlockBuffer};
+use block_buffer::block_padding::{Pkcs7, ZeroPadding};
+use block_buffer::BlockBuffer;
+use core::fmt;
 use digest::{consts::U16, generic_array::GenericArray};
 use digest::{BlockInput, FixedOutputDirty, Reset, Update};
 
@@ -59,6 +61,9 @@ impl Default for Md2State {
     }
 }
 
+/// Size in bytes of a snapshot created by [`Md2::serialize_state`].
+pub const MD2_SNAPSHOT_LEN: usize = 48 + 16 + 1 + 16;
+
 /// The MD2 hasher
 #[derive(Clone, Default)]
 pub struct Md2 {
@@ -92,6 +97,67 @@ impl Md2State {
     }
 }
 
+impl Md2 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "MD2";
+
+    /// Serialize the hasher state, including input which does not fill a
+    /// complete block yet.
+    ///
+    /// The snapshot consists of the 48-byte state `X`, the 16-byte
+    /// checksum, the number of buffered bytes as a single byte and the
+    /// buffered bytes zero-padded to the block size.
+    pub fn serialize_state(&self) -> [u8; MD2_SNAPSHOT_LEN] {
+        let mut out = [0u8; MD2_SNAPSHOT_LEN];
+        let (x, rest) = out.split_at_mut(48);
+        x.copy_from_slice(&self.state.x);
+        let (checksum, rest) = rest.split_at_mut(16);
+        checksum.copy_from_slice(&self.state.checksum);
+
+        rest[0] = self.buffer.position() as u8;
+        // zero padding a copy of the buffer yields the buffered bytes
+        // followed by zeros, which is how the snapshot stores them
+        let mut buffer = self.buffer.clone();
+        let block = buffer
+            .pad_with::<ZeroPadding>()
+            .expect("we never use input_lazy");
+        rest[1..].copy_from_slice(block);
+        out
+    }
+
+    /// Restore a hasher from a snapshot created by `serialize_state`.
+    ///
+    /// Returns `None` if `bytes` is not [`MD2_SNAPSHOT_LEN`] bytes long or
+    /// is malformed.
+    pub fn deserialize_state(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != MD2_SNAPSHOT_LEN {
+            return None;
+        }
+        let (x, rest) = bytes.split_at(48);
+        let (checksum, rest) = rest.split_at(16);
+        let pos = rest[0] as usize;
+        if pos >= 16 {
+            return None;
+        }
+
+        let mut hasher = Md2::default();
+        hasher.state.x.copy_from_slice(x);
+        hasher.state.checksum.copy_from_slice(checksum);
+        // less than a block, so nothing gets processed
+        let s = &mut hasher.state;
+        hasher
+            .buffer
+            .input_block(&rest[1..1 + pos], |d| s.process_block(d));
+        Some(hasher)
+    }
+}
+
+impl fmt::Display for Md2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Md2 {
     type BlockSize = U16;
 }
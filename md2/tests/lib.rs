@@ -9,3 +9,16 @@ fn md2_1million_a() {
     let output = include_bytes!("data/one_million_a.bin");
     one_million_a::<md2::Md2>(output);
 }
+
+#[test]
+fn md2_alg_name() {
+    assert_eq!(md2::Md2::ALG_NAME, "MD2");
+}
+
+#[test]
+fn md2_display() {
+    extern crate std;
+    use std::format;
+
+    assert_eq!(format!("{}", md2::Md2::default()), "MD2");
+}
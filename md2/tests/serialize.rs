@@ -0,0 +1,24 @@
+use md2::{Digest, Md2, MD2_SNAPSHOT_LEN};
+
+#[test]
+fn md2_serialize_state() {
+    let data: Vec<u8> = (0..100u32).map(|i| i as u8).collect();
+    // around the 16-byte block boundaries
+    for &split in &[1, 15, 16, 31] {
+        let mut hasher = Md2::new();
+        hasher.update(&data[..split]);
+        let snapshot = hasher.serialize_state();
+        assert_eq!(snapshot.len(), MD2_SNAPSHOT_LEN);
+
+        let mut resumed = Md2::deserialize_state(&snapshot).unwrap();
+        assert_eq!(resumed.serialize_state()[..], snapshot[..]);
+        resumed.update(&data[split..]);
+        assert_eq!(resumed.finalize(), Md2::digest(&data));
+    }
+
+    let snapshot = Md2::new().serialize_state();
+    assert!(Md2::deserialize_state(&snapshot[1..]).is_none());
+    let mut bad_pos = snapshot;
+    bad_pos[MD2_SNAPSHOT_LEN - 17] = 16;
+    assert!(Md2::deserialize_state(&bad_pos).is_none());
+}
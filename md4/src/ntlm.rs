@@ -0,0 +1,59 @@
+//! NTLM password hashes.
+//!
+//! Both hashes are trivially brute-forced and provided for interoperability
+//! with legacy Windows authentication protocols only.
+use crate::Md4;
+use digest::{FixedOutput, Update};
+
+/// Compute the NT hash (NTOWFv1) of `password`, i.e. MD4 of its UTF-16LE
+/// encoding.
+#[deprecated(note = "NTLM is cryptographically broken")]
+pub fn ntlm_hash(password: &str) -> [u8; 16] {
+    let mut hasher = Md4::default();
+    for c in password.encode_utf16() {
+        hasher.update(c.to_le_bytes());
+    }
+    hasher.finalize_fixed().into()
+}
+
+/// Compute the LAN Manager hash (LMOWFv1) of `password`.
+///
+/// The password is upper-cased, truncated or zero-padded to 14 bytes and
+/// each 7-byte half is used as a DES key to encrypt `"KGS!@#$%"`.
+#[cfg(feature = "des")]
+#[deprecated(note = "NTLM is cryptographically broken")]
+pub fn lm_hash(password: &str) -> [u8; 16] {
+    use des::cipher::{generic_array::GenericArray, BlockEncrypt, NewBlockCipher};
+    use des::Des;
+
+    const MAGIC: &[u8; 8] = b"KGS!@#$%";
+
+    let mut key = [0u8; 14];
+    for (k, b) in key.iter_mut().zip(password.bytes()) {
+        *k = b.to_ascii_uppercase();
+    }
+
+    let mut out = [0u8; 16];
+    for (half, chunk) in key.chunks_exact(7).zip(out.chunks_exact_mut(8)) {
+        let cipher = Des::new(GenericArray::from_slice(&des_key(half)));
+        let block = GenericArray::from_mut_slice(chunk);
+        block.copy_from_slice(MAGIC);
+        cipher.encrypt_block(block);
+    }
+    out
+}
+
+/// Spread 56 key bits over 8 bytes, leaving the (ignored) parity bits unset.
+#[cfg(feature = "des")]
+fn des_key(k: &[u8]) -> [u8; 8] {
+    [
+        k[0] & 0xfe,
+        (k[0] << 7) | (k[1] >> 1),
+        (k[1] << 6) | (k[2] >> 2),
+        (k[2] << 5) | (k[3] >> 3),
+        (k[3] << 4) | (k[4] >> 4),
+        (k[4] << 3) | (k[5] >> 5),
+        (k[5] << 2) | (k[6] >> 6),
+        k[6] << 1,
+    ]
+}
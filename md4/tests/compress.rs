@@ -0,0 +1,22 @@
+#![cfg(feature = "compress")]
+
+use digest::generic_array::GenericArray;
+use md4::{compress, Digest, Md4};
+
+#[test]
+fn compress_padded_block() {
+    // "abc" padded into a single block, bit length in the last 8 bytes
+    let mut block = GenericArray::default();
+    block[..3].copy_from_slice(b"abc");
+    block[3] = 0x80;
+    block[56] = 24;
+
+    let mut state = [0x6745_2301, 0xEFCD_AB89, 0x98BA_DCFE, 0x1032_5476];
+    compress(&mut state, &[block]);
+
+    let mut out = [0u8; 16];
+    for (chunk, v) in out.chunks_exact_mut(4).zip(state.iter()) {
+        chunk.copy_from_slice(&v.to_le_bytes());
+    }
+    assert_eq!(out[..], Md4::digest(b"abc")[..]);
+}
@@ -10,3 +10,45 @@ fn md4_1million_a() {
     let output = include_bytes!("data/one_million_a.bin");
     one_million_a::<md4::Md4>(output);
 }
+
+#[test]
+fn md4_alg_name() {
+    assert_eq!(md4::Md4::ALG_NAME, "MD4");
+}
+
+#[test]
+fn md4_display() {
+    extern crate std;
+    use std::format;
+
+    assert_eq!(format!("{}", md4::Md4::default()), "MD4");
+}
+
+#[test]
+fn md4_rfc1320() {
+    use digest::Digest;
+    use hex_literal::hex;
+
+    // RFC 1320, Appendix A.5
+    let vectors: [(&[u8], [u8; 16]); 7] = [
+        (b"", hex!("31d6cfe0d16ae931b73c59d7e0c089c0")),
+        (b"a", hex!("bde52cb31de33e46245e05fbdbd6fb24")),
+        (b"abc", hex!("a448017aaf21d8525fc10ae87aa6729d")),
+        (b"message digest", hex!("d9130a8164549fe818874806e1c7014b")),
+        (
+            b"abcdefghijklmnopqrstuvwxyz",
+            hex!("d79e1c308aa5bbcdeea8ed63df412da9"),
+        ),
+        (
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+            hex!("043f8582f241db351ce627e153e7f0e4"),
+        ),
+        (
+            b"12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+            hex!("e33b4ddc9c38f2199c3e7b164fcc0536"),
+        ),
+    ];
+    for (input, output) in vectors.iter() {
+        assert_eq!(md4::Md4::digest(input)[..], output[..]);
+    }
+}
@@ -0,0 +1,31 @@
+//! Test vectors from [MS-NLMP] § 4.2.2.1 and common reference values
+#![allow(deprecated)]
+
+use hex_literal::hex;
+
+#[test]
+fn ntlm_hash() {
+    assert_eq!(md4::ntlm_hash(""), hex!("31d6cfe0d16ae931b73c59d7e0c089c0"));
+    assert_eq!(
+        md4::ntlm_hash("password"),
+        hex!("8846f7eaee8fb117ad06bdd830b7586c")
+    );
+    assert_eq!(
+        md4::ntlm_hash("Password"),
+        hex!("a4f49c406510bdcab6824ee7c30fd852")
+    );
+}
+
+#[cfg(feature = "des")]
+#[test]
+fn lm_hash() {
+    assert_eq!(md4::lm_hash(""), hex!("aad3b435b51404eeaad3b435b51404ee"));
+    assert_eq!(
+        md4::lm_hash("Password"),
+        hex!("e52cac67419a9a224a3b108f3fa6cb6d")
+    );
+    assert_eq!(
+        md4::lm_hash("password"),
+        hex!("e52cac67419a9a224a3b108f3fa6cb6d")
+    );
+}
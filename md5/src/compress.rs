@@ -0,0 +1,11 @@
+#[cfg(feature = "asm")]
+pub(crate) use md5_asm::compress;
+#[cfg(not(feature = "asm"))]
+mod soft;
+#[cfg(not(feature = "asm"))]
+pub(crate) use soft::compress;
+
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "simd")]
+pub use simd::compress_4way;
@@ -0,0 +1,351 @@
+//! Four independent MD5 compressions interleaved in the 32-bit lanes of a
+//! vector.
+//!
+//! SSE2 is used on x86 and NEON on AArch64 when enabled at compile time,
+//! which is the default for `x86_64` and `aarch64` targets. Elsewhere the
+//! lanes are plain arrays.
+
+#![allow(clippy::many_single_char_names)]
+
+use crate::consts::RC;
+use core::convert::TryInto;
+
+macro_rules! step {
+    (
+        $a:ident, $b:ident, $c:ident, $d:ident,
+        $f:ident, $m:expr, $t:expr, $s:expr
+    ) => {
+        let x = add(add($a, $f($b, $c, $d)), add($m, splat(RC[$t])));
+        $a = add($b, rotl!(x, $s));
+    };
+}
+
+/// Defines `compress_4way` on top of the vector type `V` and the `splat`,
+/// `from_lanes`, `to_lanes`, `add`, `and`, `or`, `xor`, `andnot` and `rotl!`
+/// operations of a backend.
+macro_rules! compress_4way_fn {
+    () => {
+        fn ff(x: V, y: V, z: V) -> V {
+            or(and(x, y), andnot(x, z))
+        }
+
+        fn gg(x: V, y: V, z: V) -> V {
+            or(and(x, z), andnot(z, y))
+        }
+
+        fn hh(x: V, y: V, z: V) -> V {
+            xor(xor(x, y), z)
+        }
+
+        fn ii(x: V, y: V, z: V) -> V {
+            xor(y, or(x, xor(z, splat(u32::MAX))))
+        }
+
+        /// MD5 compression function applied to four independent states, each
+        /// processing one block.
+        ///
+        /// Lane `i` of the computation compresses `blocks[i]` into
+        /// `states[i]`, so the results are the same as compressing each block
+        /// on its own. No padding is applied.
+        pub fn compress_4way(states: &mut [[u32; 4]; 4], blocks: &[[u8; 64]; 4]) {
+            let mut m = [splat(0); 16];
+            for (j, m) in m.iter_mut().enumerate() {
+                let mut lanes = [0u32; 4];
+                for (lane, block) in lanes.iter_mut().zip(blocks.iter()) {
+                    *lane = u32::from_le_bytes(block[4 * j..4 * j + 4].try_into().unwrap());
+                }
+                *m = from_lanes(lanes);
+            }
+            let mut init = [splat(0); 4];
+            for (k, v) in init.iter_mut().enumerate() {
+                *v = from_lanes([states[0][k], states[1][k], states[2][k], states[3][k]]);
+            }
+            let [mut a, mut b, mut c, mut d] = init;
+
+            // round 1
+            step!(a, b, c, d, ff, m[0], 0, 7);
+            step!(d, a, b, c, ff, m[1], 1, 12);
+            step!(c, d, a, b, ff, m[2], 2, 17);
+            step!(b, c, d, a, ff, m[3], 3, 22);
+            step!(a, b, c, d, ff, m[4], 4, 7);
+            step!(d, a, b, c, ff, m[5], 5, 12);
+            step!(c, d, a, b, ff, m[6], 6, 17);
+            step!(b, c, d, a, ff, m[7], 7, 22);
+            step!(a, b, c, d, ff, m[8], 8, 7);
+            step!(d, a, b, c, ff, m[9], 9, 12);
+            step!(c, d, a, b, ff, m[10], 10, 17);
+            step!(b, c, d, a, ff, m[11], 11, 22);
+            step!(a, b, c, d, ff, m[12], 12, 7);
+            step!(d, a, b, c, ff, m[13], 13, 12);
+            step!(c, d, a, b, ff, m[14], 14, 17);
+            step!(b, c, d, a, ff, m[15], 15, 22);
+
+            // round 2
+            step!(a, b, c, d, gg, m[1], 16, 5);
+            step!(d, a, b, c, gg, m[6], 17, 9);
+            step!(c, d, a, b, gg, m[11], 18, 14);
+            step!(b, c, d, a, gg, m[0], 19, 20);
+            step!(a, b, c, d, gg, m[5], 20, 5);
+            step!(d, a, b, c, gg, m[10], 21, 9);
+            step!(c, d, a, b, gg, m[15], 22, 14);
+            step!(b, c, d, a, gg, m[4], 23, 20);
+            step!(a, b, c, d, gg, m[9], 24, 5);
+            step!(d, a, b, c, gg, m[14], 25, 9);
+            step!(c, d, a, b, gg, m[3], 26, 14);
+            step!(b, c, d, a, gg, m[8], 27, 20);
+            step!(a, b, c, d, gg, m[13], 28, 5);
+            step!(d, a, b, c, gg, m[2], 29, 9);
+            step!(c, d, a, b, gg, m[7], 30, 14);
+            step!(b, c, d, a, gg, m[12], 31, 20);
+
+            // round 3
+            step!(a, b, c, d, hh, m[5], 32, 4);
+            step!(d, a, b, c, hh, m[8], 33, 11);
+            step!(c, d, a, b, hh, m[11], 34, 16);
+            step!(b, c, d, a, hh, m[14], 35, 23);
+            step!(a, b, c, d, hh, m[1], 36, 4);
+            step!(d, a, b, c, hh, m[4], 37, 11);
+            step!(c, d, a, b, hh, m[7], 38, 16);
+            step!(b, c, d, a, hh, m[10], 39, 23);
+            step!(a, b, c, d, hh, m[13], 40, 4);
+            step!(d, a, b, c, hh, m[0], 41, 11);
+            step!(c, d, a, b, hh, m[3], 42, 16);
+            step!(b, c, d, a, hh, m[6], 43, 23);
+            step!(a, b, c, d, hh, m[9], 44, 4);
+            step!(d, a, b, c, hh, m[12], 45, 11);
+            step!(c, d, a, b, hh, m[15], 46, 16);
+            step!(b, c, d, a, hh, m[2], 47, 23);
+
+            // round 4
+            step!(a, b, c, d, ii, m[0], 48, 6);
+            step!(d, a, b, c, ii, m[7], 49, 10);
+            step!(c, d, a, b, ii, m[14], 50, 15);
+            step!(b, c, d, a, ii, m[5], 51, 21);
+            step!(a, b, c, d, ii, m[12], 52, 6);
+            step!(d, a, b, c, ii, m[3], 53, 10);
+            step!(c, d, a, b, ii, m[10], 54, 15);
+            step!(b, c, d, a, ii, m[1], 55, 21);
+            step!(a, b, c, d, ii, m[8], 56, 6);
+            step!(d, a, b, c, ii, m[15], 57, 10);
+            step!(c, d, a, b, ii, m[6], 58, 15);
+            step!(b, c, d, a, ii, m[13], 59, 21);
+            step!(a, b, c, d, ii, m[4], 60, 6);
+            step!(d, a, b, c, ii, m[11], 61, 10);
+            step!(c, d, a, b, ii, m[2], 62, 15);
+            step!(b, c, d, a, ii, m[9], 63, 21);
+
+            let res = [
+                add(a, init[0]),
+                add(b, init[1]),
+                add(c, init[2]),
+                add(d, init[3]),
+            ];
+            for (k, v) in res.iter().enumerate() {
+                for (state, w) in states.iter_mut().zip(to_lanes(*v).iter()) {
+                    state[k] = *w;
+                }
+            }
+        }
+    };
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+mod backend {
+    // SAFETY: SSE2 is enabled at compile time, so the intrinsics are always
+    // available
+    #![allow(unsafe_code)]
+
+    use super::*;
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    type V = __m128i;
+
+    macro_rules! rotl {
+        ($x:expr, $s:expr) => {
+            unsafe { _mm_or_si128(_mm_slli_epi32($x, $s), _mm_srli_epi32($x, 32 - $s)) }
+        };
+    }
+
+    #[inline(always)]
+    fn splat(x: u32) -> V {
+        unsafe { _mm_set1_epi32(x as i32) }
+    }
+
+    #[inline(always)]
+    fn from_lanes(l: [u32; 4]) -> V {
+        unsafe { _mm_setr_epi32(l[0] as i32, l[1] as i32, l[2] as i32, l[3] as i32) }
+    }
+
+    #[inline(always)]
+    fn to_lanes(v: V) -> [u32; 4] {
+        let mut l = [0u32; 4];
+        unsafe { _mm_storeu_si128(l.as_mut_ptr() as *mut V, v) };
+        l
+    }
+
+    #[inline(always)]
+    fn add(a: V, b: V) -> V {
+        unsafe { _mm_add_epi32(a, b) }
+    }
+
+    #[inline(always)]
+    fn and(a: V, b: V) -> V {
+        unsafe { _mm_and_si128(a, b) }
+    }
+
+    #[inline(always)]
+    fn or(a: V, b: V) -> V {
+        unsafe { _mm_or_si128(a, b) }
+    }
+
+    #[inline(always)]
+    fn xor(a: V, b: V) -> V {
+        unsafe { _mm_xor_si128(a, b) }
+    }
+
+    /// `!a & b`
+    #[inline(always)]
+    fn andnot(a: V, b: V) -> V {
+        unsafe { _mm_andnot_si128(a, b) }
+    }
+
+    compress_4way_fn!();
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod backend {
+    // SAFETY: NEON is enabled at compile time, so the intrinsics are always
+    // available
+    #![allow(unsafe_code)]
+
+    use super::*;
+    use core::arch::aarch64::*;
+
+    type V = uint32x4_t;
+
+    macro_rules! rotl {
+        ($x:expr, $s:expr) => {
+            unsafe { vsliq_n_u32(vshrq_n_u32($x, 32 - $s), $x, $s) }
+        };
+    }
+
+    #[inline(always)]
+    fn splat(x: u32) -> V {
+        unsafe { vdupq_n_u32(x) }
+    }
+
+    #[inline(always)]
+    fn from_lanes(l: [u32; 4]) -> V {
+        unsafe { vld1q_u32(l.as_ptr()) }
+    }
+
+    #[inline(always)]
+    fn to_lanes(v: V) -> [u32; 4] {
+        let mut l = [0u32; 4];
+        unsafe { vst1q_u32(l.as_mut_ptr(), v) };
+        l
+    }
+
+    #[inline(always)]
+    fn add(a: V, b: V) -> V {
+        unsafe { vaddq_u32(a, b) }
+    }
+
+    #[inline(always)]
+    fn and(a: V, b: V) -> V {
+        unsafe { vandq_u32(a, b) }
+    }
+
+    #[inline(always)]
+    fn or(a: V, b: V) -> V {
+        unsafe { vorrq_u32(a, b) }
+    }
+
+    #[inline(always)]
+    fn xor(a: V, b: V) -> V {
+        unsafe { veorq_u32(a, b) }
+    }
+
+    /// `!a & b`
+    #[inline(always)]
+    fn andnot(a: V, b: V) -> V {
+        unsafe { vbicq_u32(b, a) }
+    }
+
+    compress_4way_fn!();
+}
+
+#[cfg(not(any(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+mod backend {
+    use super::*;
+
+    type V = [u32; 4];
+
+    macro_rules! rotl {
+        ($x:expr, $s:expr) => {
+            map($x, $x, |a, _| a.rotate_left($s))
+        };
+    }
+
+    #[inline(always)]
+    fn map(a: V, b: V, f: impl Fn(u32, u32) -> u32) -> V {
+        [f(a[0], b[0]), f(a[1], b[1]), f(a[2], b[2]), f(a[3], b[3])]
+    }
+
+    #[inline(always)]
+    fn splat(x: u32) -> V {
+        [x; 4]
+    }
+
+    #[inline(always)]
+    fn from_lanes(l: [u32; 4]) -> V {
+        l
+    }
+
+    #[inline(always)]
+    fn to_lanes(v: V) -> [u32; 4] {
+        v
+    }
+
+    #[inline(always)]
+    fn add(a: V, b: V) -> V {
+        map(a, b, u32::wrapping_add)
+    }
+
+    #[inline(always)]
+    fn and(a: V, b: V) -> V {
+        map(a, b, |a, b| a & b)
+    }
+
+    #[inline(always)]
+    fn or(a: V, b: V) -> V {
+        map(a, b, |a, b| a | b)
+    }
+
+    #[inline(always)]
+    fn xor(a: V, b: V) -> V {
+        map(a, b, |a, b| a ^ b)
+    }
+
+    /// `!a & b`
+    #[inline(always)]
+    fn andnot(a: V, b: V) -> V {
+        map(a, b, |a, b| !a & b)
+    }
+
+    compress_4way_fn!();
+}
+
+pub use backend::compress_4way;
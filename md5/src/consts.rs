@@ -1,7 +1,7 @@
 #![allow(clippy::unreadable_literal)]
 
 /// Round constants
-#[cfg(not(feature = "asm"))]
+#[cfg(any(not(feature = "asm"), feature = "simd"))]
 pub static RC: [u32; 64] = [
     // round 1
     0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
@@ -0,0 +1,65 @@
+//! [`core::hash::Hasher`] adapter for use with hash maps.
+
+use crate::Md5;
+use core::convert::TryInto;
+use core::hash::{BuildHasher, Hasher};
+use digest::{FixedOutput, Update};
+
+/// MD5-based [`Hasher`] for keying hash maps.
+///
+/// Key bytes are fed to MD5 and [`Hasher::finish`] returns the first 8 bytes
+/// of the digest interpreted as a little-endian `u64`.
+///
+/// This is a non-cryptographic use of MD5: it provides a well distributed
+/// hash, but no protection against HashDoS beyond what an unknown seed
+/// offers. Use [`Md5MapHasher::with_seed`] for maps keyed by untrusted input.
+#[derive(Clone, Default)]
+pub struct Md5MapHasher {
+    inner: Md5,
+}
+
+impl Md5MapHasher {
+    /// Create a new hasher with `seed` absorbed before any key bytes.
+    pub fn with_seed(seed: u64) -> Self {
+        let mut inner = Md5::default();
+        inner.update(seed.to_le_bytes());
+        Md5MapHasher { inner }
+    }
+}
+
+impl Hasher for Md5MapHasher {
+    fn finish(&self) -> u64 {
+        let res = self.inner.clone().finalize_fixed();
+        u64::from_le_bytes(res[..8].try_into().unwrap())
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.inner.update(bytes);
+    }
+}
+
+opaque_debug::implement!(Md5MapHasher);
+
+/// [`BuildHasher`] producing [`Md5MapHasher`] instances.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Md5BuildHasher {
+    seed: Option<u64>,
+}
+
+impl Md5BuildHasher {
+    /// Create a builder whose hashers are seeded with `seed`.
+    pub fn with_seed(seed: u64) -> Self {
+        Md5BuildHasher { seed: Some(seed) }
+    }
+}
+
+impl BuildHasher for Md5BuildHasher {
+    type Hasher = Md5MapHasher;
+
+    fn build_hasher(&self) -> Md5MapHasher {
+        match self.seed {
+            Some(seed) => Md5MapHasher::with_seed(seed),
+            None => Md5MapHasher::default(),
+        }
+    }
+}
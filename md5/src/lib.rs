@@ -31,25 +31,32 @@
 #![deny(unsafe_code)]
 #![warn(missing_docs, rust_2018_idioms)]
 
-#[cfg(feature = "asm")]
-extern crate md5_asm as utils;
-
 #[cfg(feature = "std")]
 extern crate std;
 
-#[cfg(not(feature = "asm"))]
-mod utils;
+mod compress;
 
+#[cfg(feature = "simd")]
+pub use crate::compress::compress_4way;
+pub use crate::hasher::{Md5BuildHasher, Md5MapHasher};
 pub use digest::{self, Digest};
+#[cfg(feature = "zeroize")]
+pub use zeroize_crate as zeroize;
 
-use crate::utils::compress;
+use crate::compress::compress;
+use core::convert::TryInto;
+use core::fmt;
 
-use block_buffer::BlockBuffer;
+use block_buffer::{block_padding::ZeroPadding, BlockBuffer};
 use digest::generic_array::typenum::{U16, U64};
 use digest::generic_array::GenericArray;
 use digest::{BlockInput, FixedOutputDirty, Reset, Update};
 
 mod consts;
+mod hasher;
+
+/// Size in bytes of a snapshot created by [`Md5::serialize_state`].
+pub const MD5_SNAPSHOT_LEN: usize = 16 + 8 + 1 + 64;
 
 /// The MD5 hasher
 #[derive(Clone)]
@@ -78,14 +85,81 @@ fn convert(d: &GenericArray<u8, U64>) -> &[u8; 64] {
 }
 
 impl Md5 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "MD5";
+
+    /// Serialize the hasher state, including input which does not fill a
+    /// complete block yet.
+    ///
+    /// The snapshot consists of the four state words, the number of
+    /// processed blocks as a `u64`, the number of buffered bytes as a single
+    /// byte and the buffered bytes zero-padded to the block size. All
+    /// integers are little-endian, like in MD5 itself.
+    pub fn serialize_state(&self) -> [u8; MD5_SNAPSHOT_LEN] {
+        let mut out = [0u8; MD5_SNAPSHOT_LEN];
+        let (words, rest) = out.split_at_mut(16);
+        for (chunk, v) in words.chunks_exact_mut(4).zip(self.state.iter()) {
+            chunk.copy_from_slice(&v.to_le_bytes());
+        }
+        let (block_len, rest) = rest.split_at_mut(8);
+        block_len.copy_from_slice(&(self.length_bytes / 64).to_le_bytes());
+
+        rest[0] = self.buffer.position() as u8;
+        // zero padding a copy of the buffer yields the buffered bytes
+        // followed by zeros, which is how the snapshot stores them
+        let mut buffer = self.buffer.clone();
+        let block = buffer
+            .pad_with::<ZeroPadding>()
+            .expect("we never use input_lazy");
+        rest[1..].copy_from_slice(block);
+        out
+    }
+
+    /// Restore a hasher from a snapshot created by `serialize_state`.
+    ///
+    /// Returns `None` if `bytes` is not [`MD5_SNAPSHOT_LEN`] bytes long or
+    /// is malformed.
+    pub fn deserialize_state(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != MD5_SNAPSHOT_LEN {
+            return None;
+        }
+        let (words, rest) = bytes.split_at(16);
+        let (block_len, rest) = rest.split_at(8);
+        let pos = rest[0] as usize;
+        if pos >= 64 {
+            return None;
+        }
+        let length_bytes = u64::from_le_bytes(block_len.try_into().unwrap())
+            .checked_mul(64)?
+            .checked_add(pos as u64)?;
+
+        let mut hasher = Md5::default();
+        for (v, chunk) in hasher.state.iter_mut().zip(words.chunks_exact(4)) {
+            *v = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        // less than a block, so nothing gets compressed
+        let s = &mut hasher.state;
+        hasher
+            .buffer
+            .input_block(&rest[1..1 + pos], |d| compress(s, convert(d)));
+        hasher.length_bytes = length_bytes;
+        Some(hasher)
+    }
+
     #[inline]
     fn finalize_inner(&mut self) {
         let s = &mut self.state;
-        let l = (self.length_bytes << 3) as u64;
+        let l = self.length_bytes << 3;
         self.buffer.len64_padding_le(l, |d| compress(s, convert(d)));
     }
 }
 
+impl fmt::Display for Md5 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Md5 {
     type BlockSize = U64;
 }
@@ -122,5 +196,30 @@ impl Reset for Md5 {
     }
 }
 
+/// Overwrites the chaining value, the length and the buffered input with
+/// zeros.
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::Zeroize for Md5 {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+        self.length_bytes.zeroize();
+        // the buffer is never full, so padding can not fail
+        if let Ok(block) = self.buffer.pad_with::<ZeroPadding>() {
+            block.as_mut_slice().zeroize();
+        }
+        self.buffer.reset();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Md5 {
+    fn drop(&mut self) {
+        zeroize_crate::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::ZeroizeOnDrop for Md5 {}
+
 opaque_debug::implement!(Md5);
 digest::impl_write!(Md5);
@@ -0,0 +1,57 @@
+#![cfg(feature = "simd")]
+
+use md5::{compress_4way, Digest, Md5};
+
+const S0: [u32; 4] = [0x6745_2301, 0xefcd_ab89, 0x98ba_dcfe, 0x1032_5476];
+
+/// Pad `msg` into whole blocks, with the bit length in the last 8 bytes.
+fn pad(msg: &[u8]) -> Vec<[u8; 64]> {
+    let mut data = msg.to_vec();
+    data.push(0x80);
+    while data.len() % 64 != 56 {
+        data.push(0);
+    }
+    data.extend_from_slice(&(8 * msg.len() as u64).to_le_bytes());
+    data.chunks_exact(64)
+        .map(|c| {
+            let mut block = [0u8; 64];
+            block.copy_from_slice(c);
+            block
+        })
+        .collect()
+}
+
+#[test]
+fn compress_4way_matches_md5() {
+    let mut x = 0x1234_5678u32;
+    let mut random = move || {
+        x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        (x >> 16) as u8
+    };
+
+    // every lane gets its own message, all of which pad to two blocks
+    for &lens in [[56, 60, 100, 119], [119, 64, 70, 57]].iter() {
+        let msgs: Vec<Vec<u8>> = lens
+            .iter()
+            .map(|&len| (0..len).map(|_| random()).collect())
+            .collect();
+        let padded: Vec<Vec<[u8; 64]>> = msgs.iter().map(|m| pad(m)).collect();
+
+        let mut states = [S0; 4];
+        for i in 0..2 {
+            let mut blocks = [[0u8; 64]; 4];
+            for (block, p) in blocks.iter_mut().zip(padded.iter()) {
+                *block = p[i];
+            }
+            compress_4way(&mut states, &blocks);
+        }
+
+        for (state, msg) in states.iter().zip(msgs.iter()) {
+            let mut out = [0u8; 16];
+            for (chunk, v) in out.chunks_exact_mut(4).zip(state.iter()) {
+                chunk.copy_from_slice(&v.to_le_bytes());
+            }
+            assert_eq!(out[..], Md5::digest(msg)[..]);
+        }
+    }
+}
@@ -10,3 +10,44 @@ fn md5_1million_a() {
     let output = include_bytes!("data/one_million_a.bin");
     one_million_a::<md5::Md5>(output);
 }
+
+#[test]
+fn md5_map_hasher() {
+    extern crate std;
+    use core::hash::{BuildHasher, Hasher};
+    use md5::{Md5BuildHasher, Md5MapHasher};
+    use std::collections::HashMap;
+
+    let mut map = HashMap::with_hasher(Md5BuildHasher::default());
+    map.insert("foo", 1);
+    map.insert("bar", 2);
+    assert_eq!(map.get("foo"), Some(&1));
+    assert_eq!(map.get("bar"), Some(&2));
+    assert_eq!(map.get("baz"), None);
+
+    // MD5("hello world") = 5eb63bbbe01eeed0...
+    let mut hasher = Md5MapHasher::default();
+    hasher.write(b"hello world");
+    assert_eq!(hasher.finish(), 0xd0ee_1ee0_bb3b_b65e);
+
+    let seeded = Md5BuildHasher::with_seed(42);
+    let mut h1 = seeded.build_hasher();
+    let mut h2 = Md5MapHasher::with_seed(42);
+    h1.write(b"hello world");
+    h2.write(b"hello world");
+    assert_eq!(h1.finish(), h2.finish());
+    assert_ne!(h1.finish(), hasher.finish());
+}
+
+#[test]
+fn md5_alg_name() {
+    assert_eq!(md5::Md5::ALG_NAME, "MD5");
+}
+
+#[test]
+fn md5_display() {
+    extern crate std;
+    use std::format;
+
+    assert_eq!(format!("{}", md5::Md5::default()), "MD5");
+}
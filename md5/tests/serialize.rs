@@ -0,0 +1,24 @@
+use md5::{Digest, Md5, MD5_SNAPSHOT_LEN};
+
+#[test]
+fn md5_serialize_state() {
+    let data: Vec<u8> = (0..300u32).map(|i| i as u8).collect();
+    // mid-block, exactly one block and several blocks plus a partial one
+    for &split in &[31, 64, 200] {
+        let mut hasher = Md5::new();
+        hasher.update(&data[..split]);
+        let snapshot = hasher.serialize_state();
+        assert_eq!(snapshot.len(), MD5_SNAPSHOT_LEN);
+
+        let mut resumed = Md5::deserialize_state(&snapshot).unwrap();
+        assert_eq!(resumed.serialize_state()[..], snapshot[..]);
+        resumed.update(&data[split..]);
+        assert_eq!(resumed.finalize(), Md5::digest(&data));
+    }
+
+    let snapshot = Md5::new().serialize_state();
+    assert!(Md5::deserialize_state(&snapshot[1..]).is_none());
+    let mut bad_pos = snapshot;
+    bad_pos[MD5_SNAPSHOT_LEN - 65] = 64;
+    assert!(Md5::deserialize_state(&bad_pos).is_none());
+}
@@ -0,0 +1,13 @@
+#![cfg(feature = "zeroize")]
+
+use md5::zeroize::Zeroize;
+use md5::{Digest, Md5, MD5_SNAPSHOT_LEN};
+
+#[test]
+fn zeroize() {
+    let mut hasher = Md5::new();
+    // a whole block and part of the next one
+    hasher.update(&[0x5a; 100][..]);
+    hasher.zeroize();
+    assert_eq!(hasher.serialize_state()[..], [0u8; MD5_SNAPSHOT_LEN][..]);
+}
@@ -0,0 +1,60 @@
+//! MGF1 mask generation function, as specified in [RFC 8017 §B.2.1][1] and
+//! used by RSA-OAEP and RSA-PSS, generic over the underlying hash.
+//!
+//! The `sha-1` and `sha2` crates wrap this for their hashes behind their
+//! `mgf` feature.
+//!
+//! [1]: https://tools.ietf.org/html/rfc8017#appendix-B.2.1
+
+#![no_std]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+pub use digest;
+
+use core::fmt;
+use digest::{generic_array::typenum::Unsigned, FixedOutput, Update};
+
+/// The requested mask is longer than `2^32` hash outputs, which would wrap
+/// the 32-bit counter.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MaskTooLong;
+
+impl fmt::Display for MaskTooLong {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("mask too long")
+    }
+}
+
+/// Write `length` bytes of `MGF1(seed)` computed with the hash `D` into the
+/// beginning of `output`.
+///
+/// The mask is `D(seed || C)` for a 32-bit big-endian counter `C` = 0, 1,
+/// 2, ... concatenated and truncated to `length` bytes.
+///
+/// Returns [`MaskTooLong`] if `length` exceeds `2^32` times the output size
+/// of `D`.
+///
+/// # Panics
+/// If `output` is shorter than `length`.
+pub fn mgf1<D>(seed: &[u8], length: usize, output: &mut [u8]) -> Result<(), MaskTooLong>
+where
+    D: Default + Update + FixedOutput,
+{
+    let out_len = D::OutputSize::to_usize();
+    if length as u64 > (out_len as u64) << 32 {
+        return Err(MaskTooLong);
+    }
+
+    for (counter, chunk) in output[..length].chunks_mut(out_len).enumerate() {
+        let mut h = D::default();
+        h.update(seed);
+        h.update((counter as u32).to_be_bytes());
+        chunk.copy_from_slice(&h.finalize_fixed()[..chunk.len()]);
+    }
+    Ok(())
+}
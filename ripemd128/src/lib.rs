@@ -0,0 +1,108 @@
+//! An implementation of the [RIPEMD-128][1] cryptographic hash.
+//!
+//! # Usage
+//!
+//! ```rust
+//! use hex_literal::hex;
+//! use ripemd128::{Ripemd128, Digest};
+//!
+//! // create a RIPEMD-128 hasher instance
+//! let mut hasher = Ripemd128::new();
+//!
+//! // process input message
+//! hasher.update(b"Hello world!");
+//!
+//! // acquire hash digest in the form of GenericArray,
+//! // which in this case is equivalent to [u8; 16]
+//! let expected = hex!("d917d92bc5591a0915f70acebbc2b126");
+//! let result = hasher.finalize();
+//! assert_eq!(&result[..], &expected[..]);
+//! ```
+//!
+//! [1]: https://en.wikipedia.org/wiki/RIPEMD
+
+#![no_std]
+#![deny(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms)]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+mod block;
+
+pub use digest::{self, Digest};
+
+use block::{process_msg_block, DIGEST_BUF_LEN, H0};
+use block_buffer::BlockBuffer;
+use core::fmt;
+use digest::{
+    consts::{U16, U64},
+    BlockInput, FixedOutputDirty, Reset, Update,
+};
+
+/// Structure representing the state of a Ripemd128 computation
+#[derive(Clone)]
+pub struct Ripemd128 {
+    h: [u32; DIGEST_BUF_LEN],
+    len: u64,
+    buffer: BlockBuffer<U64>,
+}
+
+impl Default for Ripemd128 {
+    fn default() -> Self {
+        Ripemd128 {
+            h: H0,
+            len: 0,
+            buffer: Default::default(),
+        }
+    }
+}
+
+impl Ripemd128 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "RIPEMD-128";
+}
+
+impl fmt::Display for Ripemd128 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
+impl BlockInput for Ripemd128 {
+    type BlockSize = U64;
+}
+
+impl Update for Ripemd128 {
+    fn update(&mut self, input: impl AsRef<[u8]>) {
+        let input = input.as_ref();
+        // Assumes that input.len() can be converted to u64 without overflow
+        self.len += input.len() as u64;
+        let h = &mut self.h;
+        self.buffer.input_block(input, |b| process_msg_block(h, b));
+    }
+}
+
+impl FixedOutputDirty for Ripemd128 {
+    type OutputSize = U16;
+
+    fn finalize_into_dirty(&mut self, out: &mut digest::Output<Self>) {
+        let h = &mut self.h;
+        let l = self.len << 3;
+        self.buffer.len64_padding_le(l, |b| process_msg_block(h, b));
+        for (chunk, v) in out.chunks_exact_mut(4).zip(self.h.iter()) {
+            chunk.copy_from_slice(&v.to_le_bytes());
+        }
+    }
+}
+
+impl Reset for Ripemd128 {
+    fn reset(&mut self) {
+        self.buffer.reset();
+        self.len = 0;
+        self.h = H0;
+    }
+}
+
+opaque_debug::implement!(Ripemd128);
+digest::impl_write!(Ripemd128);
@@ -0,0 +1,31 @@
+//! Test messages from the RIPEMD home page
+#![no_std]
+
+use digest::dev::{digest_test, one_million_a};
+use digest::new_test;
+use hex_literal::hex;
+
+new_test!(
+    ripemd128_main,
+    "ripemd128",
+    ripemd128::Ripemd128,
+    digest_test
+);
+
+#[test]
+fn ripemd128_1million_a() {
+    one_million_a::<ripemd128::Ripemd128>(&hex!("4a7f5723f954eba1216c9d8f6320431f"));
+}
+
+#[test]
+fn ripemd128_alg_name() {
+    assert_eq!(ripemd128::Ripemd128::ALG_NAME, "RIPEMD-128");
+}
+
+#[test]
+fn ripemd128_display() {
+    extern crate std;
+    use std::format;
+
+    assert_eq!(format!("{}", ripemd128::Ripemd128::default()), "RIPEMD-128");
+}
@@ -37,17 +37,36 @@ extern crate std;
 mod block;
 
 pub use digest::{self, Digest};
+#[cfg(feature = "zeroize")]
+pub use zeroize_crate as zeroize;
 
 use crate::block::{process_msg_block, DIGEST_BUF_LEN, H0};
-use block_buffer::BlockBuffer;
-use digest::consts::{U20, U64};
-use digest::{BlockInput, FixedOutputDirty, Reset, Update};
+use block_buffer::{block_padding::ZeroPadding, BlockBuffer};
+use core::convert::TryInto;
+use core::fmt;
+use digest::consts::{U12, U16, U20, U64};
+#[cfg(feature = "compress")]
+use digest::generic_array::GenericArray;
+use digest::{BlockInput, FixedOutputDirty, InvalidOutputSize, Reset, Update, VariableOutputDirty};
+
+/// Size in bytes of a snapshot created by [`Ripemd160::serialize_state`].
+pub const RIPEMD160_SNAPSHOT_LEN: usize = 4 * DIGEST_BUF_LEN + 8 + 1 + 64;
+
+/// Maximum supported input length in bytes (2^61 - 1, about 2.3 exabytes),
+/// so that the length in bits fits into the 64-bit padding field.
+const MAX_LEN: u64 = (1 << 61) - 1;
 
 /// Structure representing the state of a Ripemd160 computation
+///
+/// Inputs longer than 2^61 - 1 bytes are not supported. Exceeding that
+/// limit trips a debug assertion in [`Update::update`]; in release builds
+/// the length saturates and finalization panics instead of producing a
+/// wrong digest.
 #[derive(Clone)]
 pub struct Ripemd160 {
     h: [u32; DIGEST_BUF_LEN],
     len: u64,
+    overflowed: bool,
     buffer: BlockBuffer<U64>,
 }
 
@@ -56,11 +75,124 @@ impl Default for Ripemd160 {
         Ripemd160 {
             h: H0,
             len: 0,
+            overflowed: false,
             buffer: Default::default(),
         }
     }
 }
 
+impl Ripemd160 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "RIPEMD-160";
+
+    /// Create a hasher from a raw chaining value `h` after `block_len`
+    /// 64-byte blocks of input have been processed.
+    ///
+    /// Returns `None` if the message length in bits would overflow.
+    pub fn from_raw_state(h: [u32; DIGEST_BUF_LEN], block_len: u64) -> Option<Self> {
+        let len = block_len.checked_mul(64).filter(|l| l >> 61 == 0)?;
+        Some(Ripemd160 {
+            h,
+            len,
+            overflowed: false,
+            buffer: Default::default(),
+        })
+    }
+
+    /// Get the raw chaining value.
+    ///
+    /// Input which does not fill a complete block yet is not covered by it.
+    pub fn get_state(&self) -> [u32; DIGEST_BUF_LEN] {
+        self.h
+    }
+
+    /// Serialize the state of the hasher, including buffered input.
+    ///
+    /// The snapshot consists of the five state words and the number of
+    /// processed blocks, all little-endian, followed by the buffer position
+    /// and the 64-byte buffer. A hasher which exceeded the maximum input
+    /// length produces a snapshot that can not be restored.
+    pub fn serialize_state(&self) -> [u8; RIPEMD160_SNAPSHOT_LEN] {
+        let mut out = [0u8; RIPEMD160_SNAPSHOT_LEN];
+        let (words, rest) = out.split_at_mut(4 * DIGEST_BUF_LEN);
+        for (chunk, v) in words.chunks_exact_mut(4).zip(self.h.iter()) {
+            chunk.copy_from_slice(&v.to_le_bytes());
+        }
+        let (block_len, rest) = rest.split_at_mut(8);
+        let blocks = if self.overflowed { !0 } else { self.len / 64 };
+        block_len.copy_from_slice(&blocks.to_le_bytes());
+
+        rest[0] = self.buffer.position() as u8;
+        // zero padding a copy of the buffer yields the buffered bytes
+        // followed by zeros, which is how the snapshot stores them
+        let mut buffer = self.buffer.clone();
+        let block = buffer
+            .pad_with::<ZeroPadding>()
+            .expect("we never use input_lazy");
+        rest[1..].copy_from_slice(block);
+        out
+    }
+
+    /// Restore a hasher from a snapshot created by `serialize_state`.
+    ///
+    /// Returns `None` if `bytes` is not [`RIPEMD160_SNAPSHOT_LEN`] bytes long,
+    /// is malformed or the message length in bits would overflow.
+    pub fn deserialize_state(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != RIPEMD160_SNAPSHOT_LEN {
+            return None;
+        }
+        let (words, rest) = bytes.split_at(4 * DIGEST_BUF_LEN);
+        let (block_len, rest) = rest.split_at(8);
+        let pos = rest[0] as usize;
+        if pos >= 64 {
+            return None;
+        }
+        let len = u64::from_le_bytes(block_len.try_into().unwrap())
+            .checked_mul(64)?
+            .checked_add(pos as u64)
+            .filter(|&l| l <= MAX_LEN)?;
+
+        let mut hasher = Ripemd160::default();
+        for (v, chunk) in hasher.h.iter_mut().zip(words.chunks_exact(4)) {
+            *v = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        // less than a block, so nothing gets compressed
+        let h = &mut hasher.h;
+        hasher
+            .buffer
+            .input_block(&rest[1..1 + pos], |b| process_msg_block(h, b));
+        hasher.len = len;
+        Some(hasher)
+    }
+
+    /// Number of complete 64-byte blocks compressed so far.
+    #[cfg(feature = "state-inspection")]
+    pub fn blocks_processed(&self) -> u64 {
+        self.len / 64
+    }
+}
+
+/// Raw RIPEMD-160 compression function.
+///
+/// Processes a single 64-byte `block` into the chaining value `state`. The
+/// block is read as sixteen little-endian 32-bit words and `state` holds
+/// the words in the order used for the digest, which is their
+/// little-endian serialization. No padding is applied, so the caller must
+/// pad the final block of a message, including its length in bits.
+///
+/// This is a low-level "hazmat" API which provides direct access to the core
+/// functionality of RIPEMD-160.
+#[cfg(feature = "compress")]
+pub fn compress160(state: &mut [u32; 5], block: &GenericArray<u8, U64>) {
+    process_msg_block(state, block);
+}
+
+impl fmt::Display for Ripemd160 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Ripemd160 {
     type BlockSize = U64;
 }
@@ -68,24 +200,81 @@ impl BlockInput for Ripemd160 {
 impl Update for Ripemd160 {
     fn update(&mut self, input: impl AsRef<[u8]>) {
         let input = input.as_ref();
-        // Assumes that input.len() can be converted to u64 without overflow
-        self.len += input.len() as u64;
+        let len = self
+            .len
+            .checked_add(input.len() as u64)
+            .filter(|&l| l <= MAX_LEN);
+        debug_assert!(len.is_some(), "input exceeds maximum length");
+        match len {
+            Some(len) => self.len = len,
+            None => {
+                self.len = MAX_LEN;
+                self.overflowed = true;
+            }
+        }
         let h = &mut self.h;
         self.buffer.input_block(input, |b| process_msg_block(h, b));
     }
 }
 
-impl FixedOutputDirty for Ripemd160 {
-    type OutputSize = U20;
-
-    fn finalize_into_dirty(&mut self, out: &mut digest::Output<Self>) {
+impl Ripemd160 {
+    fn finalize_inner(&mut self) -> [u8; 20] {
         let h = &mut self.h;
+        assert!(!self.overflowed, "input exceeds maximum length");
         let l = self.len << 3;
         self.buffer.len64_padding_le(l, |b| process_msg_block(h, b));
 
+        let mut out = [0u8; 20];
         for (chunk, v) in out.chunks_exact_mut(4).zip(self.h.iter()) {
             chunk.copy_from_slice(&v.to_le_bytes());
         }
+        out
+    }
+
+    /// Finalize the hash without branching on the amount of padding.
+    ///
+    /// Regular finalization processes one or two blocks depending on whether
+    /// the length fits after the buffered data. This variant always
+    /// processes two blocks and selects the resulting chaining value with
+    /// bit masks, so the amount of work does not depend on the message
+    /// length.
+    #[cfg(feature = "constant-time")]
+    pub fn finalize_fixed_ct(mut self) -> digest::Output<Self> {
+        use block_buffer::block_padding::Iso7816;
+
+        let pos = self.buffer.position();
+        // `0xff` if the length fits into the first block, `0x00` otherwise
+        let mask = ((pos as u64).wrapping_sub(56) >> 63) as u8 * 0xff;
+        assert!(!self.overflowed, "input exceeds maximum length");
+        let len = (self.len << 3).to_le_bytes();
+
+        // the buffer is never full, so padding can not fail
+        let mut block1 = *self.buffer.pad_with::<Iso7816>().unwrap();
+        let mut block2 = block_buffer::generic_array::GenericArray::default();
+        for (i, b) in len.iter().enumerate() {
+            block1[56 + i] |= b & mask;
+            block2[56 + i] = b & !mask;
+        }
+
+        let mut h1 = self.h;
+        process_msg_block(&mut h1, &block1);
+        let mut h2 = h1;
+        process_msg_block(&mut h2, &block2);
+
+        let mask = u32::from(mask) * 0x0101_0101;
+        let mut out = digest::Output::<Self>::default();
+        for ((chunk, a), b) in out.chunks_exact_mut(4).zip(h1.iter()).zip(h2.iter()) {
+            chunk.copy_from_slice(&((a & mask) | (b & !mask)).to_le_bytes());
+        }
+        out
+    }
+}
+
+impl FixedOutputDirty for Ripemd160 {
+    type OutputSize = U20;
+
+    fn finalize_into_dirty(&mut self, out: &mut digest::Output<Self>) {
+        out.copy_from_slice(&self.finalize_inner());
     }
 }
 
@@ -93,9 +282,160 @@ impl Reset for Ripemd160 {
     fn reset(&mut self) {
         self.buffer.reset();
         self.len = 0;
+        self.overflowed = false;
         self.h = H0;
     }
 }
 
+/// Overwrites the chaining value, the length and the buffered input with
+/// zeros.
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::Zeroize for Ripemd160 {
+    fn zeroize(&mut self) {
+        self.h.zeroize();
+        self.len.zeroize();
+        self.overflowed.zeroize();
+        // the buffer is never full, so padding can not fail
+        if let Ok(block) = self.buffer.pad_with::<ZeroPadding>() {
+            block.as_mut_slice().zeroize();
+        }
+        self.buffer.reset();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Ripemd160 {
+    fn drop(&mut self) {
+        zeroize_crate::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::ZeroizeOnDrop for Ripemd160 {}
+
 opaque_debug::implement!(Ripemd160);
 digest::impl_write!(Ripemd160);
+
+/// RIPEMD-160 with the output truncated to a size chosen at runtime.
+///
+/// The full 160-bit hash is computed and only the first `output_size` bytes
+/// of it are returned, so `Ripemd160Var::new(20)` is equivalent to
+/// [`Ripemd160`].
+#[derive(Clone)]
+pub struct Ripemd160Var {
+    inner: Ripemd160,
+    output_size: usize,
+}
+
+impl Ripemd160Var {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "RIPEMD-160";
+}
+
+impl fmt::Display for Ripemd160Var {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
+impl BlockInput for Ripemd160Var {
+    type BlockSize = U64;
+}
+
+impl Update for Ripemd160Var {
+    fn update(&mut self, input: impl AsRef<[u8]>) {
+        Update::update(&mut self.inner, input);
+    }
+}
+
+impl VariableOutputDirty for Ripemd160Var {
+    fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+        if output_size == 0 || output_size > 20 {
+            return Err(InvalidOutputSize);
+        }
+        Ok(Ripemd160Var {
+            inner: Default::default(),
+            output_size,
+        })
+    }
+
+    fn output_size(&self) -> usize {
+        self.output_size
+    }
+
+    fn finalize_variable_dirty(&mut self, f: impl FnOnce(&[u8])) {
+        f(&self.inner.finalize_inner()[..self.output_size]);
+    }
+}
+
+impl Reset for Ripemd160Var {
+    fn reset(&mut self) {
+        Reset::reset(&mut self.inner);
+    }
+}
+
+opaque_debug::implement!(Ripemd160Var);
+digest::impl_write!(Ripemd160Var);
+
+macro_rules! impl_truncated {
+    ($name:ident, $output_size:ident, $alg_name:expr, $doc:expr) => {
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Default)]
+        #[doc=$doc]
+        pub struct $name {
+            inner: Ripemd160,
+        }
+
+        impl $name {
+            /// Canonical name of the algorithm, e.g. for logging.
+            pub const ALG_NAME: &'static str = $alg_name;
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Self::ALG_NAME)
+            }
+        }
+
+        impl BlockInput for $name {
+            type BlockSize = U64;
+        }
+
+        impl Update for $name {
+            fn update(&mut self, input: impl AsRef<[u8]>) {
+                Update::update(&mut self.inner, input);
+            }
+        }
+
+        impl FixedOutputDirty for $name {
+            type OutputSize = $output_size;
+
+            fn finalize_into_dirty(&mut self, out: &mut digest::Output<Self>) {
+                let n = out.len();
+                out.copy_from_slice(&self.inner.finalize_inner()[..n]);
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                Reset::reset(&mut self.inner);
+            }
+        }
+
+        opaque_debug::implement!($name);
+        digest::impl_write!($name);
+    };
+}
+
+impl_truncated!(
+    Ripemd160_96,
+    U12,
+    "RIPEMD-160/96",
+    "RIPEMD-160 with the output truncated to 96 bits."
+);
+impl_truncated!(
+    Ripemd160_128,
+    U16,
+    "RIPEMD-160/128",
+    "RIPEMD-160 with the output truncated to 128 bits."
+);
@@ -0,0 +1,43 @@
+#![cfg(feature = "compress")]
+
+use digest::generic_array::GenericArray;
+use ripemd160::{compress160, Digest, Ripemd160};
+
+#[test]
+fn compress160_padded_block() {
+    // "abc" padded into a single block, bit length in the last 8 bytes
+    let mut block = GenericArray::default();
+    block[..3].copy_from_slice(b"abc");
+    block[3] = 0x80;
+    block[56] = 24;
+
+    let mut state = Ripemd160::default().get_state();
+    compress160(&mut state, &block);
+    assert_eq!(
+        state,
+        [
+            0xf708_b28e,
+            0x7a98_5de0,
+            0x8e4a_049b,
+            0x87b0_c698,
+            0xfc0b_5af1
+        ]
+    );
+
+    let mut out = [0u8; 20];
+    for (chunk, v) in out.chunks_exact_mut(4).zip(state.iter()) {
+        chunk.copy_from_slice(&v.to_le_bytes());
+    }
+    assert_eq!(out[..], Ripemd160::digest(b"abc")[..]);
+}
+
+#[test]
+fn compress160_matches_raw_state() {
+    let block = GenericArray::clone_from_slice(&[0x5a; 64]);
+    let mut state = Ripemd160::default().get_state();
+    compress160(&mut state, &block);
+
+    let mut hasher = Ripemd160::new();
+    hasher.update(block);
+    assert_eq!(state, hasher.get_state());
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "constant-time")]
+
+use ripemd160::{Digest, Ripemd160};
+
+#[test]
+fn ct_finalize_matches() {
+    let data = [0x5au8; 200];
+    for n in 0..data.len() {
+        let mut h = Ripemd160::new();
+        h.update(&data[..n]);
+        assert_eq!(h.clone().finalize_fixed_ct(), h.finalize(), "length {}", n);
+    }
+}
+
+#[test]
+fn ct_padding_boundary() {
+    // 55 bytes need a single padding block, 56 bytes need two
+    let mut h55 = Ripemd160::new();
+    h55.update(&[0u8; 55][..]);
+    let mut h56 = Ripemd160::new();
+    h56.update(&[0u8; 56][..]);
+
+    let out55 = h55.clone().finalize_fixed_ct();
+    let out56 = h56.clone().finalize_fixed_ct();
+    assert_ne!(out55, out56);
+    assert_eq!(out55, h55.finalize());
+    assert_eq!(out56, h56.finalize());
+}
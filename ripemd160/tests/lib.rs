@@ -16,3 +16,77 @@ fn ripemd160_1million_a() {
     let output = include_bytes!("data/one_million_a.bin");
     one_million_a::<ripemd160::Ripemd160>(output);
 }
+
+#[test]
+fn ripemd160_var_full_size() {
+    use digest::{Digest, Update, VariableOutput};
+
+    let mut hasher = ripemd160::Ripemd160Var::new(20).unwrap();
+    hasher.update(b"Hello world!");
+    hasher.finalize_variable(|res| {
+        assert_eq!(res, &ripemd160::Ripemd160::digest(b"Hello world!")[..]);
+    });
+}
+
+#[test]
+fn ripemd160_var_truncated() {
+    use digest::{Digest, Update, VariableOutput};
+
+    let full = ripemd160::Ripemd160::digest(b"Hello world!");
+    for n in 1..=20 {
+        let mut hasher = ripemd160::Ripemd160Var::new(n).unwrap();
+        hasher.update(b"Hello world!");
+        hasher.finalize_variable(|res| assert_eq!(res, &full[..n]));
+    }
+
+    assert_eq!(
+        ripemd160::Ripemd160_96::digest(b"Hello world!")[..],
+        full[..12]
+    );
+    assert_eq!(
+        ripemd160::Ripemd160_128::digest(b"Hello world!")[..],
+        full[..16]
+    );
+}
+
+#[test]
+fn ripemd160_var_invalid_size() {
+    use digest::VariableOutput;
+
+    assert!(ripemd160::Ripemd160Var::new(0).is_err());
+    assert!(ripemd160::Ripemd160Var::new(21).is_err());
+}
+
+#[test]
+fn ripemd160_alg_names() {
+    assert_eq!(ripemd160::Ripemd160::ALG_NAME, "RIPEMD-160");
+    assert_eq!(ripemd160::Ripemd160Var::ALG_NAME, "RIPEMD-160");
+    assert_eq!(ripemd160::Ripemd160_96::ALG_NAME, "RIPEMD-160/96");
+    assert_eq!(ripemd160::Ripemd160_128::ALG_NAME, "RIPEMD-160/128");
+}
+
+#[test]
+fn ripemd160_display() {
+    extern crate std;
+    use std::format;
+
+    assert_eq!(format!("{}", ripemd160::Ripemd160::default()), "RIPEMD-160");
+    assert_eq!(
+        format!("{}", ripemd160::Ripemd160_96::default()),
+        "RIPEMD-160/96"
+    );
+}
+
+#[test]
+fn ripemd160_reset() {
+    use digest::{Digest, Reset};
+
+    let mut hasher = ripemd160::Ripemd160::new();
+    hasher.update([0x5a; 100]);
+    Reset::reset(&mut hasher);
+    hasher.update(b"Hello world!");
+    assert_eq!(
+        hasher.finalize(),
+        ripemd160::Ripemd160::digest(b"Hello world!")
+    );
+}
@@ -0,0 +1,58 @@
+use ripemd160::{Digest, Ripemd160, RIPEMD160_SNAPSHOT_LEN};
+
+#[test]
+fn ripemd160_raw_state_roundtrip() {
+    let hasher = Ripemd160::from_raw_state(Ripemd160::default().get_state(), 0).unwrap();
+    assert_eq!(hasher.finalize(), Ripemd160::digest(b""));
+
+    let data = [0x42u8; 200];
+    let mut hasher = Ripemd160::new();
+    hasher.update(&data[..128]);
+    let mut resumed = Ripemd160::from_raw_state(hasher.get_state(), 2).unwrap();
+    resumed.update(&data[128..]);
+    assert_eq!(resumed.finalize(), Ripemd160::digest(&data));
+
+    assert!(Ripemd160::from_raw_state([0; 5], !0u64 / 512 + 1).is_none());
+}
+
+#[test]
+fn ripemd160_max_len() {
+    let h = Ripemd160::default().get_state();
+    let mut hasher = Ripemd160::from_raw_state(h, (1 << 55) - 1).unwrap();
+    hasher.update([0u8; 63]);
+    hasher.finalize();
+}
+
+#[test]
+#[should_panic(expected = "input exceeds maximum length")]
+fn ripemd160_len_overflow() {
+    let h = Ripemd160::default().get_state();
+    let mut hasher = Ripemd160::from_raw_state(h, (1 << 55) - 1).unwrap();
+    hasher.update([0u8; 64]);
+    hasher.finalize();
+}
+
+#[test]
+fn ripemd160_serialize_state() {
+    let data: Vec<u8> = (0..300u32).map(|i| i as u8).collect();
+    for &split in [0, 1, 63, 64, 127, 128, 200].iter() {
+        let mut hasher = Ripemd160::new();
+        hasher.update(&data[..split]);
+        let snapshot = hasher.serialize_state();
+        assert_eq!(snapshot.len(), RIPEMD160_SNAPSHOT_LEN);
+
+        let mut resumed = Ripemd160::deserialize_state(&snapshot).unwrap();
+        assert_eq!(resumed.serialize_state()[..], snapshot[..]);
+        resumed.update(&data[split..]);
+        assert_eq!(resumed.finalize(), Ripemd160::digest(&data), "{}", split);
+    }
+
+    let snapshot = Ripemd160::new().serialize_state();
+    assert!(Ripemd160::deserialize_state(&snapshot[1..]).is_none());
+    let mut bad_pos = snapshot;
+    bad_pos[RIPEMD160_SNAPSHOT_LEN - 65] = 64;
+    assert!(Ripemd160::deserialize_state(&bad_pos).is_none());
+    let mut overflow = snapshot;
+    overflow[20..28].copy_from_slice(&(!0u64 / 512 + 1).to_le_bytes());
+    assert!(Ripemd160::deserialize_state(&overflow).is_none());
+}
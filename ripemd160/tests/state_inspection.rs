@@ -0,0 +1,21 @@
+#![cfg(feature = "state-inspection")]
+
+use ripemd160::{Digest, Ripemd160};
+
+#[test]
+fn ripemd160_blocks_processed() {
+    let mut hasher = Ripemd160::new();
+    let initial = hasher.get_state();
+    assert_eq!(hasher.blocks_processed(), 0);
+
+    hasher.update(b"abc");
+    assert_eq!(hasher.blocks_processed(), 0);
+    assert_eq!(hasher.get_state(), initial);
+
+    hasher.update([0u8; 61]);
+    assert_eq!(hasher.blocks_processed(), 1);
+    assert_ne!(hasher.get_state(), initial);
+
+    hasher.update([0u8; 200]);
+    assert_eq!(hasher.blocks_processed(), 4);
+}
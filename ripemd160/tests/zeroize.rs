@@ -0,0 +1,16 @@
+#![cfg(feature = "zeroize")]
+
+use ripemd160::zeroize::Zeroize;
+use ripemd160::{Digest, Ripemd160, RIPEMD160_SNAPSHOT_LEN};
+
+#[test]
+fn zeroize() {
+    let mut hasher = Ripemd160::new();
+    // a whole block and part of the next one
+    hasher.update(&[0x5a; 100][..]);
+    hasher.zeroize();
+    assert_eq!(
+        hasher.serialize_state()[..],
+        [0u8; RIPEMD160_SNAPSHOT_LEN][..]
+    );
+}
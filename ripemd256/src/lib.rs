@@ -34,6 +34,7 @@ pub use digest::{self, Digest};
 
 use block::{process_msg_block, DIGEST_BUF_LEN, H0};
 use block_buffer::BlockBuffer;
+use core::fmt;
 use digest::{
     consts::{U32, U64},
     BlockInput, FixedOutputDirty, Reset, Update,
@@ -57,6 +58,17 @@ impl Default for Ripemd256 {
     }
 }
 
+impl Ripemd256 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "RIPEMD-256";
+}
+
+impl fmt::Display for Ripemd256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Ripemd256 {
     type BlockSize = U64;
 }
@@ -51,3 +51,13 @@ fn ripemd256_1million_a() {
         "ac953744e10e31514c150d4d8d7b677342e33399788296e43ae4850ce4f97978"
     ));
 }
+
+#[test]
+fn ripemd256_alg_name() {
+    assert_eq!(ripemd256::Ripemd256::ALG_NAME, "RIPEMD-256");
+}
+
+#[test]
+fn ripemd256_display() {
+    assert_eq!(format!("{}", ripemd256::Ripemd256::default()), "RIPEMD-256");
+}
@@ -38,18 +38,37 @@ extern crate std;
 mod block;
 
 pub use digest::{self, Digest};
+#[cfg(feature = "zeroize")]
+pub use zeroize_crate as zeroize;
 
 use crate::block::{process_msg_block, DIGEST_BUF_LEN, H0};
+use core::convert::TryInto;
+use core::fmt;
 
-use block_buffer::BlockBuffer;
+use block_buffer::{block_padding::ZeroPadding, BlockBuffer};
 use digest::consts::{U40, U64};
+#[cfg(feature = "compress")]
+use digest::generic_array::GenericArray;
 use digest::{BlockInput, FixedOutputDirty, Reset, Update};
 
+/// Size in bytes of a snapshot created by [`Ripemd320::serialize_state`].
+pub const RIPEMD320_SNAPSHOT_LEN: usize = 4 * DIGEST_BUF_LEN + 8 + 1 + 64;
+
+/// Maximum supported input length in bytes (2^61 - 1, about 2.3 exabytes),
+/// so that the length in bits fits into the 64-bit padding field.
+const MAX_LEN: u64 = (1 << 61) - 1;
+
 /// Structure representing the state of a ripemd320 computation
+///
+/// Inputs longer than 2^61 - 1 bytes are not supported. Exceeding that
+/// limit trips a debug assertion in [`Update::update`]; in release builds
+/// the length saturates and finalization panics instead of producing a
+/// wrong digest.
 #[derive(Clone)]
 pub struct Ripemd320 {
     h: [u32; DIGEST_BUF_LEN],
     len: u64,
+    overflowed: bool,
     buffer: BlockBuffer<U64>,
 }
 
@@ -58,11 +77,106 @@ impl Default for Ripemd320 {
         Ripemd320 {
             h: H0,
             len: 0,
+            overflowed: false,
             buffer: Default::default(),
         }
     }
 }
 
+impl Ripemd320 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "RIPEMD-320";
+
+    /// Serialize the state of the hasher, including buffered input.
+    ///
+    /// The snapshot consists of the ten state words and the number of
+    /// processed blocks, all little-endian, followed by the buffer position
+    /// and the 64-byte buffer. A hasher which exceeded the maximum input
+    /// length produces a snapshot that can not be restored.
+    pub fn serialize_state(&self) -> [u8; RIPEMD320_SNAPSHOT_LEN] {
+        let mut out = [0u8; RIPEMD320_SNAPSHOT_LEN];
+        let (words, rest) = out.split_at_mut(4 * DIGEST_BUF_LEN);
+        for (chunk, v) in words.chunks_exact_mut(4).zip(self.h.iter()) {
+            chunk.copy_from_slice(&v.to_le_bytes());
+        }
+        let (block_len, rest) = rest.split_at_mut(8);
+        let blocks = if self.overflowed { !0 } else { self.len / 64 };
+        block_len.copy_from_slice(&blocks.to_le_bytes());
+
+        rest[0] = self.buffer.position() as u8;
+        // zero padding a copy of the buffer yields the buffered bytes
+        // followed by zeros, which is how the snapshot stores them
+        let mut buffer = self.buffer.clone();
+        let block = buffer
+            .pad_with::<ZeroPadding>()
+            .expect("we never use input_lazy");
+        rest[1..].copy_from_slice(block);
+        out
+    }
+
+    /// Restore a hasher from a snapshot created by `serialize_state`.
+    ///
+    /// Returns `None` if `bytes` is not [`RIPEMD320_SNAPSHOT_LEN`] bytes long,
+    /// is malformed or the message length in bits would overflow.
+    pub fn deserialize_state(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != RIPEMD320_SNAPSHOT_LEN {
+            return None;
+        }
+        let (words, rest) = bytes.split_at(4 * DIGEST_BUF_LEN);
+        let (block_len, rest) = rest.split_at(8);
+        let pos = rest[0] as usize;
+        if pos >= 64 {
+            return None;
+        }
+        let len = u64::from_le_bytes(block_len.try_into().unwrap())
+            .checked_mul(64)?
+            .checked_add(pos as u64)
+            .filter(|&l| l <= MAX_LEN)?;
+
+        let mut hasher = Ripemd320::default();
+        for (v, chunk) in hasher.h.iter_mut().zip(words.chunks_exact(4)) {
+            *v = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        // less than a block, so nothing gets compressed
+        let h = &mut hasher.h;
+        hasher
+            .buffer
+            .input_block(&rest[1..1 + pos], |b| process_msg_block(h, b));
+        hasher.len = len;
+        Some(hasher)
+    }
+
+    /// Get the raw chaining value.
+    ///
+    /// Input which does not fill a complete block yet is not covered by it.
+    #[cfg(feature = "state-inspection")]
+    pub fn get_state(&self) -> [u32; DIGEST_BUF_LEN] {
+        self.h
+    }
+}
+
+/// Raw RIPEMD-320 compression function.
+///
+/// Processes a single 64-byte `block` into the chaining value `state`. The
+/// block is read as sixteen little-endian 32-bit words and `state` holds the
+/// ten words of both lines in the order of the digest, which is their
+/// little-endian serialization (the layout of `Ripemd320::get_state` with
+/// the `state-inspection` feature). No padding is applied, so the caller
+/// must pad the final block of a message, including its length in bits.
+///
+/// This is a low-level "hazmat" API which provides direct access to the core
+/// functionality of RIPEMD-320.
+#[cfg(feature = "compress")]
+pub fn compress320(state: &mut [u32; 10], block: &GenericArray<u8, U64>) {
+    process_msg_block(state, block);
+}
+
+impl fmt::Display for Ripemd320 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Ripemd320 {
     type BlockSize = U64;
 }
@@ -70,8 +184,18 @@ impl BlockInput for Ripemd320 {
 impl Update for Ripemd320 {
     fn update(&mut self, input: impl AsRef<[u8]>) {
         let input = input.as_ref();
-        // Assumes that input.len() can be converted to u64 without overflow
-        self.len += input.len() as u64;
+        let len = self
+            .len
+            .checked_add(input.len() as u64)
+            .filter(|&l| l <= MAX_LEN);
+        debug_assert!(len.is_some(), "input exceeds maximum length");
+        match len {
+            Some(len) => self.len = len,
+            None => {
+                self.len = MAX_LEN;
+                self.overflowed = true;
+            }
+        }
         let h = &mut self.h;
         self.buffer.input_block(input, |b| process_msg_block(h, b));
     }
@@ -82,6 +206,7 @@ impl FixedOutputDirty for Ripemd320 {
 
     fn finalize_into_dirty(&mut self, out: &mut digest::Output<Self>) {
         let h = &mut self.h;
+        assert!(!self.overflowed, "input exceeds maximum length");
         let l = self.len << 3;
         self.buffer.len64_padding_le(l, |b| process_msg_block(h, b));
         for (chunk, v) in out.chunks_exact_mut(4).zip(self.h.iter()) {
@@ -94,9 +219,36 @@ impl Reset for Ripemd320 {
     fn reset(&mut self) {
         self.buffer.reset();
         self.len = 0;
+        self.overflowed = false;
         self.h = H0;
     }
 }
 
+/// Overwrites the chaining value, the length and the buffered input with
+/// zeros.
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::Zeroize for Ripemd320 {
+    fn zeroize(&mut self) {
+        self.h.zeroize();
+        self.len.zeroize();
+        self.overflowed.zeroize();
+        // the buffer is never full, so padding can not fail
+        if let Ok(block) = self.buffer.pad_with::<ZeroPadding>() {
+            block.as_mut_slice().zeroize();
+        }
+        self.buffer.reset();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Ripemd320 {
+    fn drop(&mut self) {
+        zeroize_crate::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::ZeroizeOnDrop for Ripemd320 {}
+
 opaque_debug::implement!(Ripemd320);
 digest::impl_write!(Ripemd320);
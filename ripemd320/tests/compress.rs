@@ -0,0 +1,40 @@
+#![cfg(feature = "compress")]
+
+use digest::generic_array::GenericArray;
+use hex_literal::hex;
+use ripemd320::compress320;
+
+const H0: [u32; 10] = [
+    0x6745_2301,
+    0xefcd_ab89,
+    0x98ba_dcfe,
+    0x1032_5476,
+    0xc3d2_e1f0,
+    0x7654_3210,
+    0xfedc_ba98,
+    0x89ab_cdef,
+    0x0123_4567,
+    0x3c2d_1e0f,
+];
+
+#[test]
+fn compress320_empty_message() {
+    // padding of the empty message: a single `0x80` byte and a zero length
+    let mut block = GenericArray::default();
+    block[0] = 0x80;
+
+    let mut state = H0;
+    compress320(&mut state, &block);
+
+    let mut out = [0u8; 40];
+    for (chunk, v) in out.chunks_exact_mut(4).zip(state.iter()) {
+        chunk.copy_from_slice(&v.to_le_bytes());
+    }
+    assert_eq!(
+        out[..],
+        hex!(
+            "22d65d5661536cdc75c1fdf5c6de7b41b9f27325
+             ebc61e8557177d705a0ec880151c3a32a00899b8"
+        )[..]
+    );
+}
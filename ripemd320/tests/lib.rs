@@ -17,3 +17,30 @@ fn ripemd320_1million_a() {
     let output = include_bytes!("data/one_million_a.bin");
     one_million_a::<ripemd320::Ripemd320>(&output[..]);
 }
+
+#[test]
+fn ripemd320_alg_name() {
+    assert_eq!(ripemd320::Ripemd320::ALG_NAME, "RIPEMD-320");
+}
+
+#[test]
+fn ripemd320_display() {
+    extern crate std;
+    use std::format;
+
+    assert_eq!(format!("{}", ripemd320::Ripemd320::default()), "RIPEMD-320");
+}
+
+#[test]
+fn ripemd320_reset() {
+    use digest::{Digest, Reset};
+
+    let mut hasher = ripemd320::Ripemd320::new();
+    hasher.update([0x5a; 100]);
+    Reset::reset(&mut hasher);
+    hasher.update(b"Hello world!");
+    assert_eq!(
+        hasher.finalize(),
+        ripemd320::Ripemd320::digest(b"Hello world!")
+    );
+}
@@ -0,0 +1,55 @@
+use ripemd320::{Digest, Ripemd320, RIPEMD320_SNAPSHOT_LEN};
+
+#[test]
+fn ripemd320_serialize_state() {
+    let data: Vec<u8> = (0..300u32).map(|i| i as u8).collect();
+    for &split in [0, 1, 63, 64, 127, 128, 200].iter() {
+        let mut hasher = Ripemd320::new();
+        hasher.update(&data[..split]);
+        let snapshot = hasher.serialize_state();
+        assert_eq!(snapshot.len(), RIPEMD320_SNAPSHOT_LEN);
+
+        let mut resumed = Ripemd320::deserialize_state(&snapshot).unwrap();
+        assert_eq!(resumed.serialize_state()[..], snapshot[..]);
+        resumed.update(&data[split..]);
+        assert_eq!(resumed.finalize(), Ripemd320::digest(&data), "{}", split);
+    }
+}
+
+#[test]
+fn ripemd320_deserialize_invalid() {
+    let snapshot = Ripemd320::new().serialize_state();
+    assert!(Ripemd320::deserialize_state(&snapshot[1..]).is_none());
+    assert!(Ripemd320::deserialize_state(&[0; RIPEMD320_SNAPSHOT_LEN + 1]).is_none());
+
+    let mut bad_pos = snapshot;
+    bad_pos[RIPEMD320_SNAPSHOT_LEN - 65] = 64;
+    assert!(Ripemd320::deserialize_state(&bad_pos).is_none());
+
+    let mut overflow = snapshot;
+    overflow[40..48].copy_from_slice(&(!0u64 / 512 + 1).to_le_bytes());
+    assert!(Ripemd320::deserialize_state(&overflow).is_none());
+}
+
+/// Hasher that has processed `(1 << 55) - 1` blocks, one block short of
+/// the maximum length.
+fn near_max_len() -> Ripemd320 {
+    let mut snapshot = Ripemd320::new().serialize_state();
+    snapshot[40..48].copy_from_slice(&((1u64 << 55) - 1).to_le_bytes());
+    Ripemd320::deserialize_state(&snapshot).unwrap()
+}
+
+#[test]
+fn ripemd320_max_len() {
+    let mut hasher = near_max_len();
+    hasher.update([0u8; 63]);
+    hasher.finalize();
+}
+
+#[test]
+#[should_panic(expected = "input exceeds maximum length")]
+fn ripemd320_len_overflow() {
+    let mut hasher = near_max_len();
+    hasher.update([0u8; 64]);
+    hasher.finalize();
+}
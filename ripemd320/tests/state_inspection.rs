@@ -0,0 +1,18 @@
+#![cfg(feature = "state-inspection")]
+
+use ripemd320::{Digest, Ripemd320};
+
+#[test]
+fn ripemd320_get_state() {
+    let mut hasher = Ripemd320::new();
+    let initial = hasher.get_state();
+
+    hasher.update(b"abc");
+    assert_eq!(hasher.get_state(), initial);
+
+    hasher.update([0u8; 61]);
+    assert_ne!(hasher.get_state(), initial);
+
+    Digest::reset(&mut hasher);
+    assert_eq!(hasher.get_state(), initial);
+}
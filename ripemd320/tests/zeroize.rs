@@ -0,0 +1,16 @@
+#![cfg(feature = "zeroize")]
+
+use ripemd320::zeroize::Zeroize;
+use ripemd320::{Digest, Ripemd320, RIPEMD320_SNAPSHOT_LEN};
+
+#[test]
+fn zeroize() {
+    let mut hasher = Ripemd320::new();
+    // a whole block and part of the next one
+    hasher.update(&[0x5a; 100][..]);
+    hasher.zeroize();
+    assert_eq!(
+        hasher.serialize_state()[..],
+        [0u8; RIPEMD320_SNAPSHOT_LEN][..]
+    );
+}
@@ -5,7 +5,14 @@ cfg_if::cfg_if! {
     if #[cfg(feature = "force-soft")] {
         mod soft;
         use soft::compress as compress_inner;
-    } else if #[cfg(all(feature = "asm", target_arch = "aarch64"))] {
+    } else if #[cfg(all(feature = "s390x-cpacf", target_arch = "s390x"))] {
+        mod soft;
+        mod s390x;
+        use s390x::compress as compress_inner;
+    } else if #[cfg(all(
+        any(feature = "asm", feature = "aarch64-sha1"),
+        target_arch = "aarch64",
+    ))] {
         mod soft;
         mod aarch64;
         use aarch64::compress as compress_inner;
@@ -26,6 +33,9 @@ cfg_if::cfg_if! {
     }
 }
 
+#[cfg(all(feature = "compress", target_arch = "x86_64"))]
+mod sse2;
+
 /// SHA-1 compression function
 #[cfg_attr(docsrs, doc(cfg(feature = "compress")))]
 pub fn compress(state: &mut [u32; 5], blocks: &[GenericArray<u8, U64>]) {
@@ -35,3 +45,14 @@ pub fn compress(state: &mut [u32; 5], blocks: &[GenericArray<u8, U64>]) {
     let blocks: &[[u8; 64]] = unsafe { &*(blocks as *const _ as *const [[u8; 64]]) };
     compress_inner(state, blocks);
 }
+
+/// SHA-1 compression function applied to four independent states, each
+/// processing one block.
+///
+/// Uses SSE2 to compute all four compressions at once if available, which is
+/// useful when hashing many short independent messages.
+#[cfg(all(feature = "compress", target_arch = "x86_64"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "compress", target_arch = "x86_64"))))]
+pub fn compress4x(states: &mut [[u32; 5]; 4], blocks: &[[u8; 64]; 4]) {
+    sse2::compress4x(states, blocks);
+}
@@ -1,4 +1,9 @@
 //! SHA-1 `aarch64` backend.
+//!
+//! With the `aarch64-sha1` feature the ARMv8 SHA1 instructions are used
+//! through the `core::arch` intrinsics, otherwise through `sha1-asm`. Support
+//! for the extension is detected at compile time, or at run time where the
+//! target does not guarantee it.
 
 // Per rustc target feature docs for `aarch64-unknown-linux-gnu` and
 // `aarch64-apple-darwin` platforms, the `sha2` target feature enables
@@ -8,11 +13,61 @@
 cpufeatures::new!(sha1_hwcap, "sha2");
 
 pub fn compress(state: &mut [u32; 5], blocks: &[[u8; 64]]) {
-    // TODO: Replace with https://github.com/rust-lang/rfcs/pull/2725
-    // after stabilization
     if sha1_hwcap::get() {
+        #[cfg(feature = "aarch64-sha1")]
+        unsafe {
+            sha1_compress(state, blocks);
+        }
+        #[cfg(not(feature = "aarch64-sha1"))]
         sha1_asm::compress(state, blocks);
     } else {
         super::soft::compress(state, blocks);
     }
 }
+
+#[cfg(feature = "aarch64-sha1")]
+#[target_feature(enable = "sha2")]
+unsafe fn sha1_compress(state: &mut [u32; 5], blocks: &[[u8; 64]]) {
+    use crate::consts::{K0, K1, K2, K3};
+    use core::arch::aarch64::*;
+
+    let k = [K0, K1, K2, K3];
+    let mut abcd = vld1q_u32(state[0..4].as_ptr());
+    let mut e0 = state[4];
+
+    for block in blocks {
+        let abcd_orig = abcd;
+        let e0_orig = e0;
+
+        // message words are big-endian
+        let mut w = [
+            vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(block[0..16].as_ptr()))),
+            vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(block[16..32].as_ptr()))),
+            vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(block[32..48].as_ptr()))),
+            vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(block[48..64].as_ptr()))),
+        ];
+
+        // 20 groups of 4 rounds, the schedule runs 4 groups ahead
+        for i in 0..20 {
+            let wk = vaddq_u32(w[i % 4], vdupq_n_u32(k[i / 5]));
+            // `e` of the next group is `a` of this one rotated by 30 bits
+            let e1 = vsha1h_u32(vgetq_lane_u32(abcd, 0));
+            abcd = match i / 5 {
+                0 => vsha1cq_u32(abcd, e0, wk),
+                2 => vsha1mq_u32(abcd, e0, wk),
+                _ => vsha1pq_u32(abcd, e0, wk),
+            };
+            e0 = e1;
+            if i < 16 {
+                let t = vsha1su0q_u32(w[i % 4], w[(i + 1) % 4], w[(i + 2) % 4]);
+                w[i % 4] = vsha1su1q_u32(t, w[(i + 3) % 4]);
+            }
+        }
+
+        abcd = vaddq_u32(abcd, abcd_orig);
+        e0 = e0.wrapping_add(e0_orig);
+    }
+
+    vst1q_u32(state[0..4].as_mut_ptr(), abcd);
+    state[4] = e0;
+}
@@ -0,0 +1,103 @@
+//! SHA-1 multi-buffer backend processing four independent states at once,
+//! one per 32-bit lane of an SSE2 register.
+
+#![allow(unsafe_code)]
+
+use crate::consts::{K0, K1, K2, K3};
+use core::arch::x86_64::*;
+use core::convert::TryInto;
+
+macro_rules! rotl {
+    ($x:expr, $n:expr) => {
+        _mm_or_si128(_mm_slli_epi32($x, $n), _mm_srli_epi32($x, 32 - $n))
+    };
+}
+
+/// Load lane `i` of each register from `states[i]`.
+#[target_feature(enable = "sse2")]
+unsafe fn load_word(states: &[[u32; 5]; 4], j: usize) -> __m128i {
+    _mm_set_epi32(
+        states[3][j] as i32,
+        states[2][j] as i32,
+        states[1][j] as i32,
+        states[0][j] as i32,
+    )
+}
+
+/// Load the big-endian message word `t` of each block.
+#[target_feature(enable = "sse2")]
+unsafe fn load_msg(blocks: &[[u8; 64]; 4], t: usize) -> __m128i {
+    let w = |i: usize| u32::from_be_bytes(blocks[i][4 * t..4 * t + 4].try_into().unwrap()) as i32;
+    _mm_set_epi32(w(3), w(2), w(1), w(0))
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn digest_blocks_4x(states: &mut [[u32; 5]; 4], blocks: &[[u8; 64]; 4]) {
+    let mut w = [_mm_setzero_si128(); 16];
+    for (t, w) in w.iter_mut().enumerate() {
+        *w = load_msg(blocks, t);
+    }
+
+    let h = [
+        load_word(states, 0),
+        load_word(states, 1),
+        load_word(states, 2),
+        load_word(states, 3),
+        load_word(states, 4),
+    ];
+    let [mut a, mut b, mut c, mut d, mut e] = h;
+
+    for t in 0..80 {
+        if t >= 16 {
+            let x = _mm_xor_si128(
+                _mm_xor_si128(w[(t - 3) & 15], w[(t - 8) & 15]),
+                _mm_xor_si128(w[(t - 14) & 15], w[t & 15]),
+            );
+            w[t & 15] = rotl!(x, 1);
+        }
+        let (f, k) = match t / 20 {
+            0 => (
+                _mm_or_si128(_mm_and_si128(b, c), _mm_andnot_si128(b, d)),
+                K0,
+            ),
+            1 => (_mm_xor_si128(_mm_xor_si128(b, c), d), K1),
+            2 => (
+                _mm_or_si128(_mm_and_si128(b, c), _mm_and_si128(d, _mm_or_si128(b, c))),
+                K2,
+            ),
+            _ => (_mm_xor_si128(_mm_xor_si128(b, c), d), K3),
+        };
+        let tmp = _mm_add_epi32(
+            _mm_add_epi32(rotl!(a, 5), f),
+            _mm_add_epi32(_mm_add_epi32(e, _mm_set1_epi32(k as i32)), w[t & 15]),
+        );
+        e = d;
+        d = c;
+        c = rotl!(b, 30);
+        b = a;
+        a = tmp;
+    }
+
+    let res = [a, b, c, d, e];
+    for (j, (v, h)) in res.iter().zip(h.iter()).enumerate() {
+        let mut lanes = [0u32; 4];
+        _mm_storeu_si128(lanes.as_mut_ptr() as *mut __m128i, _mm_add_epi32(*v, *h));
+        for (state, lane) in states.iter_mut().zip(lanes.iter()) {
+            state[j] = *lane;
+        }
+    }
+}
+
+cpufeatures::new!(sse2_cpuid, "sse2");
+
+pub fn compress4x(states: &mut [[u32; 5]; 4], blocks: &[[u8; 64]; 4]) {
+    if !cfg!(feature = "force-soft") && sse2_cpuid::get() {
+        unsafe {
+            digest_blocks_4x(states, blocks);
+        }
+    } else {
+        for (state, block) in states.iter_mut().zip(blocks.iter()) {
+            super::compress_inner(state, core::slice::from_ref(block));
+        }
+    }
+}
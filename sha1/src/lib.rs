@@ -27,6 +27,10 @@
 //! assert_eq!(result[..], hex!("2aae6c35c94fcfb415dbe95f408b9ce91ee846ed"));
 //! ```
 //!
+//! With the `hmac` feature enabled, the [`HmacSha1`] type alias is available
+//! as well. The `mgf` feature enables the MGF1 mask generation function in
+//! the `mgf` module.
+//!
 //! Also see [RustCrypto/hashes][3] readme.
 //!
 //! [1]: https://en.wikipedia.org/wiki/SHA-1
@@ -47,17 +51,39 @@ extern crate std;
 
 mod compress;
 mod consts;
+#[cfg(feature = "mgf")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mgf")))]
+pub mod mgf;
+#[cfg(feature = "pbkdf2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pbkdf2")))]
+pub mod pbkdf2;
 
 #[cfg(feature = "compress")]
 pub use crate::compress::compress;
 #[cfg(not(feature = "compress"))]
 use crate::compress::compress;
+#[cfg(all(feature = "compress", target_arch = "x86_64"))]
+pub use crate::compress::compress4x;
 use crate::consts::{H, STATE_LEN};
-use block_buffer::BlockBuffer;
+use block_buffer::{block_padding::ZeroPadding, BlockBuffer};
+use core::convert::TryInto;
+use core::fmt;
 use digest::consts::{U20, U64};
 pub use digest::{self, Digest};
 use digest::{BlockInput, FixedOutputDirty, Reset, Update};
 
+#[cfg(feature = "hmac")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hmac")))]
+pub use hmac;
+
+/// HMAC-SHA-1
+#[cfg(feature = "hmac")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hmac")))]
+pub type HmacSha1 = hmac::Hmac<Sha1>;
+
+/// Size in bytes of a snapshot created by [`Sha1::serialize_state`].
+pub const SHA1_SNAPSHOT_LEN: usize = 4 * STATE_LEN + 8 + 1 + 64;
+
 /// Structure representing the state of a SHA-1 computation
 #[derive(Clone)]
 pub struct Sha1 {
@@ -76,6 +102,79 @@ impl Default for Sha1 {
     }
 }
 
+impl Sha1 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "SHA-1";
+
+    /// Serialize the hasher state, including input which does not fill a
+    /// complete block yet.
+    ///
+    /// The snapshot consists of the five state words, the number of
+    /// processed blocks as a `u64`, the number of buffered bytes as a single
+    /// byte and the buffered bytes zero-padded to the block size. All
+    /// integers are big-endian.
+    pub fn serialize_state(&self) -> [u8; SHA1_SNAPSHOT_LEN] {
+        let mut out = [0u8; SHA1_SNAPSHOT_LEN];
+        let (words, rest) = out.split_at_mut(4 * STATE_LEN);
+        for (chunk, v) in words.chunks_exact_mut(4).zip(self.h.iter()) {
+            chunk.copy_from_slice(&v.to_be_bytes());
+        }
+        let (block_len, rest) = rest.split_at_mut(8);
+        block_len.copy_from_slice(&(self.len / 64).to_be_bytes());
+
+        rest[0] = self.buffer.position() as u8;
+        // zero padding a copy of the buffer yields the buffered bytes
+        // followed by zeros, which is how the snapshot stores them
+        let mut buffer = self.buffer.clone();
+        let block = buffer
+            .pad_with::<ZeroPadding>()
+            .expect("we never use input_lazy");
+        rest[1..].copy_from_slice(block);
+        out
+    }
+
+    /// Restore a hasher from a snapshot created by `serialize_state`.
+    ///
+    /// Returns `None` if `bytes` is not [`SHA1_SNAPSHOT_LEN`] bytes long or
+    /// is malformed.
+    pub fn deserialize_state(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != SHA1_SNAPSHOT_LEN {
+            return None;
+        }
+        let (words, rest) = bytes.split_at(4 * STATE_LEN);
+        let (block_len, rest) = rest.split_at(8);
+        let pos = rest[0] as usize;
+        if pos >= 64 {
+            return None;
+        }
+        // the length in bits has to fit into the padding
+        let len = u64::from_be_bytes(block_len.try_into().unwrap())
+            .checked_mul(64)?
+            .checked_add(pos as u64)?;
+        if len > !0u64 >> 3 {
+            return None;
+        }
+
+        let mut hasher = Sha1::default();
+        for (v, chunk) in hasher.h.iter_mut().zip(words.chunks_exact(4)) {
+            *v = u32::from_be_bytes(chunk.try_into().unwrap());
+        }
+        // less than a block, so nothing gets compressed
+        let s = &mut hasher.h;
+        hasher
+            .buffer
+            .input_blocks(&rest[1..1 + pos], |b| compress(s, b));
+        hasher.len = len;
+        Some(hasher)
+    }
+}
+
+impl fmt::Display for Sha1 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Sha1 {
     type BlockSize = U64;
 }
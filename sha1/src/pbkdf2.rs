@@ -0,0 +1,23 @@
+//! PBKDF2 with HMAC-SHA-1 as the PRF, as specified in
+//! [RFC 8018 §5.2][1].
+//!
+//! This is a thin wrapper over the [`pbkdf2`][2] crate, e.g. for WPA2 or
+//! legacy formats which mandate SHA-1. New designs should prefer
+//! PBKDF2-HMAC-SHA256 or a memory-hard KDF.
+//!
+//! ```rust
+//! use sha1::pbkdf2::pbkdf2_sha1;
+//!
+//! let mut key = [0u8; 20];
+//! pbkdf2_sha1(b"password", b"salt", 4096, &mut key);
+//! ```
+//!
+//! [1]: https://tools.ietf.org/html/rfc8018#section-5.2
+//! [2]: https://docs.rs/pbkdf2
+use crate::HmacSha1;
+
+/// Derive `output.len()` bytes from `password` and `salt` with
+/// PBKDF2-HMAC-SHA1 using `iterations` rounds.
+pub fn pbkdf2_sha1(password: &[u8], salt: &[u8], iterations: u32, output: &mut [u8]) {
+    pbkdf2_crate::pbkdf2::<HmacSha1>(password, salt, iterations, output);
+}
@@ -0,0 +1,49 @@
+//! HMAC-SHA-1 test vectors from RFC 2202
+#![cfg(feature = "hmac")]
+
+use hex_literal::hex;
+use sha1::hmac::{Mac, NewMac};
+
+fn check<M: Mac + NewMac>(key: &[u8], data: &[u8], tag: &[u8]) {
+    let mut mac = M::new_from_slice(key).unwrap();
+    mac.update(data);
+    assert_eq!(mac.finalize().into_bytes()[..], tag[..]);
+
+    let mut mac = M::new_from_slice(key).unwrap();
+    mac.update(data);
+    mac.verify(tag).unwrap();
+}
+
+#[test]
+fn hmac_sha1() {
+    check::<sha1::HmacSha1>(
+        &[0x0b; 20],
+        b"Hi There",
+        &hex!("b617318655057264e28bc0b6fb378c8ef146be00"),
+    );
+    check::<sha1::HmacSha1>(
+        b"Jefe",
+        b"what do ya want for nothing?",
+        &hex!("effcdf6ae5eb2fa2d27416d5f184df9c259a7c79"),
+    );
+    check::<sha1::HmacSha1>(
+        &[0xaa; 20],
+        &[0xdd; 50],
+        &hex!("125d7342b9ac11cd91a39af48aa17b4f63f175d3"),
+    );
+    check::<sha1::HmacSha1>(
+        &hex!("0102030405060708090a0b0c0d0e0f10111213141516171819"),
+        &[0xcd; 50],
+        &hex!("4c9007f4026250c6bc8414f9bf50c86c2d7235da"),
+    );
+    check::<sha1::HmacSha1>(
+        &[0xaa; 80],
+        b"Test Using Larger Than Block-Size Key - Hash Key First",
+        &hex!("aa4ae5e15272d00e95705637ce8a3b55ed402112"),
+    );
+    check::<sha1::HmacSha1>(
+        &[0xaa; 80],
+        b"Test Using Larger Than Block-Size Key and Larger Than One Block-Size Data",
+        &hex!("e8e99d0f45237d786d6bbaa7965c7808bbff1a91"),
+    );
+}
@@ -10,3 +10,42 @@ fn sha1_1million_a() {
     let output = include_bytes!("data/one_million_a.bin");
     one_million_a::<sha1::Sha1>(output);
 }
+
+#[test]
+fn sha1_alg_name() {
+    assert_eq!(sha1::Sha1::ALG_NAME, "SHA-1");
+}
+
+#[test]
+fn sha1_display() {
+    extern crate std;
+    use std::format;
+
+    assert_eq!(format!("{}", sha1::Sha1::default()), "SHA-1");
+}
+
+#[test]
+#[cfg(all(feature = "compress", target_arch = "x86_64"))]
+fn sha1_compress4x() {
+    use digest::generic_array::GenericArray;
+
+    let mut blocks = [[0u8; 64]; 4];
+    for (i, block) in blocks.iter_mut().enumerate() {
+        for (j, b) in block.iter_mut().enumerate() {
+            *b = (i * 64 + j) as u8;
+        }
+    }
+    let mut states = [[0u32; 5]; 4];
+    for (i, state) in states.iter_mut().enumerate() {
+        for (j, w) in state.iter_mut().enumerate() {
+            *w = 0x0101_0101u32.wrapping_mul((5 * i + j) as u32);
+        }
+    }
+
+    let mut expected = states;
+    for (state, block) in expected.iter_mut().zip(blocks.iter()) {
+        sha1::compress(state, &[GenericArray::clone_from_slice(block)]);
+    }
+    sha1::compress4x(&mut states, &blocks);
+    assert_eq!(states, expected);
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "mgf")]
+
+use hex_literal::hex;
+use sha1::mgf::{mgf1_sha1, MaskTooLong};
+
+#[test]
+fn mgf1_sha1_short() {
+    let mut out = [0u8; 5];
+    mgf1_sha1(b"foo", 3, &mut out).unwrap();
+    assert_eq!(out, hex!("1ac9070000"));
+    mgf1_sha1(b"foo", 5, &mut out).unwrap();
+    assert_eq!(out, hex!("1ac9075cd4"));
+    mgf1_sha1(b"bar", 5, &mut out).unwrap();
+    assert_eq!(out, hex!("bc0c655e01"));
+}
+
+#[test]
+fn mgf1_sha1_multi_block() {
+    let mut out = [0u8; 50];
+    mgf1_sha1(b"bar", 50, &mut out).unwrap();
+    assert_eq!(
+        out[..],
+        hex!(
+            "
+        bc0c655e016bc2931d85a2e675181adcef7f581f76df2739da74faac41627be2
+        f7f415c89e983fd0ce80ced9878641cb4876
+    "
+        )[..]
+    );
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn mgf1_sha1_mask_too_long() {
+    // the length is checked before `output` is touched
+    assert_eq!(
+        mgf1_sha1(b"foo", (1 << 32) * 20 + 1, &mut []),
+        Err(MaskTooLong)
+    );
+}
@@ -0,0 +1,46 @@
+//! RFC 6070 test vectors
+#![cfg(feature = "pbkdf2")]
+
+use hex_literal::hex;
+use sha1::pbkdf2::pbkdf2_sha1;
+
+fn check(password: &[u8], salt: &[u8], iterations: u32, expected: &[u8]) {
+    let mut out = [0u8; 32];
+    let out = &mut out[..expected.len()];
+    pbkdf2_sha1(password, salt, iterations, out);
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn pbkdf2_sha1_rfc6070() {
+    check(
+        b"password",
+        b"salt",
+        1,
+        &hex!("0c60c80f961f0e71f3a9b524af6012062fe037a6"),
+    );
+    check(
+        b"password",
+        b"salt",
+        2,
+        &hex!("ea6c014dc72d6f8ccd1ed92ace1d41f0d8de8957"),
+    );
+    check(
+        b"password",
+        b"salt",
+        4096,
+        &hex!("4b007901b765489abead49d926f721d065a429c1"),
+    );
+    check(
+        b"passwordPASSWORDpassword",
+        b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+        4096,
+        &hex!("3d2eec4fe41c849b80c8d83662c0e44a8b291a964cf2f07038"),
+    );
+    check(
+        b"pass\0word",
+        b"sa\0lt",
+        4096,
+        &hex!("56fa6aa75548099dcc37d7f03425e0c3"),
+    );
+}
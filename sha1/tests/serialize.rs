@@ -0,0 +1,31 @@
+use sha1::{Digest, Sha1, SHA1_SNAPSHOT_LEN};
+
+#[test]
+fn sha1_serialize_state() {
+    let data: Vec<u8> = (0..600u32).map(|i| i as u8).collect();
+    // empty, the last byte which fits next to the length padding, the first
+    // which does not, and several whole blocks
+    for &split in &[0, 55, 56, 512] {
+        let mut hasher = Sha1::new();
+        hasher.update(&data[..split]);
+        let snapshot = hasher.serialize_state();
+        assert_eq!(snapshot.len(), SHA1_SNAPSHOT_LEN);
+
+        let resumed = Sha1::deserialize_state(&snapshot).unwrap();
+        assert_eq!(resumed.serialize_state()[..], snapshot[..]);
+        assert_eq!(resumed.clone().finalize(), Sha1::digest(&data[..split]));
+
+        let mut resumed = resumed;
+        resumed.update(&data[split..]);
+        assert_eq!(resumed.finalize(), Sha1::digest(&data));
+    }
+
+    let snapshot = Sha1::new().serialize_state();
+    assert!(Sha1::deserialize_state(&snapshot[1..]).is_none());
+    let mut bad_pos = snapshot;
+    bad_pos[SHA1_SNAPSHOT_LEN - 65] = 64;
+    assert!(Sha1::deserialize_state(&bad_pos).is_none());
+    let mut bad_len = snapshot;
+    bad_len[20..28].copy_from_slice(&(!0u64).to_be_bytes());
+    assert!(Sha1::deserialize_state(&bad_len).is_none());
+}
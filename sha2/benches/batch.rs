@@ -0,0 +1,92 @@
+//! Root of a 10,000-leaf binary Merkle tree with `H(left || right)` nodes,
+//! computed level by level with `compress256_batch` versus one
+//! `Sha256::digest` call per node. Throughput is per 64-byte node input.
+#![feature(test)]
+extern crate test;
+
+use sha2::parallel::compress256_batch;
+use sha2::{Digest, Sha256};
+use test::Bencher;
+
+const LEAVES: usize = 10_000;
+const NODES: u64 = LEAVES as u64 - 1;
+
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// Padding block for a 64-byte message.
+const PAD: [u8; 64] = {
+    let mut b = [0u8; 64];
+    b[0] = 0x80;
+    b[62] = 0x02;
+    b
+};
+
+fn leaves() -> Vec<[u8; 32]> {
+    (0..LEAVES as u32)
+        .map(|i| Sha256::digest(&i.to_le_bytes()).into())
+        .collect()
+}
+
+fn pair(l: &[u8; 32], r: &[u8; 32]) -> [u8; 64] {
+    let mut b = [0u8; 64];
+    b[..32].copy_from_slice(l);
+    b[32..].copy_from_slice(r);
+    b
+}
+
+fn root_sequential(mut level: Vec<[u8; 32]>) -> [u8; 32] {
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|c| match c {
+                [l, r] => Sha256::digest(&pair(l, r)).into(),
+                [x] => *x,
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    level[0]
+}
+
+fn root_batch(mut level: Vec<[u8; 32]>) -> [u8; 32] {
+    while level.len() > 1 {
+        let blocks: Vec<_> = level.chunks_exact(2).map(|c| pair(&c[0], &c[1])).collect();
+        let mut states = vec![IV; blocks.len()];
+        compress256_batch(&mut states, &blocks);
+        compress256_batch(&mut states, &vec![PAD; blocks.len()]);
+
+        let odd = level.len() % 2 == 1;
+        let last = *level.last().unwrap();
+        level = states
+            .iter()
+            .map(|s| {
+                let mut out = [0u8; 32];
+                for (c, w) in out.chunks_exact_mut(4).zip(s.iter()) {
+                    c.copy_from_slice(&w.to_be_bytes());
+                }
+                out
+            })
+            .collect();
+        if odd {
+            level.push(last);
+        }
+    }
+    level[0]
+}
+
+#[bench]
+fn merkle_10k_sequential(b: &mut Bencher) {
+    let leaves = leaves();
+    b.bytes = 64 * NODES;
+    b.iter(|| root_sequential(leaves.clone()));
+}
+
+#[bench]
+fn merkle_10k_batch(b: &mut Bencher) {
+    let leaves = leaves();
+    assert_eq!(root_batch(leaves.clone()), root_sequential(leaves.clone()));
+    b.bytes = 64 * NODES;
+    b.iter(|| root_batch(leaves.clone()));
+}
@@ -0,0 +1,41 @@
+//! `compress256x8` versus eight `compress256` calls over 64 KiB of input,
+//! 128 blocks per lane. Needs AVX2 at compile time:
+//!
+//! ```text
+//! RUSTFLAGS="-C target-feature=+avx2" cargo bench --features compress --bench x8
+//! ```
+#![cfg(target_feature = "avx2")]
+#![feature(test)]
+extern crate test;
+
+use sha2::digest::generic_array::GenericArray;
+use sha2::{compress256, compress256x8};
+use test::Bencher;
+
+const BLOCKS: usize = 128;
+
+#[bench]
+fn compress256_8_lanes(b: &mut Bencher) {
+    let blocks = [GenericArray::clone_from_slice(&[0x5a; 64]); BLOCKS];
+    let mut states = [[0u32; 8]; 8];
+    b.bytes = (8 * 64 * BLOCKS) as u64;
+    b.iter(|| {
+        for s in states.iter_mut() {
+            compress256(s, &blocks);
+        }
+        test::black_box(&states);
+    });
+}
+
+#[bench]
+fn compress256x8_8_lanes(b: &mut Bencher) {
+    let blocks = [[0x5a; 64]; 8];
+    let mut states = [[0u32; 8]; 8];
+    b.bytes = (8 * 64 * BLOCKS) as u64;
+    b.iter(|| {
+        for _ in 0..BLOCKS {
+            compress256x8(&mut states, &blocks);
+        }
+        test::black_box(&states);
+    });
+}
@@ -0,0 +1,152 @@
+//! `Hash_DRBG` instantiated with SHA-256, as specified in
+//! [NIST SP 800-90A Rev. 1][1] §10.1.1.
+//!
+//! The caller is responsible for supplying entropy input of at least 256 bits
+//! and a nonce of at least 128 bits, and for reseeding once
+//! [`HashDrbgSha256::generate`] reports [`Error::ReseedRequired`].
+//! Prediction resistance is not provided by this module; it can be obtained
+//! by calling [`HashDrbgSha256::reseed`] before every request.
+//!
+//! ```rust
+//! use sha2::drbg::HashDrbgSha256;
+//!
+//! let mut drbg = HashDrbgSha256::new(&[0x42; 32], &[0x24; 16], b"example");
+//! let mut out = [0u8; 64];
+//! drbg.generate(&mut out, &[]).unwrap();
+//! ```
+//!
+//! [1]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+use crate::Sha256;
+use core::fmt;
+use digest::{FixedOutput, Update};
+
+/// Length of `V` and `C` in bytes (`seedlen` = 440 bits for SHA-256).
+const SEED_LEN: usize = 55;
+/// Output length of SHA-256 in bytes.
+const OUT_LEN: usize = 32;
+/// Maximum number of `generate` calls between reseeds (2^48).
+const RESEED_INTERVAL: u64 = 1 << 48;
+/// Maximum number of bytes per `generate` call (2^19 bits).
+const MAX_REQUEST_LEN: usize = 1 << 16;
+
+/// Errors returned by [`HashDrbgSha256::generate`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The reseed interval has been reached; call
+    /// [`HashDrbgSha256::reseed`] before generating more output.
+    ReseedRequired,
+    /// More than 2^16 bytes were requested in a single call.
+    RequestTooLarge,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::ReseedRequired => "Hash_DRBG reseed required",
+            Error::RequestTooLarge => "Hash_DRBG request too large",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// `Hash_DRBG` using SHA-256.
+#[derive(Clone)]
+pub struct HashDrbgSha256 {
+    v: [u8; SEED_LEN],
+    c: [u8; SEED_LEN],
+    reseed_counter: u64,
+}
+
+impl HashDrbgSha256 {
+    /// Instantiate the DRBG (`Hash_DRBG_Instantiate_algorithm`).
+    pub fn new(entropy: &[u8], nonce: &[u8], personalization: &[u8]) -> Self {
+        let mut v = [0u8; SEED_LEN];
+        hash_df(&[entropy, nonce, personalization], &mut v);
+        let mut c = [0u8; SEED_LEN];
+        hash_df(&[&[0x00], &v], &mut c);
+        Self {
+            v,
+            c,
+            reseed_counter: 1,
+        }
+    }
+
+    /// Reseed the DRBG (`Hash_DRBG_Reseed_algorithm`).
+    pub fn reseed(&mut self, entropy: &[u8], additional_input: &[u8]) {
+        let mut v = [0u8; SEED_LEN];
+        hash_df(&[&[0x01], &self.v, entropy, additional_input], &mut v);
+        self.v = v;
+        hash_df(&[&[0x00], &self.v], &mut self.c);
+        self.reseed_counter = 1;
+    }
+
+    /// Fill `output` with pseudorandom bytes (`Hash_DRBG_Generate_algorithm`).
+    pub fn generate(&mut self, output: &mut [u8], additional_input: &[u8]) -> Result<(), Error> {
+        if output.len() > MAX_REQUEST_LEN {
+            return Err(Error::RequestTooLarge);
+        }
+        if self.reseed_counter > RESEED_INTERVAL {
+            return Err(Error::ReseedRequired);
+        }
+
+        if !additional_input.is_empty() {
+            let w = hash(&[&[0x02], &self.v, additional_input]);
+            add_be(&mut self.v, &w);
+        }
+
+        // Hashgen
+        let mut data = self.v;
+        for chunk in output.chunks_mut(OUT_LEN) {
+            let w = hash(&[&data]);
+            chunk.copy_from_slice(&w[..chunk.len()]);
+            add_be(&mut data, &[1]);
+        }
+
+        let h = hash(&[&[0x03], &self.v]);
+        let c = self.c;
+        add_be(&mut self.v, &h);
+        add_be(&mut self.v, &c);
+        add_be(&mut self.v, &self.reseed_counter.to_be_bytes());
+        self.reseed_counter += 1;
+        Ok(())
+    }
+}
+
+opaque_debug::implement!(HashDrbgSha256);
+
+fn hash(parts: &[&[u8]]) -> [u8; OUT_LEN] {
+    let mut h = Sha256::default();
+    for part in parts {
+        h.update(part);
+    }
+    let mut out = [0u8; OUT_LEN];
+    out.copy_from_slice(&h.finalize_fixed());
+    out
+}
+
+/// `Hash_df` derivation function (SP 800-90A §10.3.1).
+fn hash_df(parts: &[&[u8]], output: &mut [u8]) {
+    let bits = (output.len() as u32 * 8).to_be_bytes();
+    for (counter, chunk) in output.chunks_mut(OUT_LEN).enumerate() {
+        let mut h = Sha256::default();
+        h.update([counter as u8 + 1]);
+        h.update(bits);
+        for part in parts {
+            h.update(part);
+        }
+        chunk.copy_from_slice(&h.finalize_fixed()[..chunk.len()]);
+    }
+}
+
+/// `acc = (acc + x) mod 2^(8 * SEED_LEN)`, both big-endian.
+fn add_be(acc: &mut [u8; SEED_LEN], x: &[u8]) {
+    let mut carry = 0u16;
+    let mut xs = x.iter().rev();
+    for a in acc.iter_mut().rev() {
+        let sum = u16::from(*a) + u16::from(*xs.next().unwrap_or(&0)) + carry;
+        *a = sum as u8;
+        carry = sum >> 8;
+    }
+}
@@ -0,0 +1,65 @@
+//! One-step key derivation with HMAC as specified in
+//! [NIST SP 800-56C Rev. 2][1] §4.1, option 2.
+//!
+//! Each output block is `HMAC(salt, counter || Z || FixedInfo)` with a 32-bit
+//! big-endian counter starting at 1. `FixedInfo` is encoded as
+//! `label || 0x00 || context || [L]_32`, where `L` is the output length in
+//! bits. The default (all-zero) salt is used.
+//!
+//! ```rust
+//! use sha2::kdf::hmac_kdf_sha256;
+//!
+//! let key = hmac_kdf_sha256(b"shared secret", b"label", b"context", 32);
+//! assert_eq!(key.len(), 32);
+//! ```
+//!
+//! [1]: https://doi.org/10.6028/NIST.SP.800-56Cr2
+use crate::{HmacSha256, HmacSha512};
+use hmac::{Mac, NewMac};
+use std::vec::Vec;
+
+/// Derive `length` bytes from the shared secret `z` using HMAC-SHA-256.
+///
+/// # Panics
+/// If `length * 8` does not fit in 32 bits.
+pub fn hmac_kdf_sha256(z: &[u8], label: &[u8], context: &[u8], length: usize) -> Vec<u8> {
+    one_step::<HmacSha256>(z, label, context, length)
+}
+
+/// Derive `length` bytes from the shared secret `z` using HMAC-SHA-512.
+///
+/// # Panics
+/// If `length * 8` does not fit in 32 bits.
+pub fn hmac_kdf_sha512(z: &[u8], label: &[u8], context: &[u8], length: usize) -> Vec<u8> {
+    one_step::<HmacSha512>(z, label, context, length)
+}
+
+fn one_step<M: Mac + NewMac + Clone>(
+    z: &[u8],
+    label: &[u8],
+    context: &[u8],
+    length: usize,
+) -> Vec<u8> {
+    let bits = length
+        .checked_mul(8)
+        .filter(|&b| b <= u32::MAX as usize)
+        .expect("output length too large") as u32;
+
+    let prf = M::new_from_slice(&[]).expect("HMAC accepts keys of any length");
+    let mut out = Vec::with_capacity(length);
+    let mut counter = 1u32;
+    while out.len() < length {
+        let mut mac = prf.clone();
+        mac.update(&counter.to_be_bytes());
+        mac.update(z);
+        mac.update(label);
+        mac.update(&[0x00]);
+        mac.update(context);
+        mac.update(&bits.to_be_bytes());
+        let block = mac.finalize().into_bytes();
+        let n = core::cmp::min(block.len(), length - out.len());
+        out.extend_from_slice(&block[..n]);
+        counter += 1;
+    }
+    out
+}
@@ -13,6 +13,10 @@
 //! * `Sha512Trunc256`, which is the 8 x 64-bit `Sha512` algorithm with the result
 //! truncated to 256 bits.
 //!
+//! Both truncated variants are instances of the general `Sha512t` construction,
+//! which supports any output size that is a multiple of 8 bits below 512,
+//! except 384.
+//!
 //! Algorithmically, there are only 2 core algorithms: `Sha256` and `Sha512`.
 //! All other algorithms are just applications of these with different initial
 //! hash values, and truncated to different digest bit lengths.
@@ -47,6 +51,44 @@
 //! ")[..]);
 //! ```
 //!
+//! Digests implement [`LowerHex`][core::fmt::LowerHex] and
+//! [`UpperHex`][core::fmt::UpperHex], so they can be printed as hex strings
+//! without pulling in a separate hex crate:
+//!
+//! ```rust
+//! use sha2::{Digest, Sha256};
+//!
+//! let s = format!("{:x}", Sha256::digest(b"abc"));
+//! assert_eq!(s, "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+//! ```
+//!
+//! With the `hmac` feature enabled, HMAC type aliases are available as well:
+//!
+//! ```rust
+//! # #[cfg(feature = "hmac")]
+//! # {
+//! use hex_literal::hex;
+//! use sha2::HmacSha256;
+//! use sha2::hmac::{Mac, NewMac};
+//!
+//! let mut mac = HmacSha256::new_from_slice(b"Jefe").unwrap();
+//! mac.update(b"what do ya want for nothing?");
+//! let tag = mac.finalize().into_bytes();
+//!
+//! assert_eq!(tag[..], hex!("
+//!     5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843
+//! ")[..]);
+//! # }
+//! ```
+//!
+//! The `drbg` feature enables `Hash_DRBG` over SHA-256 in the `drbg` module,
+//! the `mgf` feature enables the MGF1 mask generation function in the `mgf`
+//! module, the `kdf` feature enables the SP 800-56C one-step KDF in the
+//! `kdf` module, the `batch` feature enables multi-buffer SHA-256
+//! compression in the `parallel` module, and the `opaque-digest` feature
+//! enables the `OpaqueDigest` wrapper which hides hash values from
+//! formatting.
+//!
 //! Also see [RustCrypto/hashes][2] readme.
 //!
 //! [1]: https://en.wikipedia.org/wiki/SHA-2
@@ -60,17 +102,76 @@
 )]
 #![warn(missing_docs, rust_2018_idioms)]
 
+#[cfg(feature = "wasm-bindgen")]
+extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
 mod consts;
+#[cfg(feature = "drbg")]
+#[cfg_attr(docsrs, doc(cfg(feature = "drbg")))]
+pub mod drbg;
+#[cfg(feature = "kdf")]
+#[cfg_attr(docsrs, doc(cfg(feature = "kdf")))]
+pub mod kdf;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod merkle;
+#[cfg(feature = "mgf")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mgf")))]
+pub mod mgf;
+#[cfg(feature = "opaque-digest")]
+mod opaque;
+#[cfg(feature = "batch")]
+#[cfg_attr(docsrs, doc(cfg(feature = "batch")))]
+pub mod parallel;
+#[cfg(feature = "pbkdf2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pbkdf2")))]
+pub mod pbkdf2;
 mod sha256;
 mod sha512;
+#[cfg(feature = "wasm-bindgen")]
+mod wasm;
 
 pub use digest::{self, Digest};
+#[cfg(feature = "opaque-digest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "opaque-digest")))]
+pub use opaque::OpaqueDigest;
 #[cfg(feature = "compress")]
 pub use sha256::compress256;
-pub use sha256::{Sha224, Sha256};
+#[cfg(all(feature = "compress", target_feature = "avx2"))]
+pub use sha256::compress256x8;
+pub use sha256::{Sha224, Sha256, SHA256_SNAPSHOT_LEN};
 #[cfg(feature = "compress")]
 pub use sha512::compress512;
-pub use sha512::{Sha384, Sha512, Sha512Trunc224, Sha512Trunc256};
+pub use sha512::{
+    Sha384, Sha512, Sha512Trunc224, Sha512Trunc256, Sha512t, Sha512tSize, SHA512_SNAPSHOT_LEN,
+};
+#[cfg(feature = "wasm-bindgen")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasm-bindgen")))]
+pub use wasm::JsSha256;
+
+#[cfg(feature = "hmac")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hmac")))]
+pub use hmac;
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub use zeroize_crate as zeroize;
+
+/// HMAC-SHA-224
+#[cfg(feature = "hmac")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hmac")))]
+pub type HmacSha224 = hmac::Hmac<Sha224>;
+/// HMAC-SHA-256
+#[cfg(feature = "hmac")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hmac")))]
+pub type HmacSha256 = hmac::Hmac<Sha256>;
+/// HMAC-SHA-384
+#[cfg(feature = "hmac")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hmac")))]
+pub type HmacSha384 = hmac::Hmac<Sha384>;
+/// HMAC-SHA-512
+#[cfg(feature = "hmac")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hmac")))]
+pub type HmacSha512 = hmac::Hmac<Sha512>;
@@ -0,0 +1,118 @@
+//! Merkle tree hashing as specified in [RFC 6962 § 2.1][1].
+//!
+//! Leaves are hashed as `H(0x00 || leaf)` and interior nodes as
+//! `H(0x01 || left || right)`, which separates the two domains and prevents
+//! second-preimage attacks which substitute an interior node for a leaf.
+//!
+//! ```rust
+//! use hex_literal::hex;
+//! use sha2::{merkle::MerkleTree, Sha256};
+//!
+//! let tree = MerkleTree::<Sha256>::new(&["", "\0"]);
+//! assert_eq!(tree.root()[..], hex!("
+//!     fac54203e7cc696cf0dfcb42c92a1d9dbaf70ad9e621f4bd8d98662f00e3c125
+//! ")[..]);
+//! ```
+//!
+//! [1]: https://tools.ietf.org/html/rfc6962#section-2.1
+use digest::{Digest, Output};
+use std::vec::Vec;
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Merkle tree over a list of leaves, generic over the hash function.
+#[derive(Clone, Debug)]
+pub struct MerkleTree<D: Digest> {
+    leaves: Vec<Output<D>>,
+}
+
+impl<D: Digest> MerkleTree<D> {
+    /// Build a tree from the given leaf values.
+    pub fn new<T: AsRef<[u8]>>(leaves: &[T]) -> Self {
+        let leaves = leaves
+            .iter()
+            .map(|leaf| {
+                let mut d = D::new();
+                d.update([LEAF_PREFIX]);
+                d.update(leaf.as_ref());
+                d.finalize()
+            })
+            .collect();
+        MerkleTree { leaves }
+    }
+
+    /// Number of leaves in the tree.
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Returns `true` if the tree has no leaves.
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Merkle tree hash of the leaves.
+    ///
+    /// The root of an empty tree is the hash of the empty string.
+    pub fn root(&self) -> Output<D> {
+        if self.leaves.is_empty() {
+            return D::digest(&[]);
+        }
+        subtree_root::<D>(&self.leaves)
+    }
+
+    /// Audit path for the leaf at `leaf_index`, ordered from the leaf level
+    /// up to the root.
+    ///
+    /// # Panics
+    ///
+    /// If `leaf_index` is out of bounds.
+    pub fn proof(&self, leaf_index: usize) -> Vec<Output<D>> {
+        assert!(leaf_index < self.leaves.len(), "leaf index out of bounds");
+        let mut path = Vec::new();
+        audit_path::<D>(leaf_index, &self.leaves, &mut path);
+        path
+    }
+}
+
+/// Largest power of two smaller than `n`, which must be greater than 1.
+fn split_point(n: usize) -> usize {
+    debug_assert!(n > 1);
+    let mut k = 1;
+    while k << 1 < n {
+        k <<= 1;
+    }
+    k
+}
+
+fn node_hash<D: Digest>(left: &Output<D>, right: &Output<D>) -> Output<D> {
+    let mut d = D::new();
+    d.update([NODE_PREFIX]);
+    d.update(left);
+    d.update(right);
+    d.finalize()
+}
+
+fn subtree_root<D: Digest>(leaves: &[Output<D>]) -> Output<D> {
+    if leaves.len() == 1 {
+        return leaves[0].clone();
+    }
+    let (left, right) = leaves.split_at(split_point(leaves.len()));
+    node_hash::<D>(&subtree_root::<D>(left), &subtree_root::<D>(right))
+}
+
+fn audit_path<D: Digest>(m: usize, leaves: &[Output<D>], path: &mut Vec<Output<D>>) {
+    if leaves.len() <= 1 {
+        return;
+    }
+    let k = split_point(leaves.len());
+    let (left, right) = leaves.split_at(k);
+    if m < k {
+        audit_path::<D>(m, left, path);
+        path.push(subtree_root::<D>(right));
+    } else {
+        audit_path::<D>(m - k, right, path);
+        path.push(subtree_root::<D>(left));
+    }
+}
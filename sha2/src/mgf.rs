@@ -0,0 +1,21 @@
+//! MGF1 mask generation function over SHA-256, as specified in
+//! [RFC 8017 §B.2.1][1] and used by RSA-OAEP and RSA-PSS.
+//!
+//! This is a thin wrapper over the [`mgf1`][2] crate, which works with any
+//! fixed-output hash.
+//!
+//! [1]: https://tools.ietf.org/html/rfc8017#appendix-B.2.1
+//! [2]: https://docs.rs/mgf1
+use crate::Sha256;
+
+pub use mgf1::MaskTooLong;
+
+/// Write `length` bytes of `MGF1(seed)` into the beginning of `output`.
+///
+/// Returns [`MaskTooLong`] if `length` exceeds `2^32` hash outputs.
+///
+/// # Panics
+/// If `output` is shorter than `length`.
+pub fn mgf1_sha256(seed: &[u8], length: usize, output: &mut [u8]) -> Result<(), MaskTooLong> {
+    mgf1::mgf1::<Sha256>(seed, length, output)
+}
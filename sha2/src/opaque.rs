@@ -0,0 +1,73 @@
+//! Digest output wrapper which hides its value from formatting.
+use core::fmt;
+use digest::{Digest, Output};
+use subtle::ConstantTimeEq;
+
+/// Hash output which is not revealed by its [`Display`][fmt::Display] and
+/// [`Debug`][fmt::Debug] implementations.
+///
+/// Useful for values such as password hashes or MAC tags which should not
+/// end up in logs by accident. The bytes are still accessible explicitly via
+/// [`OpaqueDigest::reveal`] or [`AsRef<[u8]>`][AsRef], and comparison between
+/// two `OpaqueDigest`s is performed in constant time.
+///
+/// ```rust
+/// use sha2::{Digest, OpaqueDigest, Sha256};
+///
+/// let digest = OpaqueDigest::<Sha256>::new(Sha256::digest(b"hunter2"));
+/// assert_eq!(format!("{}", digest), "[redacted]");
+/// assert_eq!(format!("{:?}", digest), "OpaqueDigest(SHA-256)");
+/// ```
+pub struct OpaqueDigest<D: Digest>(Output<D>);
+
+impl<D: Digest> OpaqueDigest<D> {
+    /// Wrap digest output.
+    pub fn new(output: Output<D>) -> Self {
+        OpaqueDigest(output)
+    }
+
+    /// Access the wrapped digest output.
+    pub fn reveal(&self) -> &Output<D> {
+        &self.0
+    }
+}
+
+impl<D: Digest> From<Output<D>> for OpaqueDigest<D> {
+    fn from(output: Output<D>) -> Self {
+        OpaqueDigest(output)
+    }
+}
+
+impl<D: Digest> AsRef<[u8]> for OpaqueDigest<D> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<D: Digest> Clone for OpaqueDigest<D> {
+    fn clone(&self) -> Self {
+        OpaqueDigest(self.0.clone())
+    }
+}
+
+impl<D: Digest> PartialEq for OpaqueDigest<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0).into()
+    }
+}
+
+impl<D: Digest> Eq for OpaqueDigest<D> {}
+
+impl<D: Digest> fmt::Display for OpaqueDigest<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[redacted]")
+    }
+}
+
+/// Prints the algorithm name, which the hashers of this crate write with
+/// `Display`, in place of the output.
+impl<D: Digest + fmt::Display> fmt::Debug for OpaqueDigest<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "OpaqueDigest({})", D::new())
+    }
+}
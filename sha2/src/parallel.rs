@@ -0,0 +1,48 @@
+//! Multi-buffer SHA-256 compression for many independent inputs, e.g. the
+//! leaves and interior nodes of a Merkle tree.
+//!
+//! On `x86`/`x86_64` the inputs are processed 4 (SSE2), 8 (AVX2) or, with the
+//! `batch-avx512` feature, 16 (AVX-512F) at a time, one per 32-bit SIMD lane.
+//! Inputs which do not fill a whole group are compressed one by one with the
+//! regular backend. On CPUs with the SHA extensions that backend outperforms
+//! the 4- and 8-wide kernels, so only the AVX-512 kernel is used there.
+//!
+//! The `batch-avx512` feature requires Rust 1.89 or newer.
+//!
+//! ```rust
+//! use sha2::parallel::compress256_batch;
+//!
+//! let mut states = [[0u32; 8]; 10];
+//! let blocks = [[0u8; 64]; 10];
+//! compress256_batch(&mut states, &blocks);
+//! ```
+use crate::sha256::compress256;
+use core::slice;
+use digest::generic_array::GenericArray;
+
+cfg_if::cfg_if! {
+    if #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "force-soft"),
+    ))] {
+        mod x86;
+        use x86::compress_lanes;
+    } else {
+        fn compress_lanes(_: &mut [[u32; 8]], _: &[[u8; 64]]) -> usize {
+            0
+        }
+    }
+}
+
+/// Apply the SHA-256 compression function to `states[i]` with `blocks[i]`
+/// for every `i`.
+///
+/// # Panics
+/// If `states` and `blocks` have different lengths.
+pub fn compress256_batch(states: &mut [[u32; 8]], blocks: &[[u8; 64]]) {
+    assert_eq!(states.len(), blocks.len());
+    let done = compress_lanes(states, blocks);
+    for (state, block) in states[done..].iter_mut().zip(&blocks[done..]) {
+        compress256(state, slice::from_ref(GenericArray::from_slice(block)));
+    }
+}
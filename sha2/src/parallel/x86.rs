@@ -0,0 +1,246 @@
+//! Multi-buffer SHA-256 for `x86`/`x86_64`: one independent state per 32-bit
+//! lane of an SSE2, AVX2 or AVX-512 register.
+
+#![allow(unsafe_code, clippy::many_single_char_names)]
+
+use crate::consts::K32;
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::convert::TryInto;
+
+macro_rules! multi_impl {
+    (
+        $name:ident, $feature:literal, $lanes:expr, $vec:ty,
+        $loadu:ident, $storeu:ident, $set1:ident, $add:ident,
+        $xor:ident, $and:ident, $andnot:ident, $or:ident, $srli:ident, $slli:ident
+    ) => {
+        #[target_feature(enable = $feature)]
+        unsafe fn $name(states: &mut [[u32; 8]], blocks: &[[u8; 64]]) {
+            debug_assert!(states.len() == $lanes && blocks.len() == $lanes);
+
+            macro_rules! rotr {
+                ($x:expr, $n:expr) => {
+                    $or($srli($x, $n), $slli($x, 32 - $n))
+                };
+            }
+            macro_rules! gather {
+                ($f:expr) => {{
+                    let mut buf = [0u32; $lanes];
+                    for (i, v) in buf.iter_mut().enumerate() {
+                        *v = $f(i);
+                    }
+                    $loadu(buf.as_ptr() as *const _)
+                }};
+            }
+
+            let mut w = [$set1(0); 16];
+            for (t, w) in w.iter_mut().enumerate() {
+                *w = gather!(|i: usize| u32::from_be_bytes(
+                    blocks[i][4 * t..4 * t + 4].try_into().unwrap()
+                ));
+            }
+            let mut h = [$set1(0); 8];
+            for (j, h) in h.iter_mut().enumerate() {
+                *h = gather!(|i: usize| states[i][j]);
+            }
+            let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh] = h;
+
+            for t in 0..64 {
+                if t >= 16 {
+                    let w15 = w[(t - 15) & 15];
+                    let w2 = w[(t - 2) & 15];
+                    let s0 = $xor($xor(rotr!(w15, 7), rotr!(w15, 18)), $srli(w15, 3));
+                    let s1 = $xor($xor(rotr!(w2, 17), rotr!(w2, 19)), $srli(w2, 10));
+                    w[t & 15] = $add($add(w[t & 15], s0), $add(w[(t - 7) & 15], s1));
+                }
+                let s1 = $xor($xor(rotr!(e, 6), rotr!(e, 11)), rotr!(e, 25));
+                let ch = $xor($and(e, f), $andnot(e, g));
+                let t1 = $add(
+                    $add(hh, s1),
+                    $add(ch, $add($set1(K32[t] as i32), w[t & 15])),
+                );
+                let s0 = $xor($xor(rotr!(a, 2), rotr!(a, 13)), rotr!(a, 22));
+                let maj = $or($and(a, b), $and(c, $or(a, b)));
+                let t2 = $add(s0, maj);
+                hh = g;
+                g = f;
+                f = e;
+                e = $add(d, t1);
+                d = c;
+                c = b;
+                b = a;
+                a = $add(t1, t2);
+            }
+
+            let res = [a, b, c, d, e, f, g, hh];
+            for (j, (v, h)) in res.iter().zip(h.iter()).enumerate() {
+                let mut buf = [0u32; $lanes];
+                $storeu(buf.as_mut_ptr() as *mut _, $add(*v, *h));
+                for (state, word) in states.iter_mut().zip(buf.iter()) {
+                    state[j] = *word;
+                }
+            }
+        }
+    };
+}
+
+multi_impl!(
+    compress_sse2,
+    "sse2",
+    4,
+    __m128i,
+    _mm_loadu_si128,
+    _mm_storeu_si128,
+    _mm_set1_epi32,
+    _mm_add_epi32,
+    _mm_xor_si128,
+    _mm_and_si128,
+    _mm_andnot_si128,
+    _mm_or_si128,
+    _mm_srli_epi32,
+    _mm_slli_epi32
+);
+
+multi_impl!(
+    compress_avx2,
+    "avx2",
+    8,
+    __m256i,
+    _mm256_loadu_si256,
+    _mm256_storeu_si256,
+    _mm256_set1_epi32,
+    _mm256_add_epi32,
+    _mm256_xor_si256,
+    _mm256_and_si256,
+    _mm256_andnot_si256,
+    _mm256_or_si256,
+    _mm256_srli_epi32,
+    _mm256_slli_epi32
+);
+
+#[cfg(feature = "batch-avx512")]
+multi_impl!(
+    compress_avx512,
+    "avx512f",
+    16,
+    __m512i,
+    _mm512_loadu_si512,
+    _mm512_storeu_si512,
+    _mm512_set1_epi32,
+    _mm512_add_epi32,
+    _mm512_xor_si512,
+    _mm512_and_si512,
+    _mm512_andnot_si512,
+    _mm512_or_si512,
+    _mm512_srli_epi32,
+    _mm512_slli_epi32
+);
+
+cpufeatures::new!(shani_cpuid, "sha", "sse2", "ssse3", "sse4.1");
+cpufeatures::new!(sse2_cpuid, "sse2");
+cpufeatures::new!(avx2_cpuid, "avx2");
+#[cfg(feature = "batch-avx512")]
+cpufeatures::new!(avx512_cpuid, "avx512f");
+
+/// Process `chunk`-sized groups of inputs with `f`, returning the number of
+/// inputs handled.
+fn run(
+    states: &mut [[u32; 8]],
+    blocks: &[[u8; 64]],
+    chunk: usize,
+    f: unsafe fn(&mut [[u32; 8]], &[[u8; 64]]),
+) -> usize {
+    let n = states.len() - states.len() % chunk;
+    for (s, b) in states[..n].chunks_mut(chunk).zip(blocks.chunks(chunk)) {
+        unsafe { f(s, b) };
+    }
+    n
+}
+
+/// Compress as many inputs as possible using the widest available backend,
+/// returning how many were processed. The rest is left to the caller.
+///
+/// The SHA extensions process a single stream faster than the 4- and 8-wide
+/// kernels, so those are skipped when SHA-NI is available.
+pub(super) fn compress_lanes(states: &mut [[u32; 8]], blocks: &[[u8; 64]]) -> usize {
+    let mut done = 0;
+    #[cfg(feature = "batch-avx512")]
+    {
+        if avx512_cpuid::get() {
+            done += run(&mut states[done..], &blocks[done..], 16, compress_avx512);
+        }
+    }
+    if shani_cpuid::get() {
+        return done;
+    }
+    if avx2_cpuid::get() {
+        done += run(&mut states[done..], &blocks[done..], 8, compress_avx2);
+    }
+    if sse2_cpuid::get() {
+        done += run(&mut states[done..], &blocks[done..], 4, compress_sse2);
+    }
+    done
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sha256::soft;
+    use core::slice;
+
+    /// Check a kernel of `lanes` lanes against the single-stream software
+    /// implementation on pseudorandom states and blocks.
+    fn check(lanes: usize, f: unsafe fn(&mut [[u32; 8]], &[[u8; 64]])) {
+        let mut x = 0x9e37_79b9_7f4a_7c15u64;
+        let mut next = move || {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            x
+        };
+        let mut states = [[0u32; 8]; 16];
+        let mut blocks = [[0u8; 64]; 16];
+        for (s, b) in states.iter_mut().zip(blocks.iter_mut()) {
+            for w in s.iter_mut() {
+                *w = next() as u32;
+            }
+            for c in b.chunks_exact_mut(8) {
+                c.copy_from_slice(&next().to_le_bytes());
+            }
+        }
+        let (states, blocks) = (&mut states[..lanes], &blocks[..lanes]);
+
+        let mut expected = [[0u32; 8]; 16];
+        let expected = &mut expected[..lanes];
+        expected.copy_from_slice(states);
+        for (s, b) in expected.iter_mut().zip(blocks.iter()) {
+            soft::compress(s, slice::from_ref(b));
+        }
+        unsafe { f(states, blocks) };
+        assert_eq!(states, expected);
+    }
+
+    #[test]
+    fn sse2() {
+        if sse2_cpuid::get() {
+            check(4, compress_sse2);
+        }
+    }
+
+    #[test]
+    fn avx2() {
+        if avx2_cpuid::get() {
+            check(8, compress_avx2);
+        }
+    }
+
+    #[cfg(feature = "batch-avx512")]
+    #[test]
+    fn avx512() {
+        if avx512_cpuid::get() {
+            check(16, compress_avx512);
+        }
+    }
+}
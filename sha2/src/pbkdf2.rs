@@ -0,0 +1,21 @@
+//! PBKDF2 with HMAC-SHA-256 as the PRF, as specified in
+//! [RFC 8018 §5.2][1].
+//!
+//! This is a thin wrapper over the [`pbkdf2`][2] crate.
+//!
+//! ```rust
+//! use sha2::pbkdf2::pbkdf2_sha256;
+//!
+//! let mut key = [0u8; 32];
+//! pbkdf2_sha256(b"password", b"salt", 100_000, &mut key);
+//! ```
+//!
+//! [1]: https://tools.ietf.org/html/rfc8018#section-5.2
+//! [2]: https://docs.rs/pbkdf2
+use crate::HmacSha256;
+
+/// Derive `output.len()` bytes from `password` and `salt` with
+/// PBKDF2-HMAC-SHA256 using `iterations` rounds.
+pub fn pbkdf2_sha256(password: &[u8], salt: &[u8], iterations: u32, output: &mut [u8]) {
+    pbkdf2_crate::pbkdf2::<HmacSha256>(password, salt, iterations, output);
+}
@@ -1,6 +1,8 @@
 //! SHA-256
 use crate::consts::{H224, H256, STATE_LEN};
-use block_buffer::BlockBuffer;
+use block_buffer::{block_padding::ZeroPadding, BlockBuffer};
+use core::convert::TryInto;
+use core::fmt;
 use core::slice::from_ref;
 use digest::consts::{U28, U32, U64};
 use digest::generic_array::GenericArray;
@@ -8,6 +10,14 @@ use digest::{BlockInput, FixedOutputDirty, Reset, Update};
 
 type BlockSize = U64;
 
+/// Size in bytes of a snapshot created by [`Sha256::serialize_state`] or
+/// [`Sha224::serialize_state`].
+///
+/// Both hashers use the same snapshot format, which does not record the
+/// variant. A snapshot has to be restored with the type that created it,
+/// restoring it with the other one is not detected and yields a wrong digest.
+pub const SHA256_SNAPSHOT_LEN: usize = 4 * STATE_LEN + 8 + 1 + 64;
+
 /// Structure that keeps state of the Sha-256 operation and
 /// contains the logic necessary to perform the final calculations.
 #[derive(Clone)]
@@ -45,6 +55,76 @@ impl Engine256 {
         self.buffer.reset();
         self.state = *h;
     }
+
+    fn serialize(&self) -> [u8; SHA256_SNAPSHOT_LEN] {
+        let mut out = [0u8; SHA256_SNAPSHOT_LEN];
+        let (words, rest) = out.split_at_mut(4 * STATE_LEN);
+        for (chunk, v) in words.chunks_exact_mut(4).zip(self.state.iter()) {
+            chunk.copy_from_slice(&v.to_be_bytes());
+        }
+        let (block_len, rest) = rest.split_at_mut(8);
+        block_len.copy_from_slice(&(self.len >> 9).to_be_bytes());
+
+        rest[0] = self.buffer.position() as u8;
+        // zero padding a copy of the buffer yields the buffered bytes
+        // followed by zeros, which is how the snapshot stores them
+        let mut buffer = self.buffer.clone();
+        let block = buffer
+            .pad_with::<ZeroPadding>()
+            .expect("we never use input_lazy");
+        rest[1..].copy_from_slice(block);
+        out
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != SHA256_SNAPSHOT_LEN {
+            return None;
+        }
+        let (words, rest) = bytes.split_at(4 * STATE_LEN);
+        let (block_len, rest) = rest.split_at(8);
+        let pos = rest[0] as usize;
+        if pos >= 64 {
+            return None;
+        }
+        let len = u64::from_be_bytes(block_len.try_into().unwrap())
+            .checked_mul(512)?
+            .checked_add(8 * pos as u64)?;
+
+        let mut h = [0u32; STATE_LEN];
+        for (v, chunk) in h.iter_mut().zip(words.chunks_exact(4)) {
+            *v = u32::from_be_bytes(chunk.try_into().unwrap());
+        }
+        let mut engine = Engine256::new(&h);
+        // less than a block, so nothing gets compressed
+        let s = &mut engine.state;
+        engine
+            .buffer
+            .input_blocks(&rest[1..1 + pos], |b| compress256(s, b));
+        engine.len = len;
+        Some(engine)
+    }
+}
+
+/// Overwrites the chaining value, the length and the buffered input with
+/// zeros.
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::Zeroize for Engine256 {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+        self.len.zeroize();
+        // the buffer is never full, so padding can not fail
+        if let Ok(block) = self.buffer.pad_with::<ZeroPadding>() {
+            block.as_mut_slice().zeroize();
+        }
+        self.buffer.reset();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Engine256 {
+    fn drop(&mut self) {
+        zeroize_crate::Zeroize::zeroize(self);
+    }
 }
 
 /// The SHA-256 hash algorithm with the SHA-256 initial hash value.
@@ -61,6 +141,57 @@ impl Default for Sha256 {
     }
 }
 
+impl Sha256 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "SHA-256";
+
+    /// Create a hasher from a raw chaining value `h` after `block_len`
+    /// 64-byte blocks of input have been processed.
+    ///
+    /// Returns `None` if the message length in bits would overflow.
+    pub fn from_raw_state(h: [u32; STATE_LEN], block_len: u64) -> Option<Self> {
+        let len = block_len.checked_mul(512)?;
+        let mut engine = Engine256::new(&h);
+        engine.len = len;
+        Some(Sha256 { engine })
+    }
+
+    /// Get the raw chaining value.
+    ///
+    /// Input which does not fill a complete block yet is not covered by it.
+    pub fn get_state(&self) -> [u32; STATE_LEN] {
+        self.engine.state
+    }
+
+    /// Serialize the hasher state, including input which does not fill a
+    /// complete block yet.
+    ///
+    /// The snapshot consists of the eight state words, the number of
+    /// processed blocks as a `u64`, the number of buffered bytes as a single
+    /// byte and the buffered bytes zero-padded to the block size. All
+    /// integers are big-endian.
+    pub fn serialize_state(&self) -> [u8; SHA256_SNAPSHOT_LEN] {
+        self.engine.serialize()
+    }
+
+    /// Restore a hasher from a snapshot created by `serialize_state`.
+    ///
+    /// Returns `None` if `bytes` is not [`SHA256_SNAPSHOT_LEN`] bytes long or
+    /// is malformed. Whether the snapshot was taken from the same variant is
+    /// not checked.
+    pub fn deserialize_state(bytes: &[u8]) -> Option<Self> {
+        Some(Sha256 {
+            engine: Engine256::deserialize(bytes)?,
+        })
+    }
+}
+
+impl fmt::Display for Sha256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Sha256 {
     type BlockSize = BlockSize;
 }
@@ -104,6 +235,39 @@ impl Default for Sha224 {
     }
 }
 
+impl Sha224 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "SHA-224";
+
+    /// Serialize the hasher state, including input which does not fill a
+    /// complete block yet.
+    ///
+    /// The snapshot consists of the eight state words, the number of
+    /// processed blocks as a `u64`, the number of buffered bytes as a single
+    /// byte and the buffered bytes zero-padded to the block size. All
+    /// integers are big-endian.
+    pub fn serialize_state(&self) -> [u8; SHA256_SNAPSHOT_LEN] {
+        self.engine.serialize()
+    }
+
+    /// Restore a hasher from a snapshot created by `serialize_state`.
+    ///
+    /// Returns `None` if `bytes` is not [`SHA256_SNAPSHOT_LEN`] bytes long or
+    /// is malformed. Whether the snapshot was taken from the same variant is
+    /// not checked.
+    pub fn deserialize_state(bytes: &[u8]) -> Option<Self> {
+        Some(Sha224 {
+            engine: Engine256::deserialize(bytes)?,
+        })
+    }
+}
+
+impl fmt::Display for Sha224 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Sha224 {
     type BlockSize = BlockSize;
 }
@@ -132,6 +296,24 @@ impl Reset for Sha224 {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::Zeroize for Sha224 {
+    fn zeroize(&mut self) {
+        zeroize_crate::Zeroize::zeroize(&mut self.engine);
+    }
+}
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::Zeroize for Sha256 {
+    fn zeroize(&mut self) {
+        zeroize_crate::Zeroize::zeroize(&mut self.engine);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::ZeroizeOnDrop for Sha224 {}
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::ZeroizeOnDrop for Sha256 {}
+
 opaque_debug::implement!(Sha224);
 opaque_debug::implement!(Sha256);
 
@@ -142,21 +324,31 @@ cfg_if::cfg_if! {
     if #[cfg(feature = "force-soft")] {
         mod soft;
         use soft::compress;
+    } else if #[cfg(all(feature = "s390x-cpacf", target_arch = "s390x"))] {
+        mod soft;
+        mod s390x;
+        use s390x::compress;
     } else if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
         #[cfg(not(feature = "asm"))]
-        mod soft;
+        pub(crate) mod soft;
         #[cfg(feature = "asm")]
-        mod soft {
+        pub(crate) mod soft {
             pub(crate) fn compress(state: &mut [u32; 8], blocks: &[[u8; 64]]) {
                 sha2_asm::compress256(state, blocks);
             }
         }
         mod x86;
         use x86::compress;
-    } else if #[cfg(all(feature = "asm", target_arch = "aarch64"))] {
+    } else if #[cfg(all(
+        any(feature = "asm", feature = "aarch64-sha2"),
+        target_arch = "aarch64",
+    ))] {
         mod soft;
         mod aarch64;
         use aarch64::compress;
+    } else if #[cfg(all(target_arch = "riscv64", target_feature = "zknh"))] {
+        mod riscv;
+        use riscv::compress;
     } else {
         mod soft;
         use soft::compress;
@@ -175,3 +367,18 @@ pub fn compress256(state: &mut [u32; 8], blocks: &[GenericArray<u8, U64>]) {
     let blocks: &[[u8; 64]] = unsafe { &*(blocks as *const _ as *const [[u8; 64]]) };
     compress(state, blocks)
 }
+
+#[cfg(all(feature = "compress", target_feature = "avx2"))]
+mod avx2;
+
+/// Raw SHA-256 compression function applied to eight independent states at
+/// once, `states[i]` with `blocks[i]`, using AVX2.
+///
+/// Only available when AVX2 is enabled at compile time, e.g. with
+/// `RUSTFLAGS="-C target-feature=+avx2"`. The `batch` feature offers
+/// run-time detection for any number of inputs.
+#[cfg(all(feature = "compress", target_feature = "avx2"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "compress", target_feature = "avx2"))))]
+pub fn compress256x8(states: &mut [[u32; 8]; 8], blocks: &[[u8; 64]; 8]) {
+    avx2::compress(states, blocks)
+}
@@ -1,15 +1,62 @@
 //! SHA-256 `aarch64` backend.
-
-// TODO: stdarch intrinsics: RustCrypto/hashes#257
+//!
+//! With the `aarch64-sha2` feature the ARMv8 SHA2 extension is used through
+//! the `core::arch` intrinsics, otherwise through `sha2-asm`. Support for the
+//! extension is detected at compile time, or at run time where the target
+//! does not guarantee it.
 
 cpufeatures::new!(sha2_hwcap, "sha2");
 
 pub fn compress(state: &mut [u32; 8], blocks: &[[u8; 64]]) {
-    // TODO: Replace with https://github.com/rust-lang/rfcs/pull/2725
-    // after stabilization
     if sha2_hwcap::get() {
+        #[cfg(feature = "aarch64-sha2")]
+        unsafe {
+            sha256_compress(state, blocks);
+        }
+        #[cfg(not(feature = "aarch64-sha2"))]
         sha2_asm::compress256(state, blocks);
     } else {
         super::soft::compress(state, blocks);
     }
 }
+
+#[cfg(feature = "aarch64-sha2")]
+#[target_feature(enable = "sha2")]
+unsafe fn sha256_compress(state: &mut [u32; 8], blocks: &[[u8; 64]]) {
+    use crate::consts::K32;
+    use core::arch::aarch64::*;
+
+    let mut abcd = vld1q_u32(state[0..4].as_ptr());
+    let mut efgh = vld1q_u32(state[4..8].as_ptr());
+
+    for block in blocks {
+        let abcd_orig = abcd;
+        let efgh_orig = efgh;
+
+        // message words are big-endian
+        let mut w = [
+            vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(block[0..16].as_ptr()))),
+            vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(block[16..32].as_ptr()))),
+            vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(block[32..48].as_ptr()))),
+            vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(block[48..64].as_ptr()))),
+        ];
+
+        // 16 groups of 4 rounds, the schedule runs 4 groups ahead
+        for i in 0..16 {
+            let wk = vaddq_u32(w[i % 4], vld1q_u32(K32[4 * i..].as_ptr()));
+            let abcd_prev = abcd;
+            abcd = vsha256hq_u32(abcd_prev, efgh, wk);
+            efgh = vsha256h2q_u32(efgh, abcd_prev, wk);
+            if i < 12 {
+                let t = vsha256su0q_u32(w[i % 4], w[(i + 1) % 4]);
+                w[i % 4] = vsha256su1q_u32(t, w[(i + 2) % 4], w[(i + 3) % 4]);
+            }
+        }
+
+        abcd = vaddq_u32(abcd, abcd_orig);
+        efgh = vaddq_u32(efgh, efgh_orig);
+    }
+
+    vst1q_u32(state[0..4].as_mut_ptr(), abcd);
+    vst1q_u32(state[4..8].as_mut_ptr(), efgh);
+}
@@ -0,0 +1,132 @@
+//! Eight-way SHA-256 compression with AVX2. The words of the eight messages
+//! and states are transposed in registers, so that each 32-bit lane carries
+//! one independent stream through the rounds.
+
+#![allow(unsafe_code, clippy::many_single_char_names)]
+
+use crate::consts::K32;
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+macro_rules! rotr {
+    ($x:expr, $n:expr) => {
+        _mm256_or_si256(_mm256_srli_epi32($x, $n), _mm256_slli_epi32($x, 32 - $n))
+    };
+}
+
+/// Transpose eight rows of eight 32-bit words, so that word `j` of row `i`
+/// ends up as word `i` of row `j`.
+#[inline(always)]
+unsafe fn transpose(r: [__m256i; 8]) -> [__m256i; 8] {
+    // pairs of words: r0[0] r1[0] r0[1] r1[1] | r0[4] r1[4] r0[5] r1[5], ...
+    let t0 = _mm256_unpacklo_epi32(r[0], r[1]);
+    let t1 = _mm256_unpackhi_epi32(r[0], r[1]);
+    let t2 = _mm256_unpacklo_epi32(r[2], r[3]);
+    let t3 = _mm256_unpackhi_epi32(r[2], r[3]);
+    let t4 = _mm256_unpacklo_epi32(r[4], r[5]);
+    let t5 = _mm256_unpackhi_epi32(r[4], r[5]);
+    let t6 = _mm256_unpacklo_epi32(r[6], r[7]);
+    let t7 = _mm256_unpackhi_epi32(r[6], r[7]);
+
+    // quads: r0[0] r1[0] r2[0] r3[0] | r0[4] r1[4] r2[4] r3[4], ...
+    let u0 = _mm256_unpacklo_epi64(t0, t2);
+    let u1 = _mm256_unpackhi_epi64(t0, t2);
+    let u2 = _mm256_unpacklo_epi64(t1, t3);
+    let u3 = _mm256_unpackhi_epi64(t1, t3);
+    let u4 = _mm256_unpacklo_epi64(t4, t6);
+    let u5 = _mm256_unpackhi_epi64(t4, t6);
+    let u6 = _mm256_unpacklo_epi64(t5, t7);
+    let u7 = _mm256_unpackhi_epi64(t5, t7);
+
+    [
+        _mm256_permute2x128_si256(u0, u4, 0x20),
+        _mm256_permute2x128_si256(u1, u5, 0x20),
+        _mm256_permute2x128_si256(u2, u6, 0x20),
+        _mm256_permute2x128_si256(u3, u7, 0x20),
+        _mm256_permute2x128_si256(u0, u4, 0x31),
+        _mm256_permute2x128_si256(u1, u5, 0x31),
+        _mm256_permute2x128_si256(u2, u6, 0x31),
+        _mm256_permute2x128_si256(u3, u7, 0x31),
+    ]
+}
+
+/// Load eight rows of eight words, `f(i)` pointing at row `i`.
+#[inline(always)]
+unsafe fn load_rows(f: impl Fn(usize) -> *const u8) -> [__m256i; 8] {
+    let mut rows = [_mm256_setzero_si256(); 8];
+    for (i, row) in rows.iter_mut().enumerate() {
+        *row = _mm256_loadu_si256(f(i) as *const __m256i);
+    }
+    rows
+}
+
+pub(crate) fn compress(states: &mut [[u32; 8]; 8], blocks: &[[u8; 64]; 8]) {
+    // SAFETY: this module is only built with AVX2 enabled at compile time
+    unsafe { compress_avx2(states, blocks) }
+}
+
+unsafe fn compress_avx2(states: &mut [[u32; 8]; 8], blocks: &[[u8; 64]; 8]) {
+    // message words are big-endian
+    #[rustfmt::skip]
+    let bswap = _mm256_setr_epi8(
+        3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15, 14, 13, 12,
+        3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15, 14, 13, 12,
+    );
+    let mut w = [_mm256_setzero_si256(); 16];
+    let lo = transpose(load_rows(|i| blocks[i].as_ptr()));
+    let hi = transpose(load_rows(|i| blocks[i][32..].as_ptr()));
+    for (w, v) in w.iter_mut().zip(lo.iter().chain(hi.iter())) {
+        *w = _mm256_shuffle_epi8(*v, bswap);
+    }
+
+    let h = transpose(load_rows(|i| states[i].as_ptr() as *const u8));
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh] = h;
+
+    for t in 0..64 {
+        if t >= 16 {
+            let w15 = w[(t - 15) & 15];
+            let w2 = w[(t - 2) & 15];
+            let s0 = _mm256_xor_si256(
+                _mm256_xor_si256(rotr!(w15, 7), rotr!(w15, 18)),
+                _mm256_srli_epi32(w15, 3),
+            );
+            let s1 = _mm256_xor_si256(
+                _mm256_xor_si256(rotr!(w2, 17), rotr!(w2, 19)),
+                _mm256_srli_epi32(w2, 10),
+            );
+            w[t & 15] = _mm256_add_epi32(
+                _mm256_add_epi32(w[t & 15], s0),
+                _mm256_add_epi32(w[(t - 7) & 15], s1),
+            );
+        }
+        let s1 = _mm256_xor_si256(_mm256_xor_si256(rotr!(e, 6), rotr!(e, 11)), rotr!(e, 25));
+        let ch = _mm256_xor_si256(_mm256_and_si256(e, f), _mm256_andnot_si256(e, g));
+        let kw = _mm256_add_epi32(_mm256_set1_epi32(K32[t] as i32), w[t & 15]);
+        let t1 = _mm256_add_epi32(_mm256_add_epi32(hh, s1), _mm256_add_epi32(ch, kw));
+        let s0 = _mm256_xor_si256(_mm256_xor_si256(rotr!(a, 2), rotr!(a, 13)), rotr!(a, 22));
+        let maj = _mm256_or_si256(
+            _mm256_and_si256(a, b),
+            _mm256_and_si256(c, _mm256_or_si256(a, b)),
+        );
+        let t2 = _mm256_add_epi32(s0, maj);
+        hh = g;
+        g = f;
+        f = e;
+        e = _mm256_add_epi32(d, t1);
+        d = c;
+        c = b;
+        b = a;
+        a = _mm256_add_epi32(t1, t2);
+    }
+
+    let res = [a, b, c, d, e, f, g, hh];
+    let mut sum = [_mm256_setzero_si256(); 8];
+    for (s, (v, h)) in sum.iter_mut().zip(res.iter().zip(h.iter())) {
+        *s = _mm256_add_epi32(*v, *h);
+    }
+    for (state, v) in states.iter_mut().zip(transpose(sum).iter()) {
+        _mm256_storeu_si256(state.as_mut_ptr() as *mut __m256i, *v);
+    }
+}
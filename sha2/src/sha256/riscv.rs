@@ -0,0 +1,74 @@
+//! SHA-256 `riscv64` backend using the `sha256sig0`, `sha256sig1`,
+//! `sha256sum0` and `sha256sum1` instructions of the Zknh extension.
+//!
+//! The extension must be enabled at compile time, e.g. with
+//! `RUSTFLAGS="-C target-feature=+zknh"`.
+
+#![allow(clippy::many_single_char_names)]
+
+use crate::consts::K32;
+use core::arch::asm;
+use core::convert::TryInto;
+
+macro_rules! zknh {
+    ($name:ident) => {
+        #[inline(always)]
+        fn $name(x: u32) -> u32 {
+            let rd: usize;
+            // SAFETY: the instruction only reads and writes registers
+            unsafe {
+                asm!(
+                    concat!(stringify!($name), " {rd}, {rs1}"),
+                    rd = lateout(reg) rd,
+                    rs1 = in(reg) x as usize,
+                    options(pure, nomem, nostack),
+                );
+            }
+            rd as u32
+        }
+    };
+}
+
+zknh!(sha256sig0);
+zknh!(sha256sig1);
+zknh!(sha256sum0);
+zknh!(sha256sum1);
+
+pub fn compress(state: &mut [u32; 8], blocks: &[[u8; 64]]) {
+    for block in blocks {
+        let mut w = [0u32; 16];
+        for (w, chunk) in w.iter_mut().zip(block.chunks_exact(4)) {
+            *w = u32::from_be_bytes(chunk.try_into().unwrap());
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+        for t in 0..64 {
+            if t >= 16 {
+                w[t & 15] = w[t & 15]
+                    .wrapping_add(sha256sig0(w[(t - 15) & 15]))
+                    .wrapping_add(w[(t - 7) & 15])
+                    .wrapping_add(sha256sig1(w[(t - 2) & 15]));
+            }
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h
+                .wrapping_add(sha256sum1(e))
+                .wrapping_add(ch)
+                .wrapping_add(K32[t])
+                .wrapping_add(w[t & 15]);
+            let maj = (a & b) | (c & (a | b));
+            let t2 = sha256sum0(a).wrapping_add(maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+
+        for (s, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h].iter()) {
+            *s = s.wrapping_add(*v);
+        }
+    }
+}
@@ -1,13 +1,25 @@
 //! SHA-512
 use crate::consts::{H384, H512, H512_TRUNC_224, H512_TRUNC_256, STATE_LEN};
-use block_buffer::BlockBuffer;
+use block_buffer::{block_padding::ZeroPadding, BlockBuffer};
+use core::convert::TryInto;
+use core::fmt;
+use core::marker::PhantomData;
 use core::slice::from_ref;
 use digest::consts::{U128, U28, U32, U48, U64};
-use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::{IsGreater, IsLess, IsNotEqual, True, U0};
+use digest::generic_array::{ArrayLength, GenericArray};
 use digest::{BlockInput, FixedOutputDirty, Reset, Update};
 
 type BlockSize = U128;
 
+/// Size in bytes of a snapshot created by the `serialize_state` method of the
+/// SHA-512 based hashers.
+///
+/// All of them use the same snapshot format, which does not record the
+/// variant. A snapshot has to be restored with the type that created it,
+/// restoring it with another one is not detected and yields a wrong digest.
+pub const SHA512_SNAPSHOT_LEN: usize = 8 * STATE_LEN + 16 + 1 + 128;
+
 /// Structure that keeps state of the Sha-512 operation and
 /// contains the logic necessary to perform the final calculations.
 #[derive(Clone)]
@@ -43,6 +55,76 @@ impl Engine512 {
         self.buffer.reset();
         self.state = *h;
     }
+
+    fn serialize(&self) -> [u8; SHA512_SNAPSHOT_LEN] {
+        let mut out = [0u8; SHA512_SNAPSHOT_LEN];
+        let (words, rest) = out.split_at_mut(8 * STATE_LEN);
+        for (chunk, v) in words.chunks_exact_mut(8).zip(self.state.iter()) {
+            chunk.copy_from_slice(&v.to_be_bytes());
+        }
+        let (block_len, rest) = rest.split_at_mut(16);
+        block_len.copy_from_slice(&(self.len >> 10).to_be_bytes());
+
+        rest[0] = self.buffer.position() as u8;
+        // zero padding a copy of the buffer yields the buffered bytes
+        // followed by zeros, which is how the snapshot stores them
+        let mut buffer = self.buffer.clone();
+        let block = buffer
+            .pad_with::<ZeroPadding>()
+            .expect("we never use input_lazy");
+        rest[1..].copy_from_slice(block);
+        out
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != SHA512_SNAPSHOT_LEN {
+            return None;
+        }
+        let (words, rest) = bytes.split_at(8 * STATE_LEN);
+        let (block_len, rest) = rest.split_at(16);
+        let pos = rest[0] as usize;
+        if pos >= 128 {
+            return None;
+        }
+        let len = u128::from_be_bytes(block_len.try_into().unwrap())
+            .checked_mul(1024)?
+            .checked_add(8 * pos as u128)?;
+
+        let mut h = [0u64; STATE_LEN];
+        for (v, chunk) in h.iter_mut().zip(words.chunks_exact(8)) {
+            *v = u64::from_be_bytes(chunk.try_into().unwrap());
+        }
+        let mut engine = Engine512::new(&h);
+        // less than a block, so nothing gets compressed
+        let s = &mut engine.state;
+        engine
+            .buffer
+            .input_blocks(&rest[1..1 + pos], |b| compress512(s, b));
+        engine.len = len;
+        Some(engine)
+    }
+}
+
+/// Overwrites the chaining value, the length and the buffered input with
+/// zeros.
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::Zeroize for Engine512 {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+        self.len.zeroize();
+        // the buffer is never full, so padding can not fail
+        if let Ok(block) = self.buffer.pad_with::<ZeroPadding>() {
+            block.as_mut_slice().zeroize();
+        }
+        self.buffer.reset();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Engine512 {
+    fn drop(&mut self) {
+        zeroize_crate::Zeroize::zeroize(self);
+    }
 }
 
 /// The SHA-512 hash algorithm with the SHA-512 initial hash value.
@@ -59,6 +141,57 @@ impl Default for Sha512 {
     }
 }
 
+impl Sha512 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "SHA-512";
+
+    /// Create a hasher from a raw chaining value `h` after `block_len`
+    /// 128-byte blocks of input have been processed.
+    ///
+    /// Returns `None` if the message length in bits would overflow.
+    pub fn from_raw_state(h: [u64; STATE_LEN], block_len: u128) -> Option<Self> {
+        let len = block_len.checked_mul(1024)?;
+        let mut engine = Engine512::new(&h);
+        engine.len = len;
+        Some(Sha512 { engine })
+    }
+
+    /// Get the raw chaining value.
+    ///
+    /// Input which does not fill a complete block yet is not covered by it.
+    pub fn get_state(&self) -> [u64; STATE_LEN] {
+        self.engine.state
+    }
+
+    /// Serialize the hasher state, including input which does not fill a
+    /// complete block yet.
+    ///
+    /// The snapshot consists of the eight state words, the number of
+    /// processed blocks as a `u128`, the number of buffered bytes as a single
+    /// byte and the buffered bytes zero-padded to the block size. All
+    /// integers are big-endian.
+    pub fn serialize_state(&self) -> [u8; SHA512_SNAPSHOT_LEN] {
+        self.engine.serialize()
+    }
+
+    /// Restore a hasher from a snapshot created by `serialize_state`.
+    ///
+    /// Returns `None` if `bytes` is not [`SHA512_SNAPSHOT_LEN`] bytes long or
+    /// is malformed. Whether the snapshot was taken from the same variant is
+    /// not checked.
+    pub fn deserialize_state(bytes: &[u8]) -> Option<Self> {
+        Some(Sha512 {
+            engine: Engine512::deserialize(bytes)?,
+        })
+    }
+}
+
+impl fmt::Display for Sha512 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Sha512 {
     type BlockSize = BlockSize;
 }
@@ -102,6 +235,39 @@ impl Default for Sha384 {
     }
 }
 
+impl Sha384 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "SHA-384";
+
+    /// Serialize the hasher state, including input which does not fill a
+    /// complete block yet.
+    ///
+    /// The snapshot consists of the eight state words, the number of
+    /// processed blocks as a `u128`, the number of buffered bytes as a single
+    /// byte and the buffered bytes zero-padded to the block size. All
+    /// integers are big-endian.
+    pub fn serialize_state(&self) -> [u8; SHA512_SNAPSHOT_LEN] {
+        self.engine.serialize()
+    }
+
+    /// Restore a hasher from a snapshot created by `serialize_state`.
+    ///
+    /// Returns `None` if `bytes` is not [`SHA512_SNAPSHOT_LEN`] bytes long or
+    /// is malformed. Whether the snapshot was taken from the same variant is
+    /// not checked.
+    pub fn deserialize_state(bytes: &[u8]) -> Option<Self> {
+        Some(Sha384 {
+            engine: Engine512::deserialize(bytes)?,
+        })
+    }
+}
+
+impl fmt::Display for Sha384 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Sha384 {
     type BlockSize = BlockSize;
 }
@@ -130,111 +296,233 @@ impl Reset for Sha384 {
     }
 }
 
-/// The SHA-512 hash algorithm with the SHA-512/256 initial hash value. The
-/// result is truncated to 256 bits.
-#[derive(Clone)]
-pub struct Sha512Trunc256 {
+/// The SHA-512/t hash algorithm (FIPS 180-4 §5.3.6): SHA-512 with an
+/// initial hash value derived from `t`, the result truncated to `t` bits.
+///
+/// `T` is the output size in bytes, so `t = 8 * T`. Sizes that FIPS 180-4
+/// does not allow are rejected at compile time:
+///
+/// ```compile_fail
+/// use sha2::{digest::consts::U48, Digest, Sha512t};
+/// Sha512t::<U48>::digest(b"SHA-512/384 is not a valid SHA-512/t");
+/// ```
+pub struct Sha512t<T: Sha512tSize> {
     engine: Engine512,
+    iv: [u64; STATE_LEN],
+    _t: PhantomData<T>,
 }
 
-impl Default for Sha512Trunc256 {
-    fn default() -> Self {
-        Sha512Trunc256 {
-            engine: Engine512::new(&H512_TRUNC_256),
+/// Output sizes `T` accepted by [`Sha512t`]: `0 < T < 64` and `T != 48`.
+pub trait Sha512tSize: ArrayLength<u8> {}
+
+impl<T> Sha512tSize for T where
+    T: ArrayLength<u8>
+        + IsGreater<U0, Output = True>
+        + IsLess<U64, Output = True>
+        + IsNotEqual<U48, Output = True>
+{
+}
+
+/// The SHA-512 hash algorithm with the SHA-512/224 initial hash value.
+/// The result is truncated to 224 bits.
+pub type Sha512Trunc224 = Sha512t<U28>;
+
+/// The SHA-512 hash algorithm with the SHA-512/256 initial hash value. The
+/// result is truncated to 256 bits.
+pub type Sha512Trunc256 = Sha512t<U32>;
+
+impl<T: Sha512tSize> Sha512t<T> {
+    /// Initial hash value for `t = 8 * T`, generated by hashing `"SHA-512/t"`
+    /// unless it is one of the precomputed ones.
+    fn iv() -> [u64; STATE_LEN] {
+        let t = 8 * T::USIZE;
+        match t {
+            224 => H512_TRUNC_224,
+            256 => H512_TRUNC_256,
+            _ => {
+                let mut h = H512;
+                for v in h.iter_mut() {
+                    *v ^= 0xa5a5_a5a5_a5a5_a5a5;
+                }
+                let digits = [
+                    b'0' + (t / 100) as u8,
+                    b'0' + (t / 10 % 10) as u8,
+                    b'0' + (t % 10) as u8,
+                ];
+                let skip = if t >= 100 {
+                    0
+                } else if t >= 10 {
+                    1
+                } else {
+                    2
+                };
+
+                let mut engine = Engine512::new(&h);
+                engine.update(b"SHA-512/");
+                engine.update(&digits[skip..]);
+                engine.finish();
+                engine.state
+            }
         }
     }
-}
 
-impl BlockInput for Sha512Trunc256 {
-    type BlockSize = BlockSize;
-}
+    /// Serialize the hasher state, including input which does not fill a
+    /// complete block yet.
+    ///
+    /// The snapshot consists of the eight state words, the number of
+    /// processed blocks as a `u128`, the number of buffered bytes as a single
+    /// byte and the buffered bytes zero-padded to the block size. All
+    /// integers are big-endian.
+    pub fn serialize_state(&self) -> [u8; SHA512_SNAPSHOT_LEN] {
+        self.engine.serialize()
+    }
 
-impl Update for Sha512Trunc256 {
-    fn update(&mut self, input: impl AsRef<[u8]>) {
-        self.engine.update(input.as_ref());
+    /// Restore a hasher from a snapshot created by `serialize_state`.
+    ///
+    /// Returns `None` if `bytes` is not [`SHA512_SNAPSHOT_LEN`] bytes long or
+    /// is malformed. Whether the snapshot was taken from the same variant is
+    /// not checked.
+    pub fn deserialize_state(bytes: &[u8]) -> Option<Self> {
+        Some(Sha512t {
+            engine: Engine512::deserialize(bytes)?,
+            iv: Self::iv(),
+            _t: PhantomData,
+        })
     }
 }
 
-impl FixedOutputDirty for Sha512Trunc256 {
-    type OutputSize = U32;
+impl Sha512t<U28> {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "SHA-512/224";
+}
 
-    fn finalize_into_dirty(&mut self, out: &mut digest::Output<Self>) {
-        self.engine.finish();
-        let s = &self.engine.state[..4];
-        for (chunk, v) in out.chunks_exact_mut(8).zip(s.iter()) {
-            chunk.copy_from_slice(&v.to_be_bytes());
+impl Sha512t<U32> {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "SHA-512/256";
+}
+
+impl<T: Sha512tSize> Clone for Sha512t<T> {
+    fn clone(&self) -> Self {
+        Sha512t {
+            engine: self.engine.clone(),
+            iv: self.iv,
+            _t: PhantomData,
         }
     }
 }
 
-impl Reset for Sha512Trunc256 {
-    fn reset(&mut self) {
-        self.engine.reset(&H512_TRUNC_256);
+impl<T: Sha512tSize> Default for Sha512t<T> {
+    fn default() -> Self {
+        let iv = Self::iv();
+        Sha512t {
+            engine: Engine512::new(&iv),
+            iv,
+            _t: PhantomData,
+        }
     }
 }
 
-/// The SHA-512 hash algorithm with the SHA-512/224 initial hash value.
-/// The result is truncated to 224 bits.
-#[derive(Clone)]
-pub struct Sha512Trunc224 {
-    engine: Engine512,
+impl<T: Sha512tSize> fmt::Display for Sha512t<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SHA-512/{}", 8 * T::USIZE)
+    }
 }
 
-impl Default for Sha512Trunc224 {
-    fn default() -> Self {
-        Sha512Trunc224 {
-            engine: Engine512::new(&H512_TRUNC_224),
-        }
+impl<T: Sha512tSize> fmt::Debug for Sha512t<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Sha512t { ... }")
     }
 }
 
-impl BlockInput for Sha512Trunc224 {
+impl<T: Sha512tSize> BlockInput for Sha512t<T> {
     type BlockSize = BlockSize;
 }
 
-impl Update for Sha512Trunc224 {
+impl<T: Sha512tSize> Update for Sha512t<T> {
     fn update(&mut self, input: impl AsRef<[u8]>) {
         self.engine.update(input.as_ref());
     }
 }
 
-impl FixedOutputDirty for Sha512Trunc224 {
-    type OutputSize = U28;
+impl<T: Sha512tSize> FixedOutputDirty for Sha512t<T> {
+    type OutputSize = T;
 
     fn finalize_into_dirty(&mut self, out: &mut digest::Output<Self>) {
         self.engine.finish();
-        let s = &self.engine.state;
-        for (chunk, v) in out.chunks_exact_mut(8).zip(s[..3].iter()) {
+        let mut buf = [0u8; 64];
+        for (chunk, v) in buf.chunks_exact_mut(8).zip(self.engine.state.iter()) {
             chunk.copy_from_slice(&v.to_be_bytes());
         }
-        out[24..28].copy_from_slice(&s[3].to_be_bytes()[..4]);
+        out.copy_from_slice(&buf[..T::USIZE]);
     }
 }
 
-impl Reset for Sha512Trunc224 {
+impl<T: Sha512tSize> Reset for Sha512t<T> {
     fn reset(&mut self) {
-        self.engine.reset(&H512_TRUNC_224);
+        self.engine.reset(&self.iv);
     }
 }
 
+#[cfg(feature = "std")]
+impl<T: Sha512tSize> std::io::Write for Sha512t<T> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Update::update(self, buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::Zeroize for Sha384 {
+    fn zeroize(&mut self) {
+        zeroize_crate::Zeroize::zeroize(&mut self.engine);
+    }
+}
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::Zeroize for Sha512 {
+    fn zeroize(&mut self) {
+        zeroize_crate::Zeroize::zeroize(&mut self.engine);
+    }
+}
+#[cfg(feature = "zeroize")]
+impl<T: Sha512tSize> zeroize_crate::Zeroize for Sha512t<T> {
+    fn zeroize(&mut self) {
+        zeroize_crate::Zeroize::zeroize(&mut self.engine);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::ZeroizeOnDrop for Sha384 {}
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::ZeroizeOnDrop for Sha512 {}
+#[cfg(feature = "zeroize")]
+impl<T: Sha512tSize> zeroize_crate::ZeroizeOnDrop for Sha512t<T> {}
+
 opaque_debug::implement!(Sha384);
 opaque_debug::implement!(Sha512);
-opaque_debug::implement!(Sha512Trunc224);
-opaque_debug::implement!(Sha512Trunc256);
 
 digest::impl_write!(Sha384);
 digest::impl_write!(Sha512);
-digest::impl_write!(Sha512Trunc224);
-digest::impl_write!(Sha512Trunc256);
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "force-soft")] {
         mod soft;
         use soft::compress;
+    } else if #[cfg(all(feature = "s390x-cpacf", target_arch = "s390x"))] {
+        mod soft;
+        mod s390x;
+        use s390x::compress;
     } else if #[cfg(all(feature = "asm", any(target_arch = "x86", target_arch = "x86_64")))] {
         fn compress(state: &mut [u64; 8], blocks: &[[u8; 128]]) {
             sha2_asm::compress512(state, blocks);
         }
+    } else if #[cfg(all(feature = "aarch64-sha512", target_arch = "aarch64"))] {
+        mod soft;
+        mod aarch64;
+        use aarch64::compress;
     } else {
         mod soft;
         use soft::compress;
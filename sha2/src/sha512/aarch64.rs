@@ -0,0 +1,72 @@
+//! SHA-512 `aarch64` backend.
+//!
+//! Uses the ARMv8.2 SHA512 instructions, which are part of the `sha3`
+//! feature group. Support is detected at compile time, or at run time where
+//! the target does not guarantee it.
+
+use crate::consts::K64;
+use core::arch::aarch64::*;
+
+cpufeatures::new!(sha3_hwcap, "sha3");
+
+pub fn compress(state: &mut [u64; 8], blocks: &[[u8; 128]]) {
+    if sha3_hwcap::get() {
+        unsafe {
+            sha512_compress(state, blocks);
+        }
+    } else {
+        super::soft::compress(state, blocks);
+    }
+}
+
+#[target_feature(enable = "sha3")]
+unsafe fn sha512_compress(state: &mut [u64; 8], blocks: &[[u8; 128]]) {
+    let mut ab = vld1q_u64(state[0..2].as_ptr());
+    let mut cd = vld1q_u64(state[2..4].as_ptr());
+    let mut ef = vld1q_u64(state[4..6].as_ptr());
+    let mut gh = vld1q_u64(state[6..8].as_ptr());
+
+    for block in blocks {
+        let ab_orig = ab;
+        let cd_orig = cd;
+        let ef_orig = ef;
+        let gh_orig = gh;
+
+        // message words are big-endian
+        let mut w = [vdupq_n_u64(0); 8];
+        for (wi, chunk) in w.iter_mut().zip(block.chunks_exact(16)) {
+            *wi = vreinterpretq_u64_u8(vrev64q_u8(vld1q_u8(chunk.as_ptr())));
+        }
+
+        // 40 pairs of rounds, the schedule runs 8 pairs ahead
+        for i in 0..40 {
+            if i >= 8 {
+                let t = vsha512su0q_u64(w[i % 8], w[(i + 1) % 8]);
+                let w9 = vextq_u64(w[(i + 4) % 8], w[(i + 5) % 8], 1);
+                w[i % 8] = vsha512su1q_u64(t, w[(i + 7) % 8], w9);
+            }
+
+            let wk = vaddq_u64(w[i % 8], vld1q_u64(K64[2 * i..].as_ptr()));
+            let sum = vaddq_u64(vextq_u64(wk, wk, 1), gh);
+            let t = vsha512hq_u64(sum, vextq_u64(ef, gh, 1), vextq_u64(cd, ef, 1));
+            let gh_next = vsha512h2q_u64(t, cd, ab);
+            let cd_next = vaddq_u64(cd, t);
+
+            // the working variables shift by two positions every pair
+            gh = ef;
+            ef = cd_next;
+            cd = ab;
+            ab = gh_next;
+        }
+
+        ab = vaddq_u64(ab, ab_orig);
+        cd = vaddq_u64(cd, cd_orig);
+        ef = vaddq_u64(ef, ef_orig);
+        gh = vaddq_u64(gh, gh_orig);
+    }
+
+    vst1q_u64(state[0..2].as_mut_ptr(), ab);
+    vst1q_u64(state[2..4].as_mut_ptr(), cd);
+    vst1q_u64(state[4..6].as_mut_ptr(), ef);
+    vst1q_u64(state[6..8].as_mut_ptr(), gh);
+}
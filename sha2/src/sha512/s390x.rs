@@ -0,0 +1,73 @@
+//! SHA-512 `s390x` backend using the CP Assist for Cryptographic Functions
+//! (CPACF) `KIMD` instruction.
+//!
+//! Enabled with the `s390x-cpacf` feature, since `asm!` on s390x requires
+//! Rust 1.84.
+
+#![allow(unsafe_code)]
+
+use core::arch::asm;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// `KIMD-Query` function code
+const KIMD_QUERY: u64 = 0;
+/// `KIMD-SHA-512` function code
+const KIMD_SHA_512: u64 = 3;
+
+const UNINIT: u8 = u8::MAX;
+static SUPPORTED: AtomicU8 = AtomicU8::new(UNINIT);
+
+/// Check if `KIMD-SHA-512` is installed. The result of the query is cached.
+fn kimd_sha512_supported() -> bool {
+    let val = SUPPORTED.load(Ordering::Relaxed);
+    if val != UNINIT {
+        return val == 1;
+    }
+
+    // The query function stores a 128-bit status word in which bit `n`
+    // (counting from the most significant bit) is set if function code `n`
+    // is installed.
+    let mut status = [0u8; 16];
+    unsafe {
+        asm!(
+            ".insn rre,0xb93e0000,%r0,%r2",
+            in("r0") KIMD_QUERY,
+            in("r1") status.as_mut_ptr(),
+            in("r2") 0u64,
+            in("r3") 0u64,
+            options(nostack),
+        );
+    }
+    let res = status[0] & (0x80 >> KIMD_SHA_512) != 0;
+    SUPPORTED.store(res as u8, Ordering::Relaxed);
+    res
+}
+
+/// Process `blocks` with `KIMD-SHA-512`.
+///
+/// The parameter block is the chaining value stored as big-endian words,
+/// which matches the native layout of `state` on `s390x`.
+unsafe fn digest_blocks(state: &mut [u64; 8], blocks: &[[u8; 128]]) {
+    asm!(
+        // `KIMD` may complete only part of the input, in which case it sets
+        // condition code 3 and has to be re-executed.
+        "0:",
+        ".insn rre,0xb93e0000,%r0,%r2",
+        "jo 0b",
+        in("r0") KIMD_SHA_512,
+        in("r1") state.as_mut_ptr(),
+        inout("r2") blocks.as_ptr() => _,
+        inout("r3") 128 * blocks.len() => _,
+        options(nostack),
+    );
+}
+
+pub fn compress(state: &mut [u64; 8], blocks: &[[u8; 128]]) {
+    if kimd_sha512_supported() {
+        unsafe {
+            digest_blocks(state, blocks);
+        }
+    } else {
+        super::soft::compress(state, blocks);
+    }
+}
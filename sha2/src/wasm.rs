@@ -0,0 +1,40 @@
+//! `wasm-bindgen` bindings for calling SHA-256 from JavaScript.
+use crate::Sha256;
+use alloc::boxed::Box;
+use digest::{FixedOutput, Update};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// SHA-256 hasher exported to JavaScript.
+///
+/// Byte slices are exchanged with JavaScript as `Uint8Array`s:
+///
+/// ```js
+/// const hasher = new JsSha256();
+/// hasher.update(new TextEncoder().encode("hello world"));
+/// const digest = hasher.finalize(); // Uint8Array(32)
+/// ```
+#[wasm_bindgen]
+#[derive(Clone, Default)]
+pub struct JsSha256 {
+    inner: Sha256,
+}
+
+#[wasm_bindgen]
+impl JsSha256 {
+    /// Create a new hasher.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> JsSha256 {
+        JsSha256::default()
+    }
+
+    /// Process input data.
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    /// Consume the hasher and return the 32-byte digest.
+    pub fn finalize(self) -> Box<[u8]> {
+        let res: [u8; 32] = self.inner.finalize_fixed().into();
+        Box::new(res)
+    }
+}
@@ -0,0 +1,35 @@
+#![cfg(all(feature = "compress", target_feature = "avx2"))]
+
+use sha2::digest::generic_array::GenericArray;
+use sha2::{compress256, compress256x8};
+
+#[test]
+fn x8_matches_compress256() {
+    let mut x = 0x9e37_79b9_7f4a_7c15u64;
+    let mut next = move || {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        x
+    };
+
+    for _ in 0..16 {
+        let mut states = [[0u32; 8]; 8];
+        let mut blocks = [[0u8; 64]; 8];
+        for (s, b) in states.iter_mut().zip(blocks.iter_mut()) {
+            for w in s.iter_mut() {
+                *w = next() as u32;
+            }
+            for c in b.chunks_exact_mut(8) {
+                c.copy_from_slice(&next().to_le_bytes());
+            }
+        }
+        let mut expected = states;
+        for (s, b) in expected.iter_mut().zip(blocks.iter()) {
+            compress256(s, &[*GenericArray::from_slice(b)]);
+        }
+
+        compress256x8(&mut states, &blocks);
+        assert_eq!(states, expected);
+    }
+}
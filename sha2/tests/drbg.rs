@@ -0,0 +1,118 @@
+//! `Hash_DRBG` tests following the CAVS procedure: instantiate, optionally
+//! reseed, then generate twice and check the output of the second call.
+//!
+//! `cavs_no_reseed_count0` is taken from the NIST CAVS `Hash_DRBG.rsp` file
+//! (no reseed, no prediction resistance). Expected values for the other
+//! tests were produced with OpenSSL's independent `HASH-DRBG` implementation
+//! (SHA-256, no prediction resistance).
+#![cfg(feature = "drbg")]
+
+use hex_literal::hex;
+use sha2::drbg::{Error, HashDrbgSha256};
+
+const ENTROPY: [u8; 32] = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+const NONCE: [u8; 16] = hex!("202122232425262728292a2b2c2d2e2f");
+const PERS: [u8; 32] = hex!("808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f");
+const ENTROPY_RESEED: [u8; 32] =
+    hex!("404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f");
+const ADD_RESEED: [u8; 32] =
+    hex!("c0c1c2c3c4c5c6c7c8c9cacbcccdcecfd0d1d2d3d4d5d6d7d8d9dadbdcdddedf");
+const ADD1: [u8; 32] = hex!("606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f");
+const ADD2: [u8; 32] = hex!("a0a1a2a3a4a5a6a7a8a9aaabacadaeafb0b1b2b3b4b5b6b7b8b9babbbcbdbebf");
+
+fn run(pers: &[u8], reseed: Option<(&[u8], &[u8])>, add1: &[u8], add2: &[u8]) -> [u8; 128] {
+    let mut drbg = HashDrbgSha256::new(&ENTROPY, &NONCE, pers);
+    if let Some((entropy, add)) = reseed {
+        drbg.reseed(entropy, add);
+    }
+    let mut out = [0u8; 128];
+    drbg.generate(&mut out, add1).unwrap();
+    drbg.generate(&mut out, add2).unwrap();
+    out
+}
+
+/// `[SHA-256]`, `[PredictionResistance = False]`, no personalization string
+/// or additional input, `COUNT = 0`
+#[test]
+fn cavs_no_reseed_count0() {
+    let entropy = hex!("a65ad0f345db4e0effe875c3a2e71f42c7129d620ff5c119a9ef55f05185e0fb");
+    let nonce = hex!("8581f9317517276e06e9607ddbcbcc2e");
+    let expected = hex!(
+        "
+        d3e160c35b99f340b2628264d1751060e0045da383ff57a57d73a673d2b8d80d
+        aaf6a6c35a91bb4579d73fd0c8fed111b0391306828adfed528f018121b3febd
+        c343e797b87dbb63db1333ded9d1ece177cfa6b71fe8ab1da46624ed6415e51c
+        cde2c7ca86e283990eeaeb91120415528b2295910281b02dd431f4c9f70427df
+    "
+    );
+
+    let mut drbg = HashDrbgSha256::new(&entropy, &nonce, &[]);
+    let mut out = [0u8; 128];
+    drbg.generate(&mut out, &[]).unwrap();
+    drbg.generate(&mut out, &[]).unwrap();
+    assert_eq!(out[..], expected[..]);
+}
+
+#[test]
+fn no_reseed() {
+    let expected = hex!(
+        "
+        27a3342a35d4bbb8e1dcd8ec0fc1a0d1a25cf906f0445d3b974dbddf4a3ba34e
+        073302ab655234a703381741af7b15191a96164cc087ad1ef8360960b94dfba7
+        451ade5f57ff6f74afeb737f8f539304c1ce58a98f3ad4b852b4cec0aceffb2b
+        d5f153f9395b593dc8d890c6d9cc570107b36cfd4b7081c42102efd89752a1de
+    "
+    );
+    assert_eq!(run(&[], None, &[], &[])[..], expected[..]);
+}
+
+#[test]
+fn personalization_and_additional_input() {
+    let expected = hex!(
+        "
+        ff7f2bae5a0ce4fdd9f4b7d22961f0a7718486c16d2a7dd94e7f7437e645cf68
+        03745c8e79fa318de54939868b92042085cc276bf25deb74a190fad93e7ccf42
+        6859d132f56b40fefdd5cc63fba2b7cec3b191ffd828e6d6eb07b6fe2b8951f9
+        63b0437f95bb83c60fa57832194a53d18a90fe737333a2b0458507dca71b100a
+    "
+    );
+    assert_eq!(run(&PERS, None, &ADD1, &ADD2)[..], expected[..]);
+}
+
+#[test]
+fn reseed() {
+    let expected = hex!(
+        "
+        e59479ead866deeccf1c4aa0d442bb58e1689a29a81c83ecad85421fe9661262
+        a58ff467d328d8df5f641a6c1775d2ce67810b802d9777a95d2316fc3d2550b4
+        a8941cc241a062980c3d09f66a871b3d9840f43d23d521aa7b71e29b641dab46
+        84fdd7c0993c1a961c8a9a56d6be9f692f4e6f17a9548ce799110745a66fa21c
+    "
+    );
+    assert_eq!(
+        run(&[], Some((&ENTROPY_RESEED, &[])), &[], &[])[..],
+        expected[..]
+    );
+}
+
+#[test]
+fn reseed_with_all_inputs() {
+    let expected = hex!(
+        "
+        1a0b4655e91981e1ca07bf808b9963778bc135667d261a92530140215f2048d2
+        628b4477ac260feec4ea3b3af834efa8ff22d3d35cc25b0d154434f83883a4de
+        5d754b0ce74c6858e78d87e3544ce835200f9b09c81147f64e5fe31e9755b0b5
+        be9779966ab477f31abfa1329d030996fa7ef67bb2ad070311e921fa11fbfa59
+    "
+    );
+    let out = run(&PERS, Some((&ENTROPY_RESEED, &ADD_RESEED)), &ADD1, &ADD2);
+    assert_eq!(out[..], expected[..]);
+}
+
+#[test]
+fn request_too_large() {
+    let mut drbg = HashDrbgSha256::new(&ENTROPY, &NONCE, &[]);
+    let mut out = vec![0u8; (1 << 16) + 1];
+    assert_eq!(drbg.generate(&mut out, &[]), Err(Error::RequestTooLarge));
+    assert_eq!(drbg.generate(&mut out[1..], &[]), Ok(()));
+}
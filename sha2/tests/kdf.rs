@@ -0,0 +1,67 @@
+//! Expected values were cross-checked against OpenSSL's `SSKDF` in HMAC mode
+//! with the default all-zero salt.
+#![cfg(feature = "kdf")]
+
+use hex_literal::hex;
+use sha2::kdf::{hmac_kdf_sha256, hmac_kdf_sha512};
+
+const Z: [u8; 32] = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+
+#[test]
+fn hmac_sha256_one_block() {
+    assert_eq!(
+        hmac_kdf_sha256(&Z, b"label", b"context", 32)[..],
+        hex!("5a93f794be2862713342395480e13bfdd63992e75f8bd4f1bffccd477118594f")[..]
+    );
+}
+
+#[test]
+fn hmac_sha256_multi_block() {
+    assert_eq!(
+        hmac_kdf_sha256(&Z, b"label", b"context", 80)[..],
+        hex!(
+            "
+            9c1de2bb01b6108ddb25179c40d526ddfbd9a26a3970a5d0f75a31c240528505
+            9d79cac8eef84bfb86898bc0567d2087c8a9eee0c4739a34834f828527e601c5
+            5897d93184cf91a794df3a9a7a7bdf34
+        "
+        )[..]
+    );
+}
+
+#[test]
+fn hmac_sha512_one_block() {
+    assert_eq!(
+        hmac_kdf_sha512(&Z, b"label", b"context", 64)[..],
+        hex!(
+            "
+            137a6459d264a45cd24281b268faf1db7031d2095fda7883ca2b287b975a0af3
+            05991c406b39103364c732db3bbd0fc375d1e7b94c460859a786b8925fedf8d5
+        "
+        )[..]
+    );
+}
+
+#[test]
+fn hmac_sha512_multi_block() {
+    assert_eq!(
+        hmac_kdf_sha512(&Z, b"label", b"context", 150)[..],
+        hex!(
+            "
+            3ce9a9a09608fe95bae7bde920da7d54b6864dc8cd048befcd02be1af6ea06ed
+            e5b4ab6af68be300636778e17f5151bdac4d3709698eabdc338c9886e217cecc
+            c6f7c40e35487ab4d1a216a7e387bcd19cc25589f55f6d6222e4f3666f8a76e1
+            11ec660ef39c32f9dd3395e19200bc4fd2d6da818a5e0ab9bf950efddbdc22a0
+            d945a8235e63755ce3858ed7b8b623ef56b3d7e8930d
+        "
+        )[..]
+    );
+}
+
+#[test]
+fn output_length_is_bound_to_key() {
+    // `L` is part of FixedInfo, so a shorter request is not a prefix
+    let long = hmac_kdf_sha256(&Z, b"label", b"context", 64);
+    let short = hmac_kdf_sha256(&Z, b"label", b"context", 32);
+    assert_ne!(long[..32], short[..]);
+}
@@ -29,3 +29,72 @@ fn sha512_1million_a() {
     let output = include_bytes!("data/sha512_one_million_a.bin");
     one_million_a::<sha2::Sha512>(output);
 }
+
+#[test]
+fn sha2_alg_names() {
+    assert_eq!(sha2::Sha224::ALG_NAME, "SHA-224");
+    assert_eq!(sha2::Sha256::ALG_NAME, "SHA-256");
+    assert_eq!(sha2::Sha384::ALG_NAME, "SHA-384");
+    assert_eq!(sha2::Sha512::ALG_NAME, "SHA-512");
+    assert_eq!(sha2::Sha512Trunc224::ALG_NAME, "SHA-512/224");
+    assert_eq!(sha2::Sha512Trunc256::ALG_NAME, "SHA-512/256");
+}
+
+#[test]
+fn sha2_display() {
+    assert_eq!(format!("{}", sha2::Sha224::default()), "SHA-224");
+    assert_eq!(format!("{}", sha2::Sha256::default()), "SHA-256");
+    assert_eq!(format!("{}", sha2::Sha384::default()), "SHA-384");
+    assert_eq!(format!("{}", sha2::Sha512::default()), "SHA-512");
+    assert_eq!(
+        format!("{}", sha2::Sha512Trunc224::default()),
+        "SHA-512/224"
+    );
+    assert_eq!(
+        format!("{}", sha2::Sha512Trunc256::default()),
+        "SHA-512/256"
+    );
+}
+
+#[test]
+fn sha2_hex_output() {
+    use sha2::{Digest, Sha256, Sha512};
+
+    assert_eq!(
+        format!("{:x}", Sha256::digest(b"abc")),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert_eq!(
+        format!("{:X}", Sha256::digest(b"abc")),
+        "BA7816BF8F01CFEA414140DE5DAE2223B00361A396177A9CB410FF61F20015AD"
+    );
+    assert_eq!(
+        format!("{:x}", Sha512::digest(b"abc")),
+        "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+         2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+    );
+}
+
+#[test]
+fn sha512t_generated_iv() {
+    use hex_literal::hex;
+    use sha2::digest::consts::{U1, U20, U63};
+    use sha2::{Digest, Sha512t};
+
+    // SHA-512/t of "abc" for initial hash values not precomputed by the crate
+    assert_eq!(Sha512t::<U1>::digest(b"abc")[..], hex!("c5")[..]);
+    assert_eq!(
+        Sha512t::<U20>::digest(b"abc")[..],
+        hex!("0a74fe1b43eecbea62182658da8a68b8acef25bf")[..]
+    );
+    assert_eq!(
+        Sha512t::<U63>::digest(b"abc")[..],
+        hex!(
+            "
+            8c43e4bf1cad93067af1ad632ba38bba0b5673bf0129f01a469224c2d981b8ec
+            aa301facf8e392f97efc5997885a1c90cefba70d81892f40267df4fd6fef9a
+        "
+        )[..]
+    );
+    assert_eq!(format!("{}", Sha512t::<U20>::default()), "SHA-512/160");
+}
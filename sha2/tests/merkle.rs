@@ -0,0 +1,77 @@
+//! Test vectors from the Certificate Transparency reference implementation
+#![cfg(feature = "std")]
+
+use hex_literal::hex;
+use sha2::{merkle::MerkleTree, Sha256};
+
+const LEAVES: [&[u8]; 8] = [
+    &[],
+    &hex!("00"),
+    &hex!("10"),
+    &hex!("2021"),
+    &hex!("3031"),
+    &hex!("40414243"),
+    &hex!("5051525354555657"),
+    &hex!("606162636465666768696a6b6c6d6e6f"),
+];
+
+#[test]
+fn merkle_roots() {
+    let roots = [
+        hex!("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+        hex!("6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d"),
+        hex!("fac54203e7cc696cf0dfcb42c92a1d9dbaf70ad9e621f4bd8d98662f00e3c125"),
+        hex!("aeb6bcfe274b70a14fb067a5e5578264db0fa9b51af5e0ba159158f329e06e77"),
+        hex!("d37ee418976dd95753c1c73862b9398fa2a2cf9b4ff0fdfe8b30cd95209614b7"),
+        hex!("4e3bbb1f7b478dcfe71fb631631519a3bca12c9aefca1612bfce4c13a86264d4"),
+        hex!("76e67dadbcdf1e10e1b74ddc608abd2f98dfb16fbce75277b5232a127f2087ef"),
+        hex!("ddb89be403809e325750d3d263cd78929c2942b7942a34b77e122c9594a74c8c"),
+        hex!("5dc9da79a70659a9ad559cb701ded9a2ab9d823aad2f4960cfe370eff4604328"),
+    ];
+    for (n, root) in roots.iter().enumerate() {
+        let tree = MerkleTree::<Sha256>::new(&LEAVES[..n]);
+        assert_eq!(tree.len(), n);
+        assert_eq!(tree.root()[..], root[..]);
+    }
+}
+
+#[test]
+fn merkle_proofs() {
+    let check = |n: usize, index: usize, expected: &[[u8; 32]]| {
+        let tree = MerkleTree::<Sha256>::new(&LEAVES[..n]);
+        let proof = tree.proof(index);
+        assert_eq!(proof.len(), expected.len());
+        for (p, e) in proof.iter().zip(expected) {
+            assert_eq!(p[..], e[..]);
+        }
+    };
+
+    check(1, 0, &[]);
+    check(
+        8,
+        0,
+        &[
+            hex!("96a296d224f285c67bee93c30f8a309157f0daa35dc5b87e410b78630a09cfc7"),
+            hex!("5f083f0a1a33ca076a95279832580db3e0ef4584bdff1f54c8a360f50de3031e"),
+            hex!("6b47aaf29ee3c2af9af889bc1fb9254dabd31177f16232dd6aab035ca39bf6e4"),
+        ],
+    );
+    check(
+        8,
+        5,
+        &[
+            hex!("bc1a0643b12e4d2d7c77918f44e0f4f79a838b6cf9ec5b5c283e1f4d88599e6b"),
+            hex!("ca854ea128ed050b41b35ffc1b87b8eb2bde461e9e3b5596ece6b9d5975a0ae0"),
+            hex!("d37ee418976dd95753c1c73862b9398fa2a2cf9b4ff0fdfe8b30cd95209614b7"),
+        ],
+    );
+    check(
+        5,
+        3,
+        &[
+            hex!("0298d122906dcfc10892cb53a73992fc5b9f493ea4c9badb27b791b4127a7fe7"),
+            hex!("fac54203e7cc696cf0dfcb42c92a1d9dbaf70ad9e621f4bd8d98662f00e3c125"),
+            hex!("bc1a0643b12e4d2d7c77918f44e0f4f79a838b6cf9ec5b5c283e1f4d88599e6b"),
+        ],
+    );
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "mgf")]
+
+use hex_literal::hex;
+use sha2::mgf::{mgf1_sha256, MaskTooLong};
+
+#[test]
+fn mgf1_sha256_short() {
+    let mut out = [0u8; 50];
+    mgf1_sha256(b"bar", 50, &mut out).unwrap();
+    assert_eq!(
+        out[..],
+        hex!(
+            "
+        382576a7841021cc28fc4c0948753fb8312090cea942ea4c4e735d10dc724b15
+        5f9f6069f289d61daca0cb814502ef04eae1
+    "
+        )[..]
+    );
+}
+
+#[test]
+fn mgf1_sha256_256_bytes() {
+    let seed = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+    let mut out = [0u8; 256];
+    mgf1_sha256(&seed, 256, &mut out).unwrap();
+    assert_eq!(
+        out[..],
+        hex!(
+            "
+        70f4003d52b6eb03da852e93256b5986b5d4883098bb7973bc5318cc66637a84
+        04a6950a06d3e3308ad7d3606ef810eb124e3943404ca746a12c51c7bf776839
+        0f8d842ac9cb62349779a7537a78327d545aaeb33b2d42c7d1dc3680a4b23628
+        627e9db8ad47bfe76dbe653d03d2c0a35999ed28a5023924150d72508668d244
+        2f95db4b0a7de880458b19966f21918f9644106e8d2eb4aff23845703cd21492
+        0c1c9b0bc4358902b823c7675320d59ded234f308b9dfa5f8d844d1978330c66
+        9fa873071768cf46b419ad2867bb6312b759007caf966dff1f1e995022996027
+        25fbe9f84015dbf3deed592b4af13de19dcafdaf729d58add39b4d6816a69780
+    "
+        )[..]
+    );
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn mgf1_sha256_mask_too_long() {
+    // the length is checked before `output` is touched
+    assert_eq!(
+        mgf1_sha256(b"bar", (1 << 32) * 32 + 1, &mut []),
+        Err(MaskTooLong)
+    );
+}
@@ -0,0 +1,58 @@
+//! NIST CAVP Monte Carlo tests for SHA-256 and SHA-512.
+//!
+//! Implements the SHAVS pseudorandomly generated messages test (§6.4): each
+//! of the 100 checkpoints in `SHA256Monte.rsp` and `SHA512Monte.rsp` is the
+//! result of 1000 further chained digests, and every checkpoint is compared
+//! so that a divergence is reported at the first one that differs.
+//!
+//! The seed and the `MD` checkpoints of those files are stored in
+//! `tests/data/sha256_monte.blb` and `tests/data/sha512_monte.blb`, the seed
+//! first and then the checkpoints in `COUNT` order. Run the tests with:
+//!
+//! ```text
+//! cargo test --features nist-vectors --test monte_carlo
+//! ```
+#![cfg(feature = "nist-vectors")]
+
+use digest::dev::blobby::BlobIterator;
+use sha2::{Digest, Sha256, Sha512};
+
+/// Run the SHAVS Monte Carlo procedure for `D` against `data`.
+///
+/// ```text
+/// for j in 0..100:
+///     MD[0] = MD[1] = MD[2] = Seed
+///     for i in 3..1003:
+///         MD[i] = H(MD[i-3] || MD[i-2] || MD[i-1])
+///     Seed = MD[1002], which must equal checkpoint j
+/// ```
+fn monte_carlo<D: Digest>(name: &str, data: &[u8]) {
+    let mut blobs = BlobIterator::new(data).unwrap();
+    let mut seed = blobs.next().unwrap().to_vec();
+    let checkpoints: Vec<&[u8]> = blobs.collect();
+    assert_eq!(checkpoints.len(), 100, "{}: expected 100 checkpoints", name);
+
+    for (j, expected) in checkpoints.iter().enumerate() {
+        let mut md = [seed.clone(), seed.clone(), seed];
+        for _ in 0..1000 {
+            let mut h = D::new();
+            for m in md.iter() {
+                h.update(m);
+            }
+            let next = h.finalize().to_vec();
+            md = [md[1].clone(), md[2].clone(), next];
+        }
+        assert_eq!(&md[2][..], *expected, "{}: COUNT = {}", name, j);
+        seed = md[2].clone();
+    }
+}
+
+#[test]
+fn sha256_monte_carlo() {
+    monte_carlo::<Sha256>("SHA256Monte", include_bytes!("data/sha256_monte.blb"));
+}
+
+#[test]
+fn sha512_monte_carlo() {
+    monte_carlo::<Sha512>("SHA512Monte", include_bytes!("data/sha512_monte.blb"));
+}
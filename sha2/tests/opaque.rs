@@ -0,0 +1,25 @@
+#![cfg(feature = "opaque-digest")]
+
+use hex_literal::hex;
+use sha2::{Digest, OpaqueDigest, Sha256, Sha512};
+
+#[test]
+fn opaque_digest_formatting() {
+    let digest = OpaqueDigest::<Sha256>::new(Sha256::digest(b"hello world"));
+    assert_eq!(format!("{}", digest), "[redacted]");
+    assert_eq!(format!("{:?}", digest), "OpaqueDigest(SHA-256)");
+
+    let digest = OpaqueDigest::<Sha512>::new(Sha512::digest(b"hello world"));
+    assert_eq!(format!("{:?}", digest), "OpaqueDigest(SHA-512)");
+}
+
+#[test]
+fn opaque_digest_access_and_eq() {
+    let digest = OpaqueDigest::<Sha256>::from(Sha256::digest(b"hello world"));
+    let expected = hex!("b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9");
+    assert_eq!(digest.reveal()[..], expected[..]);
+    assert_eq!(digest.as_ref(), &expected[..]);
+
+    assert_eq!(digest, digest.clone());
+    assert_ne!(digest, OpaqueDigest::new(Sha256::digest(b"hello")));
+}
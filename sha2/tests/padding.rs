@@ -0,0 +1,73 @@
+//! Consistency checks around the padding boundaries of SHA-256 and SHA-512
+use sha2::{Digest, Sha256, Sha512};
+
+fn check<D: Digest>(data: &[u8], chunk: usize) {
+    let expected = D::digest(data);
+
+    let mut h = D::new();
+    h.update(data);
+    assert_eq!(h.finalize(), expected);
+
+    let mut h = D::new();
+    for c in data.chunks(chunk) {
+        h.update(c);
+    }
+    assert_eq!(h.finalize_reset(), expected);
+
+    // after a reset the hasher must behave like a fresh one
+    h.update(data);
+    assert_eq!(h.finalize(), expected);
+}
+
+/// Random message lengths up to `max_len` and chunk sizes up to `max_chunk`
+///
+/// A fixed xorshift sequence stands in for proptest: proptest needs a far
+/// newer compiler than the MSRV, and Cargo can not limit a dev-dependency to
+/// some toolchains, so it would break `cargo test` on 1.41. A fixed seed
+/// also makes every failure reproducible without a regressions file.
+fn check_random<D: Digest>(max_len: usize, max_chunk: usize) {
+    let mut x = 0x9E37_79B9u32;
+    let mut next = || {
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        x
+    };
+    let mut data = vec![0u8; max_len];
+    for _ in 0..1000 {
+        let len = next() as usize % max_len;
+        let chunk = 1 + next() as usize % max_chunk;
+        data.iter_mut().for_each(|b| *b = next() as u8);
+        check::<D>(&data[..len], chunk);
+    }
+}
+
+#[test]
+fn sha256_padding() {
+    check_random::<Sha256>(300, 69);
+}
+
+#[test]
+fn sha512_padding() {
+    check_random::<Sha512>(400, 139);
+}
+
+#[test]
+fn sha256_boundaries() {
+    let data = [0xa5u8; 128];
+    for &n in [54, 55, 56, 57, 63, 64, 65, 119, 120, 128].iter() {
+        for chunk in 1..=n {
+            check::<Sha256>(&data[..n], chunk);
+        }
+    }
+}
+
+#[test]
+fn sha512_boundaries() {
+    let data = [0xa5u8; 256];
+    for &n in [110, 111, 112, 113, 127, 128, 129, 239, 240, 256].iter() {
+        for chunk in 1..=n {
+            check::<Sha512>(&data[..n], chunk);
+        }
+    }
+}
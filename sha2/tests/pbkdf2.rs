@@ -0,0 +1,27 @@
+//! PBKDF2-HMAC-SHA256 test vectors from RFC 7914 §11
+#![cfg(feature = "pbkdf2")]
+
+use hex_literal::hex;
+use sha2::pbkdf2::pbkdf2_sha256;
+
+#[test]
+fn pbkdf2_sha256_rfc7914() {
+    let mut out = [0u8; 64];
+    pbkdf2_sha256(b"passwd", b"salt", 1, &mut out);
+    assert_eq!(
+        out[..],
+        hex!(
+            "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc
+            49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783"
+        )[..]
+    );
+
+    pbkdf2_sha256(b"Password", b"NaCl", 80000, &mut out);
+    assert_eq!(
+        out[..],
+        hex!(
+            "4ddcd8f60b98be21830cee5ef22701f9641a4418d04c0414aeff08876b34ab56
+            a1d425a1225833549adb841b51c9b3176a272bdebba1d078478f62b397f33c8d"
+        )[..]
+    );
+}
@@ -0,0 +1,77 @@
+use sha2::{
+    Digest, Sha224, Sha256, Sha384, Sha512, Sha512Trunc224, Sha512Trunc256, SHA256_SNAPSHOT_LEN,
+    SHA512_SNAPSHOT_LEN,
+};
+
+#[test]
+fn sha256_raw_state_roundtrip() {
+    let hasher = Sha256::from_raw_state(Sha256::default().get_state(), 0).unwrap();
+    assert_eq!(hasher.finalize(), Sha256::digest(b""));
+
+    let data = [0x42u8; 200];
+    let mut hasher = Sha256::new();
+    hasher.update(&data[..128]);
+    let mut resumed = Sha256::from_raw_state(hasher.get_state(), 2).unwrap();
+    resumed.update(&data[128..]);
+    assert_eq!(resumed.finalize(), Sha256::digest(&data));
+
+    assert!(Sha256::from_raw_state([0; 8], !0u64 / 512 + 1).is_none());
+}
+
+#[test]
+fn sha512_raw_state_roundtrip() {
+    let hasher = Sha512::from_raw_state(Sha512::default().get_state(), 0).unwrap();
+    assert_eq!(hasher.finalize(), Sha512::digest(b""));
+
+    let data = [0x42u8; 300];
+    let mut hasher = Sha512::new();
+    hasher.update(&data[..256]);
+    let mut resumed = Sha512::from_raw_state(hasher.get_state(), 2).unwrap();
+    resumed.update(&data[256..]);
+    assert_eq!(resumed.finalize(), Sha512::digest(&data));
+
+    assert!(Sha512::from_raw_state([0; 8], !0u128 / 1024 + 1).is_none());
+}
+
+macro_rules! serialize_test {
+    ($name:ident, $hasher:ty, $len:expr, $block:expr) => {
+        #[test]
+        fn $name() {
+            let data: Vec<u8> = (0..300u32).map(|i| i as u8).collect();
+            // mid-block and 64- and 128-byte boundaries
+            for &split in &[31, 64, 128] {
+                let mut hasher = <$hasher>::new();
+                hasher.update(&data[..split]);
+                let snapshot = hasher.serialize_state();
+                assert_eq!(snapshot.len(), $len);
+
+                let mut resumed = <$hasher>::deserialize_state(&snapshot).unwrap();
+                resumed.update(&data[split..]);
+                assert_eq!(resumed.finalize(), <$hasher>::digest(&data));
+            }
+
+            let snapshot = <$hasher>::new().serialize_state();
+            assert!(<$hasher>::deserialize_state(&snapshot[1..]).is_none());
+            let mut bad_pos = snapshot;
+            bad_pos[$len - $block - 1] = 0xff;
+            assert!(<$hasher>::deserialize_state(&bad_pos).is_none());
+        }
+    };
+}
+
+serialize_test!(sha224_serialize_state, Sha224, SHA256_SNAPSHOT_LEN, 64);
+serialize_test!(sha256_serialize_state, Sha256, SHA256_SNAPSHOT_LEN, 64);
+serialize_test!(sha384_serialize_state, Sha384, SHA512_SNAPSHOT_LEN, 128);
+serialize_test!(sha512_serialize_state, Sha512, SHA512_SNAPSHOT_LEN, 128);
+serialize_test!(
+    sha512_224_serialize_state,
+    Sha512Trunc224,
+    SHA512_SNAPSHOT_LEN,
+    128
+);
+serialize_test!(
+    sha512_256_serialize_state,
+    Sha512Trunc256,
+    SHA512_SNAPSHOT_LEN,
+    128
+);
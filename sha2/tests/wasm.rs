@@ -0,0 +1,16 @@
+#![cfg(feature = "wasm-bindgen")]
+
+use hex_literal::hex;
+use sha2::JsSha256;
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn js_sha256_hello_world() {
+    let mut hasher = JsSha256::new();
+    hasher.update(b"hello ");
+    hasher.update(b"world");
+    assert_eq!(
+        hasher.finalize()[..],
+        hex!("b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9")[..],
+    );
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "zeroize")]
+
+use sha2::zeroize::Zeroize;
+use sha2::{Digest, Sha256, Sha512, SHA256_SNAPSHOT_LEN, SHA512_SNAPSHOT_LEN};
+
+/// Covers a whole block and part of the next one for both hashers, so that
+/// the state and the buffer hold input.
+const INPUT: [u8; 200] = [0x5a; 200];
+
+#[test]
+fn sha256_zeroize() {
+    let mut hasher = Sha256::new();
+    hasher.update(&INPUT[..]);
+    hasher.zeroize();
+    assert_eq!(hasher.serialize_state()[..], [0u8; SHA256_SNAPSHOT_LEN][..]);
+}
+
+#[test]
+fn sha512_zeroize() {
+    let mut hasher = Sha512::new();
+    hasher.update(&INPUT[..]);
+    hasher.zeroize();
+    assert_eq!(hasher.serialize_state()[..], [0u8; SHA512_SNAPSHOT_LEN][..]);
+}
@@ -0,0 +1,110 @@
+//! Keccak-f[1600] permutation using the ARMv8.2 SHA3 instructions.
+//!
+//! Every lane is kept in the low half of its own vector register, as in the
+//! Linux kernel's `sha3-ce` implementation: `eor3` folds three lanes into a
+//! θ column parity at once, `rax1` computes the θ effect, `xar` applies it
+//! together with the ρ rotation and `bcax` computes χ in one instruction.
+//!
+//! Only compiled when the `sha3` target feature is enabled at build time
+//! (e.g. with `RUSTFLAGS="-C target-feature=+sha3"`), which requires
+//! Rust 1.79+.
+#![allow(unsafe_code)]
+
+use core::arch::aarch64::*;
+
+const PLEN: usize = 25;
+
+const RC: [u64; 24] = [
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_8082,
+    0x8000_0000_0000_808A,
+    0x8000_0000_8000_8000,
+    0x0000_0000_0000_808B,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8009,
+    0x0000_0000_0000_008A,
+    0x0000_0000_0000_0088,
+    0x0000_0000_8000_8009,
+    0x0000_0000_8000_000A,
+    0x0000_0000_8000_808B,
+    0x8000_0000_0000_008B,
+    0x8000_0000_0000_8089,
+    0x8000_0000_0000_8003,
+    0x8000_0000_0000_8002,
+    0x8000_0000_0000_0080,
+    0x0000_0000_0000_800A,
+    0x8000_0000_8000_000A,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8080,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8008,
+];
+
+pub(crate) fn f1600(state: &mut [u64; PLEN]) {
+    // SAFETY: the `sha3` target feature is enabled at compile time
+    unsafe { keccak_f1600_sha3(state) }
+}
+
+#[inline(always)]
+unsafe fn keccak_f1600_sha3(state: &mut [u64; PLEN]) {
+    let mut a = [vdupq_n_u64(0); PLEN];
+    for (a, s) in a.iter_mut().zip(state.iter()) {
+        *a = vdupq_n_u64(*s);
+    }
+
+    for &rc in RC.iter() {
+        // θ
+        let mut c = [vdupq_n_u64(0); 5];
+        for (x, c) in c.iter_mut().enumerate() {
+            let t = veor3q_u64(a[x], a[x + 5], a[x + 10]);
+            *c = veor3q_u64(t, a[x + 15], a[x + 20]);
+        }
+        let mut d = [vdupq_n_u64(0); 5];
+        for (x, d) in d.iter_mut().enumerate() {
+            *d = vrax1q_u64(c[(x + 4) % 5], c[(x + 1) % 5]);
+        }
+
+        // θ applied together with ρ (as a right rotation) and π
+        let mut b = [vdupq_n_u64(0); PLEN];
+        b[0] = vxarq_u64::<0>(a[0], d[0]);
+        b[10] = vxarq_u64::<63>(a[1], d[1]);
+        b[20] = vxarq_u64::<2>(a[2], d[2]);
+        b[5] = vxarq_u64::<36>(a[3], d[3]);
+        b[15] = vxarq_u64::<37>(a[4], d[4]);
+        b[16] = vxarq_u64::<28>(a[5], d[0]);
+        b[1] = vxarq_u64::<20>(a[6], d[1]);
+        b[11] = vxarq_u64::<58>(a[7], d[2]);
+        b[21] = vxarq_u64::<9>(a[8], d[3]);
+        b[6] = vxarq_u64::<44>(a[9], d[4]);
+        b[7] = vxarq_u64::<61>(a[10], d[0]);
+        b[17] = vxarq_u64::<54>(a[11], d[1]);
+        b[2] = vxarq_u64::<21>(a[12], d[2]);
+        b[12] = vxarq_u64::<39>(a[13], d[3]);
+        b[22] = vxarq_u64::<25>(a[14], d[4]);
+        b[23] = vxarq_u64::<23>(a[15], d[0]);
+        b[8] = vxarq_u64::<19>(a[16], d[1]);
+        b[18] = vxarq_u64::<49>(a[17], d[2]);
+        b[3] = vxarq_u64::<43>(a[18], d[3]);
+        b[13] = vxarq_u64::<56>(a[19], d[4]);
+        b[14] = vxarq_u64::<46>(a[20], d[0]);
+        b[24] = vxarq_u64::<62>(a[21], d[1]);
+        b[9] = vxarq_u64::<3>(a[22], d[2]);
+        b[19] = vxarq_u64::<8>(a[23], d[3]);
+        b[4] = vxarq_u64::<50>(a[24], d[4]);
+
+        // χ
+        for y in (0..PLEN).step_by(5) {
+            for x in 0..5 {
+                a[y + x] = vbcaxq_u64(b[y + x], b[y + (x + 2) % 5], b[y + (x + 1) % 5]);
+            }
+        }
+
+        // ι
+        a[0] = veorq_u64(a[0], vdupq_n_u64(rc));
+    }
+
+    for (s, a) in state.iter_mut().zip(a.iter()) {
+        *s = vgetq_lane_u64::<0>(*a);
+    }
+}
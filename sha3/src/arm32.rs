@@ -0,0 +1,246 @@
+//! Keccak-f[1600] permutation for 32-bit ARM using bit interleaving.
+//!
+//! Each 64-bit lane is split into a word holding its even bits and a word
+//! holding its odd bits. A 64-bit rotation then becomes two independent
+//! 32-bit rotations, which Cortex-M cores execute for free as part of the
+//! barrel-shifted operand, instead of a sequence of shifts and ORs across
+//! a register pair. The state is converted on entry and exit, so callers
+//! keep using the regular lane layout.
+//!
+//! Enabled with the `arm32` feature; otherwise the `keccak` crate is used.
+const PLEN: usize = 25;
+
+/// Round constants as `(even, odd)` bit-interleaved words.
+const RC: [(u32, u32); 24] = [
+    (0x0000_0001, 0x0000_0000),
+    (0x0000_0000, 0x0000_0089),
+    (0x0000_0000, 0x8000_008B),
+    (0x0000_0000, 0x8000_8080),
+    (0x0000_0001, 0x0000_008B),
+    (0x0000_0001, 0x0000_8000),
+    (0x0000_0001, 0x8000_8088),
+    (0x0000_0001, 0x8000_0082),
+    (0x0000_0000, 0x0000_000B),
+    (0x0000_0000, 0x0000_000A),
+    (0x0000_0001, 0x0000_8082),
+    (0x0000_0000, 0x0000_8003),
+    (0x0000_0001, 0x0000_808B),
+    (0x0000_0001, 0x8000_000B),
+    (0x0000_0001, 0x8000_008A),
+    (0x0000_0001, 0x8000_0081),
+    (0x0000_0000, 0x8000_0081),
+    (0x0000_0000, 0x8000_0008),
+    (0x0000_0000, 0x0000_0083),
+    (0x0000_0000, 0x8000_8003),
+    (0x0000_0001, 0x8000_8088),
+    (0x0000_0000, 0x8000_0088),
+    (0x0000_0001, 0x0000_8000),
+    (0x0000_0000, 0x8000_8082),
+];
+
+/// ρ rotation offsets, indexed by `x + 5 * y`.
+const RHO: [u32; PLEN] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+/// Gather the even bits of `x` into the low half and the odd bits into the
+/// high half.
+#[inline(always)]
+fn unshuffle(mut x: u32) -> u32 {
+    let mut t;
+    t = (x ^ (x >> 1)) & 0x2222_2222;
+    x ^= t ^ (t << 1);
+    t = (x ^ (x >> 2)) & 0x0C0C_0C0C;
+    x ^= t ^ (t << 2);
+    t = (x ^ (x >> 4)) & 0x00F0_00F0;
+    x ^= t ^ (t << 4);
+    t = (x ^ (x >> 8)) & 0x0000_FF00;
+    x ^= t ^ (t << 8);
+    x
+}
+
+/// Inverse of [`unshuffle`].
+#[inline(always)]
+fn shuffle(mut x: u32) -> u32 {
+    let mut t;
+    t = (x ^ (x >> 8)) & 0x0000_FF00;
+    x ^= t ^ (t << 8);
+    t = (x ^ (x >> 4)) & 0x00F0_00F0;
+    x ^= t ^ (t << 4);
+    t = (x ^ (x >> 2)) & 0x0C0C_0C0C;
+    x ^= t ^ (t << 2);
+    t = (x ^ (x >> 1)) & 0x2222_2222;
+    x ^= t ^ (t << 1);
+    x
+}
+
+#[inline(always)]
+fn interleave(lane: u64) -> (u32, u32) {
+    let lo = unshuffle(lane as u32);
+    let hi = unshuffle((lane >> 32) as u32);
+    ((lo & 0xFFFF) | (hi << 16), (lo >> 16) | (hi & 0xFFFF_0000))
+}
+
+#[inline(always)]
+fn deinterleave(even: u32, odd: u32) -> u64 {
+    let lo = shuffle((even & 0xFFFF) | (odd << 16));
+    let hi = shuffle((even >> 16) | (odd & 0xFFFF_0000));
+    u64::from(lo) | (u64::from(hi) << 32)
+}
+
+/// Rotate an interleaved lane left by `r` bits.
+#[inline(always)]
+fn rotl(even: u32, odd: u32, r: u32) -> (u32, u32) {
+    if r & 1 == 0 {
+        (even.rotate_left(r / 2), odd.rotate_left(r / 2))
+    } else {
+        (odd.rotate_left(r / 2 + 1), even.rotate_left(r / 2))
+    }
+}
+
+pub(crate) fn f1600(a: &mut [u64; PLEN]) {
+    let mut e = [0u32; PLEN];
+    let mut o = [0u32; PLEN];
+    for ((lane, e), o) in a.iter().zip(e.iter_mut()).zip(o.iter_mut()) {
+        let (even, odd) = interleave(*lane);
+        *e = even;
+        *o = odd;
+    }
+
+    for &(rc_e, rc_o) in RC.iter() {
+        // θ
+        let mut ce = [0u32; 5];
+        let mut co = [0u32; 5];
+        for x in 0..5 {
+            ce[x] = e[x] ^ e[x + 5] ^ e[x + 10] ^ e[x + 15] ^ e[x + 20];
+            co[x] = o[x] ^ o[x + 5] ^ o[x + 10] ^ o[x + 15] ^ o[x + 20];
+        }
+        for x in 0..5 {
+            let (re, ro) = rotl(ce[(x + 1) % 5], co[(x + 1) % 5], 1);
+            let de = ce[(x + 4) % 5] ^ re;
+            let d_o = co[(x + 4) % 5] ^ ro;
+            for y in 0..5 {
+                e[x + 5 * y] ^= de;
+                o[x + 5 * y] ^= d_o;
+            }
+        }
+
+        // ρ and π
+        let mut be = [0u32; PLEN];
+        let mut bo = [0u32; PLEN];
+        for y in 0..5 {
+            for x in 0..5 {
+                let i = x + 5 * y;
+                let j = y + 5 * ((2 * x + 3 * y) % 5);
+                let (re, ro) = rotl(e[i], o[i], RHO[i]);
+                be[j] = re;
+                bo[j] = ro;
+            }
+        }
+
+        // χ
+        for y in 0..5 {
+            for x in 0..5 {
+                let i = x + 5 * y;
+                let i1 = (x + 1) % 5 + 5 * y;
+                let i2 = (x + 2) % 5 + 5 * y;
+                e[i] = be[i] ^ (!be[i1] & be[i2]);
+                o[i] = bo[i] ^ (!bo[i1] & bo[i2]);
+            }
+        }
+
+        // ι
+        e[0] ^= rc_e;
+        o[0] ^= rc_o;
+    }
+
+    for ((lane, e), o) in a.iter_mut().zip(e.iter()).zip(o.iter()) {
+        *lane = deinterleave(*e, *o);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interleave_roundtrip() {
+        let lane = 0x0123_4567_89AB_CDEF;
+        let (even, odd) = interleave(lane);
+        assert_eq!(deinterleave(even, odd), lane);
+        assert_eq!(interleave(1), (1, 0));
+        assert_eq!(interleave(2), (0, 1));
+        assert_eq!(interleave(1 << 63), (0, 1 << 31));
+    }
+
+    /// Hash `msg` with a sponge over the bit-interleaved permutation.
+    fn sponge(rate: usize, pad: u8, msg: &[u8], out: &mut [u8]) {
+        let mut a = [0u64; PLEN];
+        let xor = |a: &mut [u64; PLEN], i: usize, b: u8| a[i / 8] ^= u64::from(b) << (8 * (i % 8));
+        let mut chunks = msg.chunks_exact(rate);
+        for block in &mut chunks {
+            for (i, &b) in block.iter().enumerate() {
+                xor(&mut a, i, b);
+            }
+            f1600(&mut a);
+        }
+        let rem = chunks.remainder();
+        for (i, &b) in rem.iter().enumerate() {
+            xor(&mut a, i, b);
+        }
+        xor(&mut a, rem.len(), pad);
+        xor(&mut a, rate - 1, 0x80);
+        f1600(&mut a);
+        for (i, o) in out.iter_mut().enumerate() {
+            *o = (a[i / 8] >> (8 * (i % 8))) as u8;
+        }
+    }
+
+    fn check<D: digest::Digest>(rate: usize, pad: u8) {
+        let msg = [0xA5u8; 500];
+        for len in [0, 1, rate - 1, rate, rate + 1, 500].iter() {
+            let mut out = [0u8; 64];
+            let out = &mut out[..D::output_size()];
+            sponge(rate, pad, &msg[..*len], out);
+            assert_eq!(out, &D::digest(&msg[..*len])[..]);
+        }
+    }
+
+    fn check_xof<D: Default + digest::Update + digest::ExtendableOutput>(rate: usize) {
+        let msg = [0x5Au8; 500];
+        for len in [0, rate, 500].iter() {
+            let mut expected = [0u8; 64];
+            let mut h = D::default();
+            h.update(&msg[..*len]);
+            digest::XofReader::read(&mut h.finalize_xof(), &mut expected);
+            let mut out = [0u8; 64];
+            sponge(rate, 0x1F, &msg[..*len], &mut out);
+            assert_eq!(out[..], expected[..]);
+        }
+    }
+
+    #[test]
+    fn matches_scalar_variants() {
+        check::<crate::Sha3_224>(144, 0x06);
+        check::<crate::Sha3_256>(136, 0x06);
+        check::<crate::Sha3_384>(104, 0x06);
+        check::<crate::Sha3_512>(72, 0x06);
+        check::<crate::Keccak224>(144, 0x01);
+        check::<crate::Keccak256>(136, 0x01);
+        check::<crate::Keccak384>(104, 0x01);
+        check::<crate::Keccak512>(72, 0x01);
+        check_xof::<crate::Shake128>(168);
+        check_xof::<crate::Shake256>(136);
+    }
+
+    #[test]
+    fn matches_scalar_permutation() {
+        let mut a = [0u64; PLEN];
+        let mut b = [0u64; PLEN];
+        for _ in 0..100 {
+            f1600(&mut a);
+            keccak::f1600(&mut b);
+            assert_eq!(a, b);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! Keccak-f[1600] permutation using AVX-512.
+//!
+//! Each row of five lanes is held in one ZMM register, with the upper three
+//! lanes unused. θ and χ map onto `vpternlogq` and lane permutations within
+//! a row, ρ is a single per-lane `vprolvq`, and π gathers every output row
+//! from the five input rows with two-source permutations.
+//!
+//! Support is detected at run time, with the `keccak` crate as fallback.
+//! The intrinsics require Rust 1.89+, hence the opt-in `avx512` feature.
+#![allow(unsafe_code)]
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+const PLEN: usize = 25;
+
+cpufeatures::new!(avx512_cpuid, "avx512f");
+
+const RC: [u64; 24] = [
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_8082,
+    0x8000_0000_0000_808A,
+    0x8000_0000_8000_8000,
+    0x0000_0000_0000_808B,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8009,
+    0x0000_0000_0000_008A,
+    0x0000_0000_0000_0088,
+    0x0000_0000_8000_8009,
+    0x0000_0000_8000_000A,
+    0x0000_0000_8000_808B,
+    0x8000_0000_0000_008B,
+    0x8000_0000_0000_8089,
+    0x8000_0000_0000_8003,
+    0x8000_0000_0000_8002,
+    0x8000_0000_0000_0080,
+    0x0000_0000_0000_800A,
+    0x8000_0000_8000_000A,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8080,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8008,
+];
+
+/// ρ rotation offsets, one row per register.
+const RHO: [[i64; 8]; 5] = [
+    [0, 1, 62, 28, 27, 0, 0, 0],
+    [36, 44, 6, 55, 20, 0, 0, 0],
+    [3, 10, 43, 25, 39, 0, 0, 0],
+    [41, 45, 15, 21, 8, 0, 0, 0],
+    [18, 2, 61, 56, 14, 0, 0, 0],
+];
+
+/// Lane `x` of output row `y` of π is lane `(x + 3y) % 5` of input row `x`.
+/// Lanes 0 and 1 are picked from input rows 0 and 1 (indices 8.. select
+/// from the second operand), lanes 2 and 3 from rows 2 and 3, and lane 4
+/// from row 4.
+const PI: [[[i64; 8]; 3]; 5] = [
+    [
+        [0, 9, 0, 0, 0, 0, 0, 0],
+        [0, 0, 2, 11, 0, 0, 0, 0],
+        [0, 0, 0, 0, 4, 0, 0, 0],
+    ],
+    [
+        [3, 12, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 9, 0, 0, 0, 0],
+        [0, 0, 0, 0, 2, 0, 0, 0],
+    ],
+    [
+        [1, 10, 0, 0, 0, 0, 0, 0],
+        [0, 0, 3, 12, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0],
+    ],
+    [
+        [4, 8, 0, 0, 0, 0, 0, 0],
+        [0, 0, 1, 10, 0, 0, 0, 0],
+        [0, 0, 0, 0, 3, 0, 0, 0],
+    ],
+    [
+        [2, 11, 0, 0, 0, 0, 0, 0],
+        [0, 0, 4, 8, 0, 0, 0, 0],
+        [0, 0, 0, 0, 1, 0, 0, 0],
+    ],
+];
+
+const ROW_MASK: __mmask8 = 0b1_1111;
+
+pub(crate) fn f1600(state: &mut [u64; PLEN]) {
+    if avx512_cpuid::get() {
+        // SAFETY: AVX-512F support was checked above
+        unsafe { f1600_avx512(state) }
+    } else {
+        keccak::f1600(state);
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn load(idx: &[i64; 8]) -> __m512i {
+    _mm512_loadu_si512(idx.as_ptr() as *const _)
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn f1600_avx512(state: &mut [u64; PLEN]) {
+    let mut a = [_mm512_setzero_si512(); 5];
+    for (a, row) in a.iter_mut().zip(state.chunks_exact(5)) {
+        *a = _mm512_maskz_loadu_epi64(ROW_MASK, row.as_ptr() as *const _);
+    }
+
+    let prev = load(&[4, 0, 1, 2, 3, 5, 6, 7]);
+    let next = load(&[1, 2, 3, 4, 0, 5, 6, 7]);
+    let next2 = load(&[2, 3, 4, 0, 1, 5, 6, 7]);
+    let mut rho = [_mm512_setzero_si512(); 5];
+    for (rho, r) in rho.iter_mut().zip(RHO.iter()) {
+        *rho = load(r);
+    }
+    let mut pi = [[_mm512_setzero_si512(); 3]; 5];
+    for (pi, p) in pi.iter_mut().zip(PI.iter()) {
+        for (pi, p) in pi.iter_mut().zip(p.iter()) {
+            *pi = load(p);
+        }
+    }
+
+    for &rc in RC.iter() {
+        // θ, 0x96 being the three-way XOR
+        let c = _mm512_ternarylogic_epi64(a[0], a[1], a[2], 0x96);
+        let c = _mm512_ternarylogic_epi64(c, a[3], a[4], 0x96);
+        let d = _mm512_xor_si512(
+            _mm512_permutexvar_epi64(prev, c),
+            _mm512_rol_epi64(_mm512_permutexvar_epi64(next, c), 1),
+        );
+
+        // ρ
+        let mut b = [_mm512_setzero_si512(); 5];
+        for ((b, a), rho) in b.iter_mut().zip(a.iter()).zip(rho.iter()) {
+            *b = _mm512_rolv_epi64(_mm512_xor_si512(*a, d), *rho);
+        }
+
+        // π
+        for (a, pi) in a.iter_mut().zip(pi.iter()) {
+            let t01 = _mm512_permutex2var_epi64(b[0], pi[0], b[1]);
+            let t23 = _mm512_permutex2var_epi64(b[2], pi[1], b[3]);
+            let t = _mm512_mask_blend_epi64(0b0_1100, t01, t23);
+            *a = _mm512_mask_permutexvar_epi64(t, 0b1_0000, pi[2], b[4]);
+        }
+
+        // χ, 0xD2 being `a ^ (!b & c)`
+        for a in a.iter_mut() {
+            let a1 = _mm512_permutexvar_epi64(next, *a);
+            let a2 = _mm512_permutexvar_epi64(next2, *a);
+            *a = _mm512_ternarylogic_epi64(*a, a1, a2, 0xD2);
+        }
+
+        // ι
+        a[0] = _mm512_mask_xor_epi64(a[0], 0b1, a[0], _mm512_set1_epi64(rc as i64));
+    }
+
+    for (a, row) in a.iter().zip(state.chunks_exact_mut(5)) {
+        _mm512_mask_storeu_epi64(row.as_mut_ptr() as *mut _, ROW_MASK, *a);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_scalar_permutation() {
+        if !avx512_cpuid::get() {
+            return;
+        }
+        let mut a = [0u64; PLEN];
+        let mut b = [0u64; PLEN];
+        for i in 0..100u64 {
+            for (j, (a, b)) in a.iter_mut().zip(b.iter_mut()).enumerate() {
+                let v = i.wrapping_mul(0x9E37_79B9_7F4A_7C15).rotate_left(j as u32);
+                *a ^= v;
+                *b ^= v;
+            }
+            unsafe { f1600_avx512(&mut a) };
+            keccak::f1600(&mut b);
+            assert_eq!(a, b);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! cSHAKE, the customizable SHAKE variant of [NIST SP 800-185][1], and the
+//! string encodings it shares with the other SP 800-185 functions.
+//!
+//! [1]: https://doi.org/10.6028/NIST.SP.800-185
+
+use crate::paddings;
+use crate::reader::Sha3XofReader;
+use crate::state::Sha3State;
+use block_buffer::BlockBuffer;
+use digest::consts::{U136, U168};
+use digest::generic_array::typenum::Unsigned;
+use digest::{ExtendableOutputDirty, Reset, Update};
+
+/// Largest rate of the SP 800-185 functions, i.e. that of the 128-bit ones.
+const MAX_RATE: usize = 168;
+
+/// `left_encode(x)`: the big-endian bytes of `x`, without leading zeros,
+/// preceded by their count. Returns the used part of `buf`.
+pub(crate) fn left_encode(x: u64, buf: &mut [u8; 9]) -> &[u8] {
+    let n = encode_len(x);
+    buf[0] = n as u8;
+    buf[1..=n].copy_from_slice(&x.to_be_bytes()[8 - n..]);
+    &buf[..=n]
+}
+
+/// `right_encode(x)`: like [`left_encode`], with the count at the end.
+pub(crate) fn right_encode(x: u64, buf: &mut [u8; 9]) -> &[u8] {
+    let n = encode_len(x);
+    buf[..n].copy_from_slice(&x.to_be_bytes()[8 - n..]);
+    buf[n] = n as u8;
+    &buf[..=n]
+}
+
+fn encode_len(x: u64) -> usize {
+    // zero is still encoded as one byte
+    core::cmp::max(1, 8 - x.leading_zeros() as usize / 8)
+}
+
+/// Feed `encode_string(s)` to `absorb` and return its length in bytes.
+pub(crate) fn encode_string(s: &[u8], absorb: &mut impl FnMut(&[u8])) -> usize {
+    let mut buf = [0u8; 9];
+    let len = left_encode(8 * s.len() as u64, &mut buf);
+    absorb(len);
+    absorb(s);
+    len.len() + s.len()
+}
+
+/// Feed `bytepad(encode_string(s_1) || .. || encode_string(s_n), rate)` to
+/// `absorb`, so that the total input is a multiple of `rate` bytes.
+pub(crate) fn bytepad(strings: &[&[u8]], rate: usize, mut absorb: impl FnMut(&[u8])) {
+    debug_assert!(rate <= MAX_RATE);
+    let mut buf = [0u8; 9];
+    let prefix = left_encode(rate as u64, &mut buf);
+    absorb(prefix);
+    let mut n = prefix.len();
+    for s in strings {
+        n += encode_string(s, &mut absorb);
+    }
+    let zeros = [0u8; MAX_RATE];
+    absorb(&zeros[..(rate - n % rate) % rate]);
+}
+
+macro_rules! cshake_impl {
+    ($state:ident, $rate:ident, $alg_name:expr, $doc:expr) => {
+        #[doc=$doc]
+        #[derive(Clone)]
+        pub struct $state {
+            state: Sha3State,
+            buffer: BlockBuffer<$rate>,
+            // state right after the customization block, restored on reset
+            initial_state: Sha3State,
+            customized: bool,
+        }
+
+        impl $state {
+            /// Canonical name of the algorithm, e.g. for logging.
+            pub const ALG_NAME: &'static str = $alg_name;
+
+            /// Create a hasher with the function name `function_name` (`N`)
+            /// and customization string `customization` (`S`).
+            ///
+            /// If both are empty, the output is identical to that of SHAKE.
+            pub fn new(function_name: &[u8], customization: &[u8]) -> Self {
+                let mut state = Sha3State::default();
+                let customized = !function_name.is_empty() || !customization.is_empty();
+                if customized {
+                    let mut buffer = BlockBuffer::<$rate>::default();
+                    bytepad(&[function_name, customization], $rate::to_usize(), |data| {
+                        buffer.input_blocks(data, |b| state.absorb_blocks(b))
+                    });
+                    debug_assert_eq!(buffer.position(), 0);
+                }
+                $state {
+                    initial_state: state.clone(),
+                    state,
+                    buffer: Default::default(),
+                    customized,
+                }
+            }
+        }
+
+        impl core::fmt::Display for $state {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Self::ALG_NAME)
+            }
+        }
+
+        impl Update for $state {
+            fn update(&mut self, input: impl AsRef<[u8]>) {
+                let s = &mut self.state;
+                self.buffer
+                    .input_blocks(input.as_ref(), |b| s.absorb_blocks(b));
+            }
+        }
+
+        impl ExtendableOutputDirty for $state {
+            type Reader = Sha3XofReader;
+
+            fn finalize_xof_dirty(&mut self) -> Sha3XofReader {
+                let block = if self.customized {
+                    self.buffer.pad_with::<paddings::CShake>()
+                } else {
+                    self.buffer.pad_with::<paddings::Shake>()
+                };
+                let block = block.expect("we never use input_lazy");
+                self.state.absorb_block(block);
+                Sha3XofReader::new(self.state.clone(), $rate::to_usize())
+            }
+        }
+
+        impl Reset for $state {
+            fn reset(&mut self) {
+                self.state = self.initial_state.clone();
+                self.buffer.reset();
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl Drop for $state {
+            fn drop(&mut self) {
+                crate::state::zeroize_buffer(&mut self.buffer);
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize_crate::ZeroizeOnDrop for $state {}
+
+        opaque_debug::implement!($state);
+        digest::impl_write!($state);
+    };
+}
+
+cshake_impl!(
+    CShake128,
+    U168,
+    "cSHAKE128",
+    "cSHAKE128 customizable extendable output (XOF) hash function."
+);
+cshake_impl!(
+    CShake256,
+    U136,
+    "cSHAKE256",
+    "cSHAKE256 customizable extendable output (XOF) hash function."
+);
@@ -0,0 +1,153 @@
+//! Keccak duplex construction.
+//!
+//! A duplex object keeps a Keccak-f[1600] state across calls and lets input
+//! be absorbed and output be squeezed in any interleaving, which makes it a
+//! building block for authenticated encryption, stream ciphers and PRNGs.
+//!
+//! Absorbed data is terminated with the `pad10*1` padding of the original
+//! Keccak submission (domain bits `0x01`) when switching to squeezing, so a
+//! single `absorb` followed by `squeeze` matches the Keccak hash with the
+//! same rate, e.g. [`KeccakDuplex256`] and [`Keccak256`][crate::Keccak256].
+//!
+//! ```rust
+//! use sha3::duplex::KeccakDuplex128;
+//!
+//! let mut duplex = KeccakDuplex128::new();
+//! duplex.absorb(b"key");
+//! duplex.absorb(b"nonce");
+//! let mut keystream = [0u8; 64];
+//! duplex.squeeze(&mut keystream);
+//! duplex.ratchet();
+//! ```
+use crate::state::Sha3State;
+use core::fmt;
+use core::marker::PhantomData;
+use digest::consts::{U136, U168};
+use digest::generic_array::ArrayLength;
+
+/// Duplex with a rate of 168 bytes, i.e. 128-bit security.
+pub type KeccakDuplex128 = KeccakDuplex<U168>;
+/// Duplex with a rate of 136 bytes, i.e. 256-bit security.
+pub type KeccakDuplex256 = KeccakDuplex<U136>;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Absorbing,
+    Squeezing,
+}
+
+/// Keccak duplex object with a rate of `Rate` bytes.
+#[derive(Clone)]
+pub struct KeccakDuplex<Rate: ArrayLength<u8>> {
+    state: Sha3State,
+    pos: usize,
+    mode: Mode,
+    rate: PhantomData<Rate>,
+}
+
+impl<Rate: ArrayLength<u8>> KeccakDuplex<Rate> {
+    /// Create a duplex object with an all-zero state.
+    ///
+    /// # Panics
+    ///
+    /// If `Rate` is zero, not a multiple of 8 or not smaller than 200.
+    pub fn new() -> Self {
+        let rate = Rate::to_usize();
+        assert!(rate != 0 && rate % 8 == 0 && rate < 200, "invalid rate");
+        KeccakDuplex {
+            state: Sha3State::default(),
+            pos: 0,
+            mode: Mode::Absorbing,
+            rate: PhantomData,
+        }
+    }
+
+    /// Absorb `data` into the state.
+    ///
+    /// Absorbing after squeezing first permutes the state, so the new input
+    /// does not overlap with output that has already been produced.
+    pub fn absorb(&mut self, data: &[u8]) {
+        if self.mode == Mode::Squeezing {
+            self.permute();
+            self.mode = Mode::Absorbing;
+        }
+        for &b in data {
+            self.xor_byte(self.pos, b);
+            self.pos += 1;
+            if self.pos == Rate::to_usize() {
+                self.permute();
+            }
+        }
+    }
+
+    /// Squeeze `out.len()` bytes of output from the state.
+    ///
+    /// Pending input is padded and permuted first.
+    pub fn squeeze(&mut self, out: &mut [u8]) {
+        if self.mode == Mode::Absorbing {
+            self.pad_and_permute();
+            self.mode = Mode::Squeezing;
+        }
+        for o in out {
+            if self.pos == Rate::to_usize() {
+                self.permute();
+            }
+            *o = self.byte(self.pos);
+            self.pos += 1;
+        }
+    }
+
+    /// Irreversibly update the state so that earlier states can not be
+    /// recovered from it, e.g. for forward secrecy after a key is used.
+    ///
+    /// Pending input is padded and permuted, then the outer `Rate` bytes are
+    /// zeroed and the state is permuted again. Recovering the previous state
+    /// would require guessing the zeroed bytes.
+    pub fn ratchet(&mut self) {
+        if self.mode == Mode::Absorbing {
+            self.pad_and_permute();
+        } else {
+            self.permute();
+        }
+        let lanes = Rate::to_usize() / 8;
+        for lane in self.state.state[..lanes].iter_mut() {
+            *lane = 0;
+        }
+        self.permute();
+        self.mode = Mode::Absorbing;
+    }
+
+    fn pad_and_permute(&mut self) {
+        self.xor_byte(self.pos, 0x01);
+        self.xor_byte(Rate::to_usize() - 1, 0x80);
+        self.permute();
+    }
+
+    fn permute(&mut self) {
+        self.state.apply_f();
+        self.pos = 0;
+    }
+
+    fn xor_byte(&mut self, i: usize, b: u8) {
+        self.state.state[i / 8] ^= u64::from(b) << (8 * (i % 8));
+    }
+
+    fn byte(&self, i: usize) -> u8 {
+        (self.state.state[i / 8] >> (8 * (i % 8))) as u8
+    }
+}
+
+impl<Rate: ArrayLength<u8>> Default for KeccakDuplex<Rate> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<Rate: ArrayLength<u8>> zeroize_crate::ZeroizeOnDrop for KeccakDuplex<Rate> {}
+
+impl<Rate: ArrayLength<u8>> fmt::Debug for KeccakDuplex<Rate> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("KeccakDuplex { ... }")
+    }
+}
@@ -0,0 +1,117 @@
+//! KMAC, the cSHAKE-based message authentication code of
+//! [NIST SP 800-185][1] §4.
+//!
+//! [1]: https://doi.org/10.6028/NIST.SP.800-185
+
+use crate::cshake::{bytepad, right_encode, CShake128, CShake256};
+use crypto_mac::{InvalidKeyLength, Mac, NewMac};
+use digest::consts::{U136, U168, U32, U64};
+use digest::generic_array::typenum::Unsigned;
+use digest::generic_array::GenericArray;
+use digest::{ExtendableOutputDirty, Update, XofReader};
+
+macro_rules! kmac_impl {
+    ($state:ident, $cshake:ident, $rate:ident, $output_size:ident, $alg_name:expr, $doc:expr) => {
+        #[doc=$doc]
+        #[derive(Clone)]
+        pub struct $state {
+            inner: $cshake,
+            // cSHAKE state after absorbing the key, restored on reset
+            keyed: $cshake,
+        }
+
+        impl $state {
+            /// Canonical name of the algorithm, e.g. for logging.
+            pub const ALG_NAME: &'static str = $alg_name;
+
+            /// Create a MAC with `key` and the customization string
+            /// `customization` (`S`).
+            ///
+            /// Keys longer than the rate of the underlying sponge are
+            /// rejected.
+            pub fn new(key: &[u8], customization: &[u8]) -> Result<Self, InvalidKeyLength> {
+                if key.len() > $rate::to_usize() {
+                    return Err(InvalidKeyLength);
+                }
+                let mut keyed = $cshake::new(b"KMAC", customization);
+                bytepad(&[key], $rate::to_usize(), |data| keyed.update(data));
+                Ok($state {
+                    inner: keyed.clone(),
+                    keyed,
+                })
+            }
+
+            /// Finalize the MAC into `out`, whose length determines the
+            /// output length `L`.
+            ///
+            /// Outputs of different lengths are unrelated, i.e. a shorter
+            /// tag is not a prefix of a longer one.
+            pub fn finalize_into(mut self, out: &mut [u8]) {
+                let mut buf = [0u8; 9];
+                let output_bits = 8 * out.len() as u64;
+                self.inner.update(right_encode(output_bits, &mut buf));
+                self.inner.finalize_xof_dirty().read(out);
+            }
+        }
+
+        impl core::fmt::Display for $state {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Self::ALG_NAME)
+            }
+        }
+
+        impl NewMac for $state {
+            type KeySize = $output_size;
+
+            fn new(key: &GenericArray<u8, $output_size>) -> Self {
+                Self::new(key, b"").expect("key is shorter than the rate")
+            }
+
+            fn new_from_slice(key: &[u8]) -> Result<Self, InvalidKeyLength> {
+                Self::new(key, b"")
+            }
+        }
+
+        impl Mac for $state {
+            type OutputSize = $output_size;
+
+            fn update(&mut self, data: &[u8]) {
+                self.inner.update(data);
+            }
+
+            fn reset(&mut self) {
+                self.inner = self.keyed.clone();
+            }
+
+            fn finalize(self) -> crypto_mac::Output<Self> {
+                let mut out = GenericArray::default();
+                self.finalize_into(&mut out);
+                crypto_mac::Output::new(out)
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize_crate::ZeroizeOnDrop for $state {}
+
+        opaque_debug::implement!($state);
+    };
+}
+
+kmac_impl!(
+    Kmac128,
+    CShake128,
+    U168,
+    U32,
+    "KMAC128",
+    "KMAC128 message authentication code. The `Mac` trait produces 256-bit \
+    tags, other lengths are available with `finalize_into`."
+);
+kmac_impl!(
+    Kmac256,
+    CShake256,
+    U136,
+    U64,
+    "KMAC256",
+    "KMAC256 message authentication code. The `Mac` trait produces 512-bit \
+    tags, other lengths are available with `finalize_into`."
+);
@@ -11,6 +11,12 @@
 //! * `Keccak224`, `Keccak256`, `Keccak384`, `Keccak512` (NIST submission
 //!    without padding changes)
 //!
+//! It also provides `TurboShake128` and `TurboShake256`, the reduced-round
+//! XOFs underlying KangarooTwelve, and from [NIST SP 800-185][3] the
+//! customizable `CShake128` and `CShake256` XOFs, the `TupleHash128` and
+//! `TupleHash256` tuple hashes and the `ParallelHash128` and
+//! `ParallelHash256` block-parallel hashes.
+//!
 //! # Examples
 //!
 //! Output size of SHA3-256 is fixed, so its functionality is usually
@@ -51,12 +57,35 @@
 //! assert_eq!(res1, hex!("5881092dd818bf5cf8a3"));
 //! ```
 //!
+//! For a fixed amount of output, SHAKE can also be used through `Digest` with
+//! the [`Shake128Fixed`] and [`Shake256Fixed`] wrappers, e.g. as a drop-in
+//! replacement for [`Sha3_256`]:
+//!
+//! ```
+//! use hex_literal::hex;
+//! use sha3::{Digest, Shake128_256};
+//!
+//! let result = Shake128_256::digest(b"abc");
+//! assert_eq!(result[..], hex!("
+//!     5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8
+//! ")[..]);
+//! ```
+//!
+//! With the `hmac` feature enabled, HMAC type aliases such as
+//! [`HmacSha3_256`] are available as well. The `hkdf` feature enables the
+//! HKDF-based PRF in the [`prf`] module, and the `kmac` feature the
+//! `Kmac128` and `Kmac256` MACs built on cSHAKE. For custom sponge
+//! constructions, the `keccak-f` feature exports the underlying permutation
+//! as `keccak_f1600`.
+//!
 //! Also see [RustCrypto/hashes][2] readme.
 //!
 //! [1]: https://en.wikipedia.org/wiki/SHA-3
 //! [2]: https://github.com/RustCrypto/hashes
+//! [3]: https://doi.org/10.6028/NIST.SP.800-185
 
 #![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
     html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
@@ -69,25 +98,70 @@ extern crate std;
 
 pub use digest::{self, Digest};
 
+use block_buffer::block_padding::ZeroPadding;
 use block_buffer::BlockBuffer;
+use core::marker::PhantomData;
 use digest::consts::{U104, U136, U144, U168, U200, U28, U32, U48, U64, U72};
 use digest::generic_array::typenum::Unsigned;
-use digest::{BlockInput, ExtendableOutputDirty, FixedOutputDirty, Reset, Update};
+use digest::generic_array::ArrayLength;
+use digest::{BlockInput, ExtendableOutputDirty, FixedOutputDirty, Reset, Update, XofReader};
 
+#[cfg(all(target_arch = "aarch64", target_feature = "sha3"))]
+mod aarch64;
+#[cfg(any(all(feature = "arm32", target_arch = "arm"), test))]
+mod arm32;
+#[cfg(all(feature = "avx512", any(target_arch = "x86", target_arch = "x86_64")))]
+mod avx512;
+mod cshake;
+#[cfg(feature = "kmac")]
+mod kmac;
 mod paddings;
+mod parallelhash;
 #[macro_use]
 mod macros;
 mod reader;
-mod state;
+pub mod state;
+mod tuplehash;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod wasm;
 
+pub use crate::cshake::{CShake128, CShake256};
+#[cfg(feature = "kmac")]
+pub use crate::kmac::{Kmac128, Kmac256};
+pub use crate::parallelhash::{ParallelHash128, ParallelHash256};
 pub use crate::reader::Sha3XofReader;
-use crate::state::Sha3State;
+use crate::state::{Sha3State, Sha3StateCheckpoint, SHA3_SNAPSHOT_LEN};
+pub use crate::tuplehash::{TupleHash128, TupleHash256};
+
+/// Number of Keccak-p[1600] rounds used by TurboSHAKE.
+const TURBO_SHAKE_ROUND_COUNT: usize = 12;
+
+#[cfg(feature = "hmac")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hmac")))]
+pub use hmac;
+
+#[cfg(feature = "hkdf")]
+pub use hkdf;
+
+#[cfg(feature = "kmac")]
+pub use crypto_mac;
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub use zeroize_crate as zeroize;
+
+#[cfg(feature = "hkdf")]
+pub mod prf;
+
+#[cfg(feature = "duplex")]
+pub mod duplex;
 
 sha3_impl!(
     Keccak224,
     U28,
     U144,
     paddings::Keccak,
+    "Keccak-224",
     "Keccak-224 hash function."
 );
 sha3_impl!(
@@ -95,6 +169,7 @@ sha3_impl!(
     U32,
     U136,
     paddings::Keccak,
+    "Keccak-256",
     "Keccak-256 hash function."
 );
 sha3_impl!(
@@ -102,6 +177,7 @@ sha3_impl!(
     U48,
     U104,
     paddings::Keccak,
+    "Keccak-384",
     "Keccak-384 hash function."
 );
 sha3_impl!(
@@ -109,6 +185,7 @@ sha3_impl!(
     U64,
     U72,
     paddings::Keccak,
+    "Keccak-512",
     "Keccak-512 hash function."
 );
 
@@ -117,6 +194,7 @@ sha3_impl!(
     U200,
     U136,
     paddings::Keccak,
+    "Keccak-256-Full",
     "SHA-3 variant used in CryptoNight."
 );
 
@@ -125,6 +203,7 @@ sha3_impl!(
     U28,
     U144,
     paddings::Sha3,
+    "SHA3-224",
     "SHA-3-224 hash function."
 );
 sha3_impl!(
@@ -132,6 +211,7 @@ sha3_impl!(
     U32,
     U136,
     paddings::Sha3,
+    "SHA3-256",
     "SHA-3-256 hash function."
 );
 sha3_impl!(
@@ -139,6 +219,7 @@ sha3_impl!(
     U48,
     U104,
     paddings::Sha3,
+    "SHA3-384",
     "SHA-3-384 hash function."
 );
 sha3_impl!(
@@ -146,6 +227,7 @@ sha3_impl!(
     U64,
     U72,
     paddings::Sha3,
+    "SHA3-512",
     "SHA-3-512 hash function."
 );
 
@@ -153,11 +235,93 @@ shake_impl!(
     Shake128,
     U168,
     paddings::Shake,
+    "SHAKE128",
     "SHAKE128 extendable output (XOF) hash function"
 );
 shake_impl!(
     Shake256,
     U136,
     paddings::Shake,
+    "SHAKE256",
     "SHAKE256 extendable output (XOF) hash function"
 );
+
+turbo_shake_impl!(
+    TurboShake128,
+    U168,
+    "TurboSHAKE128",
+    "TurboSHAKE128 extendable output (XOF) hash function, based on 12 rounds \
+    of Keccak-p[1600]."
+);
+turbo_shake_impl!(
+    TurboShake256,
+    U136,
+    "TurboSHAKE256",
+    "TurboSHAKE256 extendable output (XOF) hash function, based on 12 rounds \
+    of Keccak-p[1600]."
+);
+
+shake_fixed_impl!(
+    Shake128Fixed,
+    Shake128,
+    U168,
+    "SHAKE128 with an output size of `N` bytes, usable through `Digest`."
+);
+shake_fixed_impl!(
+    Shake256Fixed,
+    Shake256,
+    U136,
+    "SHAKE256 with an output size of `N` bytes, usable through `Digest`."
+);
+
+/// Apply the Keccak-f[1600] permutation, i.e. all 24 rounds, to `state` in
+/// place.
+///
+/// Lanes use the standard layout: lane `(x, y)` is `state[x + 5 * y]`, with
+/// bytes absorbed into it in little-endian order. This is the same
+/// permutation that backs all hashers in this crate.
+#[cfg(feature = "keccak-f")]
+#[inline]
+pub fn keccak_f1600(state: &mut [u64; 25]) {
+    state::f1600(state);
+}
+
+/// Keccak step of the [CryptoNight][1] proof-of-work function.
+///
+/// This is only the initial hashing step of CryptoNight: the full algorithm
+/// additionally fills a 2 MiB scratchpad using AES rounds keyed from this
+/// state, runs the memory-hard main loop over it and selects one of the
+/// BLAKE-256, Grøstl-256, JH-256 or Skein-256 hashes for the final output.
+/// None of that is provided by this crate.
+///
+/// [1]: https://cryptonote.org/cns/cns008.txt
+#[cfg(feature = "cryptonight")]
+pub type CryptoNightKeccak = Keccak256Full;
+
+/// SHAKE128 with 256 bits of output.
+#[allow(non_camel_case_types)]
+pub type Shake128_256 = Shake128Fixed<U32>;
+/// SHAKE256 with 512 bits of output.
+#[allow(non_camel_case_types)]
+pub type Shake256_512 = Shake256Fixed<U64>;
+
+/// HMAC-SHA3-224
+#[cfg(feature = "hmac")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hmac")))]
+#[allow(non_camel_case_types)]
+pub type HmacSha3_224 = hmac::Hmac<Sha3_224>;
+/// HMAC-SHA3-256
+#[cfg(feature = "hmac")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hmac")))]
+#[allow(non_camel_case_types)]
+pub type HmacSha3_256 = hmac::Hmac<Sha3_256>;
+/// HMAC-SHA3-384
+#[cfg(feature = "hmac")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hmac")))]
+#[allow(non_camel_case_types)]
+pub type HmacSha3_384 = hmac::Hmac<Sha3_384>;
+/// HMAC-SHA3-512
+#[cfg(feature = "hmac")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hmac")))]
+#[allow(non_camel_case_types)]
+pub type HmacSha3_512 = hmac::Hmac<Sha3_512>;
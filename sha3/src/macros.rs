@@ -9,9 +9,53 @@ macro_rules! impl_state {
         }
 
         impl $state {
+            /// Snapshot the Keccak state for later use with `from_checkpoint`.
+            ///
+            /// Returns `None` unless the input so far fills a whole number of
+            /// blocks, since buffered input is not part of the checkpoint.
+            pub fn checkpoint(&self) -> Option<Sha3StateCheckpoint> {
+                if self.buffer.position() == 0 {
+                    Some((&self.state).into())
+                } else {
+                    None
+                }
+            }
+
+            /// Restore a hasher from a checkpoint taken at a block boundary.
+            pub fn from_checkpoint(checkpoint: &Sha3StateCheckpoint) -> Self {
+                Self {
+                    state: checkpoint.into(),
+                    buffer: Default::default(),
+                }
+            }
+
+            /// Serialize the hasher state, including input which does not
+            /// fill a whole block yet, see [`SHA3_SNAPSHOT_LEN`].
+            pub fn serialize_state(&self) -> [u8; SHA3_SNAPSHOT_LEN] {
+                let mut buffer = self.buffer.clone();
+                let pos = buffer.position();
+                let block = buffer
+                    .pad_with::<ZeroPadding>()
+                    .expect("we never use input_lazy");
+                self.state.serialize_with_buffer(&block[..pos])
+            }
+
+            /// Restore a hasher from a snapshot created by `serialize_state`.
+            ///
+            /// Returns `None` if `bytes` is not [`SHA3_SNAPSHOT_LEN`] bytes
+            /// long or is malformed.
+            pub fn deserialize_state(bytes: &[u8]) -> Option<Self> {
+                let (state, pos) = Sha3State::deserialize_with_buffer(bytes, $rate::to_usize())?;
+                // the buffered bytes are already XORed into the state, so
+                // zeros in their place keep the absorbed block unchanged
+                let mut buffer = BlockBuffer::default();
+                buffer.input_blocks(&[0u8; 200][..pos], |_| unreachable!());
+                Some(Self { state, buffer })
+            }
+
             fn absorb(&mut self, input: &[u8]) {
                 let s = &mut self.state;
-                self.buffer.input_block(input, |b| s.absorb_block(b));
+                self.buffer.input_blocks(input, |b| s.absorb_blocks(b));
             }
 
             fn apply_padding(&mut self) {
@@ -22,13 +66,45 @@ macro_rules! impl_state {
                 self.state.absorb_block(buf);
             }
         }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize_crate::Zeroize for $state {
+            fn zeroize(&mut self) {
+                self.state.zeroize();
+                crate::state::zeroize_buffer(&mut self.buffer);
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl Drop for $state {
+            fn drop(&mut self) {
+                crate::state::zeroize_buffer(&mut self.buffer);
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize_crate::ZeroizeOnDrop for $state {}
     };
 }
 
 macro_rules! sha3_impl {
-    ($state:ident, $output_size:ident, $rate:ident, $padding:ty, $doc:expr) => {
+    (
+        $state:ident, $output_size:ident, $rate:ident, $padding:ty,
+        $alg_name:expr, $doc:expr
+    ) => {
         impl_state!($state, $rate, $padding, $doc);
 
+        impl $state {
+            /// Canonical name of the algorithm, e.g. for logging.
+            pub const ALG_NAME: &'static str = $alg_name;
+        }
+
+        impl core::fmt::Display for $state {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Self::ALG_NAME)
+            }
+        }
+
         impl BlockInput for $state {
             type BlockSize = $rate;
         }
@@ -65,9 +141,20 @@ macro_rules! sha3_impl {
 }
 
 macro_rules! shake_impl {
-    ($state:ident, $rate:ident, $padding:ty, $doc:expr) => {
+    ($state:ident, $rate:ident, $padding:ty, $alg_name:expr, $doc:expr) => {
         impl_state!($state, $rate, $padding, $doc);
 
+        impl $state {
+            /// Canonical name of the algorithm, e.g. for logging.
+            pub const ALG_NAME: &'static str = $alg_name;
+        }
+
+        impl core::fmt::Display for $state {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Self::ALG_NAME)
+            }
+        }
+
         impl Update for $state {
             fn update(&mut self, input: impl AsRef<[u8]>) {
                 self.absorb(input.as_ref())
@@ -95,3 +182,149 @@ macro_rules! shake_impl {
         digest::impl_write!($state);
     };
 }
+
+macro_rules! shake_fixed_impl {
+    ($state:ident, $shake:ident, $rate:ident, $doc:expr) => {
+        #[doc=$doc]
+        pub struct $state<N: ArrayLength<u8>> {
+            inner: $shake,
+            output_size: PhantomData<N>,
+        }
+
+        impl<N: ArrayLength<u8>> Default for $state<N> {
+            fn default() -> Self {
+                $state {
+                    inner: Default::default(),
+                    output_size: PhantomData,
+                }
+            }
+        }
+
+        impl<N: ArrayLength<u8>> Clone for $state<N> {
+            fn clone(&self) -> Self {
+                $state {
+                    inner: self.inner.clone(),
+                    output_size: PhantomData,
+                }
+            }
+        }
+
+        impl<N: ArrayLength<u8>> BlockInput for $state<N> {
+            type BlockSize = $rate;
+        }
+
+        impl<N: ArrayLength<u8>> Update for $state<N> {
+            fn update(&mut self, input: impl AsRef<[u8]>) {
+                self.inner.absorb(input.as_ref())
+            }
+        }
+
+        impl<N: ArrayLength<u8>> FixedOutputDirty for $state<N> {
+            type OutputSize = N;
+
+            fn finalize_into_dirty(&mut self, out: &mut digest::Output<Self>) {
+                self.inner.finalize_xof_dirty().read(out);
+            }
+        }
+
+        impl<N: ArrayLength<u8>> Reset for $state<N> {
+            fn reset(&mut self) {
+                Reset::reset(&mut self.inner);
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl<N: ArrayLength<u8>> zeroize_crate::ZeroizeOnDrop for $state<N> {}
+
+        impl<N: ArrayLength<u8>> core::fmt::Debug for $state<N> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(concat!(stringify!($state), " { ... }"))
+            }
+        }
+    };
+}
+
+macro_rules! turbo_shake_impl {
+    ($state:ident, $rate:ident, $alg_name:expr, $doc:expr) => {
+        #[doc=$doc]
+        #[derive(Clone)]
+        pub struct $state {
+            state: Sha3State,
+            buffer: BlockBuffer<$rate>,
+            domain_sep: u8,
+        }
+
+        impl $state {
+            /// Canonical name of the algorithm, e.g. for logging.
+            pub const ALG_NAME: &'static str = $alg_name;
+
+            /// Create a hasher with the domain separation byte `domain_sep`.
+            ///
+            /// # Panics
+            /// If `domain_sep` is not in the range `0x01..=0x7F`.
+            pub fn new(domain_sep: u8) -> Self {
+                assert!(
+                    (0x01..=0x7F).contains(&domain_sep),
+                    "domain separation byte must be in 0x01..=0x7F"
+                );
+                $state {
+                    state: Sha3State::with_rounds(TURBO_SHAKE_ROUND_COUNT),
+                    buffer: Default::default(),
+                    domain_sep,
+                }
+            }
+        }
+
+        impl core::fmt::Display for $state {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Self::ALG_NAME)
+            }
+        }
+
+        impl Update for $state {
+            fn update(&mut self, input: impl AsRef<[u8]>) {
+                let s = &mut self.state;
+                self.buffer
+                    .input_blocks(input.as_ref(), |b| s.absorb_blocks(b));
+            }
+        }
+
+        impl ExtendableOutputDirty for $state {
+            type Reader = Sha3XofReader;
+
+            fn finalize_xof_dirty(&mut self) -> Sha3XofReader {
+                // the domain byte is only known at run time, so it is added
+                // on top of zero padding
+                let pos = self.buffer.position();
+                let block = self
+                    .buffer
+                    .pad_with::<ZeroPadding>()
+                    .expect("we never use input_lazy");
+                block[pos] ^= self.domain_sep;
+                block[$rate::to_usize() - 1] ^= 0x80;
+                self.state.absorb_block(block);
+                Sha3XofReader::new(self.state.clone(), $rate::to_usize())
+            }
+        }
+
+        impl Reset for $state {
+            fn reset(&mut self) {
+                self.state = Sha3State::with_rounds(TURBO_SHAKE_ROUND_COUNT);
+                self.buffer.reset();
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl Drop for $state {
+            fn drop(&mut self) {
+                crate::state::zeroize_buffer(&mut self.buffer);
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize_crate::ZeroizeOnDrop for $state {}
+
+        opaque_debug::implement!($state);
+        digest::impl_write!($state);
+    };
+}
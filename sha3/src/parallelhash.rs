@@ -0,0 +1,148 @@
+//! ParallelHash, the block-parallel hashing function of
+//! [NIST SP 800-185][1] §6.
+//!
+//! The message is split into blocks of `B` bytes which are hashed
+//! independently with cSHAKE, and the block hashes are then hashed together.
+//! The blocks are processed one after another here, but since they do not
+//! depend on each other they could be hashed on separate threads or with
+//! multi-lane Keccak implementations without changing the output.
+//!
+//! [1]: https://doi.org/10.6028/NIST.SP.800-185
+
+use crate::cshake::{left_encode, right_encode, CShake128, CShake256};
+use crate::reader::Sha3XofReader;
+use crate::{Shake128, Shake256};
+use digest::consts::{U32, U64};
+use digest::generic_array::GenericArray;
+use digest::{ExtendableOutputDirty, Reset, Update, XofReader};
+
+macro_rules! parallel_hash_impl {
+    (
+        $state:ident, $cshake:ident, $leaf:ident, $leaf_size:ident,
+        $alg_name:expr, $doc:expr
+    ) => {
+        #[doc=$doc]
+        #[derive(Clone)]
+        pub struct $state {
+            outer: $cshake,
+            // cSHAKE with empty `N` and `S` is SHAKE
+            leaf: $leaf,
+            leaf_len: usize,
+            block_size: usize,
+            block_count: u64,
+        }
+
+        impl $state {
+            /// Canonical name of the algorithm, e.g. for logging.
+            pub const ALG_NAME: &'static str = $alg_name;
+
+            /// Create a hasher with a block size of `block_size` bytes
+            /// (`B`) and the customization string `customization` (`S`).
+            ///
+            /// # Panics
+            /// If `block_size` is zero.
+            pub fn new(block_size: usize, customization: &[u8]) -> Self {
+                assert!(block_size != 0, "block size must not be zero");
+                let mut outer = $cshake::new(b"ParallelHash", customization);
+                let mut buf = [0u8; 9];
+                outer.update(left_encode(block_size as u64, &mut buf));
+                $state {
+                    outer,
+                    leaf: Default::default(),
+                    leaf_len: 0,
+                    block_size,
+                    block_count: 0,
+                }
+            }
+
+            /// Finalize the hash into `out`, whose length determines the
+            /// output length `L`.
+            pub fn finalize_into(self, out: &mut [u8]) {
+                let output_bits = 8 * out.len() as u64;
+                self.finalize_with_len(output_bits).read(out);
+            }
+
+            /// Finalize the hash as the ParallelHashXOF variant, whose
+            /// output is not bound to a length.
+            pub fn finalize_xof(self) -> Sha3XofReader {
+                self.finalize_with_len(0)
+            }
+
+            fn finalize_with_len(mut self, output_bits: u64) -> Sha3XofReader {
+                if self.leaf_len != 0 {
+                    self.finish_block();
+                }
+                let mut buf = [0u8; 9];
+                self.outer.update(right_encode(self.block_count, &mut buf));
+                self.outer.update(right_encode(output_bits, &mut buf));
+                self.outer.finalize_xof_dirty()
+            }
+
+            fn finish_block(&mut self) {
+                let mut leaf_hash = GenericArray::<u8, $leaf_size>::default();
+                self.leaf.finalize_xof_dirty().read(&mut leaf_hash);
+                Reset::reset(&mut self.leaf);
+                self.outer.update(&leaf_hash);
+                self.leaf_len = 0;
+                self.block_count += 1;
+            }
+        }
+
+        impl core::fmt::Display for $state {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Self::ALG_NAME)
+            }
+        }
+
+        impl Update for $state {
+            fn update(&mut self, input: impl AsRef<[u8]>) {
+                let mut input = input.as_ref();
+                while !input.is_empty() {
+                    let n = core::cmp::min(self.block_size - self.leaf_len, input.len());
+                    let (block, rest) = input.split_at(n);
+                    self.leaf.update(block);
+                    self.leaf_len += n;
+                    input = rest;
+                    if self.leaf_len == self.block_size {
+                        self.finish_block();
+                    }
+                }
+            }
+        }
+
+        impl Reset for $state {
+            fn reset(&mut self) {
+                Reset::reset(&mut self.outer);
+                let mut buf = [0u8; 9];
+                self.outer
+                    .update(left_encode(self.block_size as u64, &mut buf));
+                Reset::reset(&mut self.leaf);
+                self.leaf_len = 0;
+                self.block_count = 0;
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize_crate::ZeroizeOnDrop for $state {}
+
+        opaque_debug::implement!($state);
+        digest::impl_write!($state);
+    };
+}
+
+parallel_hash_impl!(
+    ParallelHash128,
+    CShake128,
+    Shake128,
+    U32,
+    "ParallelHash128",
+    "ParallelHash128 hash function."
+);
+parallel_hash_impl!(
+    ParallelHash256,
+    CShake256,
+    Shake256,
+    U64,
+    "ParallelHash256",
+    "ParallelHash256 hash function."
+);
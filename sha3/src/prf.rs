@@ -0,0 +1,76 @@
+//! HMAC-based PRF using the [HKDF-Expand][1] construction.
+//!
+//! This is a thin layer over the [`hmac`] and [`hkdf`] crates with SHA-3
+//! pre-wired, e.g. for TLS 1.3 cipher suites based on SHA-3. The output
+//! length is fixed by the `N` type parameter.
+//!
+//! ```rust
+//! use sha3::prf::PrfSha3_256;
+//!
+//! let prf = PrfSha3_256::new(Some(b"salt"), b"input key material");
+//! let okm = prf.expand(b"context").unwrap();
+//! assert_eq!(okm.len(), 32);
+//! ```
+//!
+//! [1]: https://tools.ietf.org/html/rfc5869#section-2.3
+use crate::{Sha3_256, Sha3_512};
+use core::marker::PhantomData;
+use digest::consts::{U32, U64};
+use digest::generic_array::{ArrayLength, GenericArray};
+use digest::{BlockInput, FixedOutput, Reset, Update};
+
+pub use hkdf::{InvalidLength, InvalidPrkLength};
+
+/// HKDF instance over SHA3-256.
+pub type HkdfSha3_256 = hkdf::Hkdf<Sha3_256>;
+/// HKDF instance over SHA3-512.
+pub type HkdfSha3_512 = hkdf::Hkdf<Sha3_512>;
+
+/// PRF over SHA3-256 with 32 bytes of output.
+pub type PrfSha3_256 = Prf<Sha3_256, U32>;
+/// PRF over SHA3-512 with 64 bytes of output.
+pub type PrfSha3_512 = Prf<Sha3_512, U64>;
+
+/// PRF producing `N` bytes of output keyed by an HKDF pseudorandom key.
+#[derive(Clone)]
+pub struct Prf<H, N>
+where
+    H: Update + BlockInput + FixedOutput + Reset + Default + Clone,
+    N: ArrayLength<u8>,
+{
+    hkdf: hkdf::Hkdf<H>,
+    output: PhantomData<N>,
+}
+
+impl<H, N> Prf<H, N>
+where
+    H: Update + BlockInput + FixedOutput + Reset + Default + Clone,
+    N: ArrayLength<u8>,
+{
+    /// Derive the PRF key from `ikm` and an optional `salt` using
+    /// HKDF-Extract.
+    pub fn new(salt: Option<&[u8]>, ikm: &[u8]) -> Self {
+        Prf {
+            hkdf: hkdf::Hkdf::new(salt, ikm),
+            output: PhantomData,
+        }
+    }
+
+    /// Create the PRF from an existing pseudorandom key, skipping
+    /// HKDF-Extract.
+    pub fn from_prk(prk: &[u8]) -> Result<Self, InvalidPrkLength> {
+        Ok(Prf {
+            hkdf: hkdf::Hkdf::from_prk(prk)?,
+            output: PhantomData,
+        })
+    }
+
+    /// Evaluate the PRF on `info` using HKDF-Expand.
+    ///
+    /// Fails if `N` exceeds 255 times the hash output size.
+    pub fn expand(&self, info: &[u8]) -> Result<GenericArray<u8, N>, InvalidLength> {
+        let mut okm = GenericArray::default();
+        self.hkdf.expand(info, &mut okm)?;
+        Ok(okm)
+    }
+}
@@ -1,11 +1,14 @@
-use crate::state::Sha3State;
-use digest::XofReader;
+use crate::state::{serialize_keccak_state, Sha3State, XOF_READER_SNAPSHOT_LEN};
+use digest::generic_array::{ArrayLength, GenericArray};
+use digest::{Reset, XofReader};
 #[cfg(feature = "std")]
 use std::io;
 
 /// Reader state for extracting extendable output.
 pub struct Sha3XofReader {
     state: Sha3State,
+    // state right after absorption, restored on reset
+    initial_state: Sha3State,
     rate: usize,
     pos: usize,
 }
@@ -13,11 +16,83 @@ pub struct Sha3XofReader {
 impl Sha3XofReader {
     pub(crate) fn new(state: Sha3State, rate: usize) -> Self {
         Sha3XofReader {
+            initial_state: state.clone(),
             state,
             rate,
             pos: 0,
         }
     }
+
+    /// Advance the reader by `n` bytes without producing them.
+    ///
+    /// Whole blocks are skipped by applying the permutation without copying
+    /// any output.
+    pub fn skip(&mut self, n: u64) {
+        let rem = (self.rate - self.pos) as u64;
+        if n < rem {
+            self.pos += n as usize;
+            return;
+        }
+        self.state.apply_f();
+        let n = n - rem;
+        let rate = self.rate as u64;
+        self.skip_blocks(n / rate);
+        self.pos = (n % rate) as usize;
+    }
+
+    fn skip_blocks(&mut self, n: u64) {
+        for _ in 0..n {
+            self.state.apply_f();
+        }
+    }
+
+    /// Serialize the reader, so that reading can be resumed from the
+    /// current position, see [`XOF_READER_SNAPSHOT_LEN`].
+    pub fn serialize_state(&self) -> [u8; XOF_READER_SNAPSHOT_LEN] {
+        let mut out = [0u8; XOF_READER_SNAPSHOT_LEN];
+        let (state, rest) = out.split_at_mut(200);
+        let (initial_state, rest) = rest.split_at_mut(200);
+        state.copy_from_slice(&serialize_keccak_state(&self.state.state));
+        initial_state.copy_from_slice(&serialize_keccak_state(&self.initial_state.state));
+        rest[0] = self.state.round_count() as u8;
+        rest[1] = self.rate as u8;
+        rest[2] = self.pos as u8;
+        out
+    }
+
+    /// Restore a reader from a snapshot created by `serialize_state`.
+    ///
+    /// Returns `None` if `bytes` is not [`XOF_READER_SNAPSHOT_LEN`] bytes
+    /// long or is malformed.
+    pub fn deserialize_state(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != XOF_READER_SNAPSHOT_LEN {
+            return None;
+        }
+        let (state, rest) = bytes.split_at(200);
+        let (initial_state, rest) = rest.split_at(200);
+        let (round_count, rate, pos) = (rest[0] as usize, rest[1] as usize, rest[2] as usize);
+        if round_count == 0 || round_count > 24 {
+            return None;
+        }
+        // rates of SHA3-512, SHA3-384, SHA3-256, SHA3-224 and SHAKE128
+        match rate {
+            72 | 104 | 136 | 144 | 168 if pos < rate => {}
+            _ => return None,
+        }
+        Some(Sha3XofReader {
+            state: Sha3State::from_bytes(state, round_count),
+            initial_state: Sha3State::from_bytes(initial_state, round_count),
+            rate,
+            pos,
+        })
+    }
+
+    /// Read the next `N` bytes of output into an array.
+    pub fn read_array<N: ArrayLength<u8>>(&mut self) -> GenericArray<u8, N> {
+        let mut buf = GenericArray::default();
+        self.read(&mut buf);
+        buf
+    }
 }
 
 impl XofReader for Sha3XofReader {
@@ -57,6 +132,29 @@ impl XofReader for Sha3XofReader {
     }
 }
 
+impl Reset for Sha3XofReader {
+    /// Rewind the reader to the start of the output.
+    fn reset(&mut self) {
+        self.state = self.initial_state.clone();
+        self.pos = 0;
+    }
+}
+
+/// Overwrites the current and initial states, the rate and the position
+/// with zeros.
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::Zeroize for Sha3XofReader {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+        self.initial_state.zeroize();
+        self.rate.zeroize();
+        self.pos.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::ZeroizeOnDrop for Sha3XofReader {}
+
 #[cfg(feature = "std")]
 impl io::Read for Sha3XofReader {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
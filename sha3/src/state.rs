@@ -1,13 +1,196 @@
+//! Keccak state serialization, e.g. for checkpointing a hash computation.
+#[cfg(feature = "zeroize")]
+use block_buffer::BlockBuffer;
 use core::convert::TryInto;
+use digest::generic_array::{ArrayLength, GenericArray};
+
+#[cfg(all(target_arch = "aarch64", target_feature = "sha3"))]
+pub(crate) use crate::aarch64::f1600;
+#[cfg(all(feature = "arm32", target_arch = "arm"))]
+pub(crate) use crate::arm32::f1600;
+#[cfg(all(feature = "avx512", any(target_arch = "x86", target_arch = "x86_64")))]
+pub(crate) use crate::avx512::f1600;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+pub(crate) use crate::wasm::f1600;
+#[cfg(not(any(
+    all(feature = "arm32", target_arch = "arm"),
+    all(target_arch = "wasm32", target_feature = "simd128"),
+    all(target_arch = "aarch64", target_feature = "sha3"),
+    all(feature = "avx512", any(target_arch = "x86", target_arch = "x86_64"))
+)))]
+pub(crate) use keccak::f1600;
 
 const PLEN: usize = 25;
+const KECCAK_F_ROUND_COUNT: usize = 24;
+
+/// Length of a hasher snapshot created by e.g. `Sha3_256::serialize_state`.
+///
+/// Like the sponge instance of the Keccak reference implementation, this is
+/// the state with any buffered input already XORed into it, followed by one
+/// byte with the number of buffered bytes.
+pub const SHA3_SNAPSHOT_LEN: usize = 8 * PLEN + 1;
+
+/// Length of a snapshot created by `Sha3XofReader::serialize_state`: the
+/// current and initial states, the round count, the rate and the position in
+/// the current block.
+pub const XOF_READER_SNAPSHOT_LEN: usize = 2 * 8 * PLEN + 3;
+
+/// Serialize a Keccak-f[1600] state in the layout used by the Keccak
+/// reference implementation: 25 lanes as little-endian 64-bit words.
+pub fn serialize_keccak_state(state: &[u64; PLEN]) -> [u8; 8 * PLEN] {
+    let mut bytes = [0u8; 8 * PLEN];
+    for (chunk, lane) in bytes.chunks_exact_mut(8).zip(state.iter()) {
+        chunk.copy_from_slice(&lane.to_le_bytes());
+    }
+    bytes
+}
+
+/// Inverse of [`serialize_keccak_state`].
+pub fn deserialize_keccak_state(bytes: &[u8; 8 * PLEN]) -> [u64; PLEN] {
+    let mut state = [0u64; PLEN];
+    for (lane, chunk) in state.iter_mut().zip(bytes.chunks_exact(8)) {
+        *lane = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    state
+}
+
+/// Snapshot of a Keccak state in the reference implementation's binary
+/// format, see [`serialize_keccak_state`].
+///
+/// Obtained from a hasher with e.g. `Sha3_256::checkpoint` and restored with
+/// `Sha3_256::from_checkpoint`.
+#[derive(Clone, Copy)]
+pub struct Sha3StateCheckpoint([u8; 8 * PLEN]);
+
+impl Sha3StateCheckpoint {
+    /// Serialized state bytes.
+    pub fn as_bytes(&self) -> &[u8; 8 * PLEN] {
+        &self.0
+    }
+}
+
+impl From<[u8; 8 * PLEN]> for Sha3StateCheckpoint {
+    fn from(bytes: [u8; 8 * PLEN]) -> Self {
+        Sha3StateCheckpoint(bytes)
+    }
+}
 
-#[derive(Clone, Default)]
+impl From<&Sha3State> for Sha3StateCheckpoint {
+    fn from(state: &Sha3State) -> Self {
+        Sha3StateCheckpoint(serialize_keccak_state(&state.state))
+    }
+}
+
+impl From<&Sha3StateCheckpoint> for Sha3State {
+    fn from(checkpoint: &Sha3StateCheckpoint) -> Self {
+        Sha3State {
+            state: deserialize_keccak_state(&checkpoint.0),
+            ..Default::default()
+        }
+    }
+}
+
+/// Keccak-p[1600] sponge state.
+#[derive(Clone)]
 pub(crate) struct Sha3State {
     pub state: [u64; PLEN],
+    round_count: usize,
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::Zeroize for Sha3State {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+        self.round_count.zeroize();
+    }
+}
+
+/// Overwrites the Keccak state with zeros, which also covers every input
+/// absorbed so far.
+#[cfg(feature = "zeroize")]
+impl Drop for Sha3State {
+    fn drop(&mut self) {
+        zeroize_crate::Zeroize::zeroize(self);
+    }
+}
+
+/// Overwrite input which does not fill a whole block yet with zeros.
+#[cfg(feature = "zeroize")]
+pub(crate) fn zeroize_buffer<N: ArrayLength<u8>>(buffer: &mut BlockBuffer<N>) {
+    use block_buffer::block_padding::ZeroPadding;
+    use zeroize_crate::Zeroize;
+
+    // the buffer is never full, so padding can not fail
+    if let Ok(block) = buffer.pad_with::<ZeroPadding>() {
+        block.as_mut_slice().zeroize();
+    }
+    buffer.reset();
+}
+
+impl Default for Sha3State {
+    fn default() -> Self {
+        Self::with_rounds(KECCAK_F_ROUND_COUNT)
+    }
 }
 
 impl Sha3State {
+    /// Empty state permuted with `round_count` rounds of Keccak-p[1600]
+    /// instead of the full Keccak-f[1600].
+    pub(crate) fn with_rounds(round_count: usize) -> Self {
+        Sha3State {
+            state: [0; PLEN],
+            round_count,
+        }
+    }
+
+    pub(crate) fn round_count(&self) -> usize {
+        self.round_count
+    }
+
+    /// State deserialized from the first `8 * PLEN` bytes of `bytes`.
+    pub(crate) fn from_bytes(bytes: &[u8], round_count: usize) -> Self {
+        let mut buf = [0u8; 8 * PLEN];
+        buf.copy_from_slice(&bytes[..8 * PLEN]);
+        Sha3State {
+            state: deserialize_keccak_state(&buf),
+            round_count,
+        }
+    }
+
+    /// Snapshot of the state with the partial block `buffered` absorbed
+    /// but not yet permuted.
+    pub(crate) fn serialize_with_buffer(&self, buffered: &[u8]) -> [u8; SHA3_SNAPSHOT_LEN] {
+        let mut out = [0u8; SHA3_SNAPSHOT_LEN];
+        out[..8 * PLEN].copy_from_slice(&serialize_keccak_state(&self.state));
+        for (o, b) in out.iter_mut().zip(buffered) {
+            *o ^= *b;
+        }
+        out[8 * PLEN] = buffered.len() as u8;
+        out
+    }
+
+    /// Inverse of [`serialize_with_buffer`](Self::serialize_with_buffer),
+    /// returning the state and the number of buffered bytes.
+    pub(crate) fn deserialize_with_buffer(bytes: &[u8], rate: usize) -> Option<(Self, usize)> {
+        if bytes.len() != SHA3_SNAPSHOT_LEN {
+            return None;
+        }
+        let pos = bytes[8 * PLEN] as usize;
+        if pos >= rate {
+            return None;
+        }
+        Some((Self::from_bytes(bytes, KECCAK_F_ROUND_COUNT), pos))
+    }
+
+    #[inline(always)]
+    fn permute(&mut self) {
+        if self.round_count == KECCAK_F_ROUND_COUNT {
+            f1600(&mut self.state);
+        } else {
+            keccak::keccak_p(&mut self.state, self.round_count);
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn absorb_block(&mut self, block: &[u8]) {
         debug_assert_eq!(block.len() % 8, 0);
@@ -30,7 +213,32 @@ impl Sha3State {
             }
         }
 
-        keccak::f1600(&mut self.state);
+        self.permute();
+    }
+
+    /// Absorb multiple blocks without returning to the caller in between.
+    #[inline]
+    pub(crate) fn absorb_blocks<N: ArrayLength<u8>>(&mut self, blocks: &[GenericArray<u8, N>]) {
+        let mut chunks = blocks.chunks_exact(4);
+        for chunk in &mut chunks {
+            self.absorb_4x(chunk);
+        }
+        for block in chunks.remainder() {
+            self.absorb_block(block);
+        }
+    }
+
+    /// Absorb four consecutive blocks.
+    ///
+    /// The permutations are inherently sequential, but unrolling them lets
+    /// the next blocks be loaded while the current one is being permuted.
+    #[inline(always)]
+    pub(crate) fn absorb_4x<N: ArrayLength<u8>>(&mut self, blocks: &[GenericArray<u8, N>]) {
+        debug_assert_eq!(blocks.len(), 4);
+        self.absorb_block(&blocks[0]);
+        self.absorb_block(&blocks[1]);
+        self.absorb_block(&blocks[2]);
+        self.absorb_block(&blocks[3]);
     }
 
     #[inline(always)]
@@ -54,6 +262,6 @@ impl Sha3State {
 
     #[inline(always)]
     pub(crate) fn apply_f(&mut self) {
-        keccak::f1600(&mut self.state);
+        self.permute();
     }
 }
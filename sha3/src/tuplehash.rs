@@ -0,0 +1,88 @@
+//! TupleHash, the tuple hashing function of [NIST SP 800-185][1] §5.
+//!
+//! Every element is framed with `encode_string`, so tuples such as
+//! `("abc", "d")` and `("ab", "cd")` hash to unrelated outputs.
+//!
+//! [1]: https://doi.org/10.6028/NIST.SP.800-185
+
+use crate::cshake::{encode_string, right_encode, CShake128, CShake256};
+use crate::reader::Sha3XofReader;
+use digest::{ExtendableOutputDirty, Reset, Update, XofReader};
+
+macro_rules! tuple_hash_impl {
+    ($state:ident, $cshake:ident, $alg_name:expr, $doc:expr) => {
+        #[doc=$doc]
+        #[derive(Clone)]
+        pub struct $state {
+            inner: $cshake,
+        }
+
+        impl $state {
+            /// Canonical name of the algorithm, e.g. for logging.
+            pub const ALG_NAME: &'static str = $alg_name;
+
+            /// Create a hasher with the customization string
+            /// `customization` (`S`).
+            pub fn new(customization: &[u8]) -> Self {
+                $state {
+                    inner: $cshake::new(b"TupleHash", customization),
+                }
+            }
+
+            /// Append `data` to the tuple as a single element.
+            pub fn input(&mut self, data: &[u8]) {
+                let inner = &mut self.inner;
+                encode_string(data, &mut |d| inner.update(d));
+            }
+
+            /// Finalize the hash into `out`, whose length determines the
+            /// output length `L`.
+            pub fn finalize_into(self, out: &mut [u8]) {
+                let output_bits = 8 * out.len() as u64;
+                self.finalize_with_len(output_bits).read(out);
+            }
+
+            /// Finalize the hash as the TupleHashXOF variant, whose output
+            /// is not bound to a length.
+            pub fn finalize_xof(self) -> Sha3XofReader {
+                self.finalize_with_len(0)
+            }
+
+            fn finalize_with_len(mut self, output_bits: u64) -> Sha3XofReader {
+                let mut buf = [0u8; 9];
+                self.inner.update(right_encode(output_bits, &mut buf));
+                self.inner.finalize_xof_dirty()
+            }
+        }
+
+        impl core::fmt::Display for $state {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(Self::ALG_NAME)
+            }
+        }
+
+        impl Reset for $state {
+            fn reset(&mut self) {
+                Reset::reset(&mut self.inner);
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize_crate::ZeroizeOnDrop for $state {}
+
+        opaque_debug::implement!($state);
+    };
+}
+
+tuple_hash_impl!(
+    TupleHash128,
+    CShake128,
+    "TupleHash128",
+    "TupleHash128 hash function for tuples of byte strings."
+);
+tuple_hash_impl!(
+    TupleHash256,
+    CShake256,
+    "TupleHash256",
+    "TupleHash256 hash function for tuples of byte strings."
+);
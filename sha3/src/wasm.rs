@@ -0,0 +1,110 @@
+//! Keccak-f[1600] permutation using WebAssembly SIMD128.
+//!
+//! θ and χ operate on pairs of lanes packed into `v128` registers. ρ and π
+//! move lanes individually and stay scalar, since SIMD128 shifts apply the
+//! same count to both halves of a vector.
+//!
+//! Only compiled when `simd128` is enabled at build time (e.g. with
+//! `RUSTFLAGS="-C target-feature=+simd128"`), which requires Rust 1.54+.
+use core::arch::wasm32::*;
+
+const PLEN: usize = 25;
+
+const RC: [u64; 24] = [
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_8082,
+    0x8000_0000_0000_808A,
+    0x8000_0000_8000_8000,
+    0x0000_0000_0000_808B,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8009,
+    0x0000_0000_0000_008A,
+    0x0000_0000_0000_0088,
+    0x0000_0000_8000_8009,
+    0x0000_0000_8000_000A,
+    0x0000_0000_8000_808B,
+    0x8000_0000_0000_008B,
+    0x8000_0000_0000_8089,
+    0x8000_0000_0000_8003,
+    0x8000_0000_0000_8002,
+    0x8000_0000_0000_0080,
+    0x0000_0000_0000_800A,
+    0x8000_0000_8000_000A,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8080,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8008,
+];
+
+/// ρ rotation offsets, indexed by `x + 5 * y`.
+const RHO: [u32; PLEN] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+#[inline(always)]
+fn pair(a: u64, b: u64) -> v128 {
+    u64x2(a, b)
+}
+
+#[inline(always)]
+fn rotl1(v: v128) -> v128 {
+    v128_or(u64x2_shl(v, 1), u64x2_shr(v, 63))
+}
+
+#[inline(always)]
+fn store(v: v128, out: &mut [u64]) {
+    out[0] = u64x2_extract_lane::<0>(v);
+    out[1] = u64x2_extract_lane::<1>(v);
+}
+
+pub(crate) fn f1600(a: &mut [u64; PLEN]) {
+    for &rc in RC.iter() {
+        // θ
+        let mut c01 = pair(a[0], a[1]);
+        let mut c23 = pair(a[2], a[3]);
+        let mut c4 = a[4];
+        for row in a[5..].chunks_exact(5) {
+            c01 = v128_xor(c01, pair(row[0], row[1]));
+            c23 = v128_xor(c23, pair(row[2], row[3]));
+            c4 ^= row[4];
+        }
+        let c0 = u64x2_extract_lane::<0>(c01);
+        let c3 = u64x2_extract_lane::<1>(c23);
+        let c12 = i64x2_shuffle::<1, 2>(c01, c23);
+        let d01 = v128_xor(pair(c4, c0), rotl1(c12));
+        let d23 = v128_xor(c12, rotl1(pair(c3, c4)));
+        let d4 = c3 ^ c0.rotate_left(1);
+        for row in a.chunks_exact_mut(5) {
+            let r01 = v128_xor(pair(row[0], row[1]), d01);
+            let r23 = v128_xor(pair(row[2], row[3]), d23);
+            store(r01, &mut row[0..2]);
+            store(r23, &mut row[2..4]);
+            row[4] ^= d4;
+        }
+
+        // ρ and π
+        let mut b = [0u64; PLEN];
+        for y in 0..5 {
+            for x in 0..5 {
+                let i = x + 5 * y;
+                b[y + 5 * ((2 * x + 3 * y) % 5)] = a[i].rotate_left(RHO[i]);
+            }
+        }
+
+        // χ
+        for (row, b) in a.chunks_exact_mut(5).zip(b.chunks_exact(5)) {
+            let b01 = pair(b[0], b[1]);
+            let b12 = pair(b[1], b[2]);
+            let b23 = pair(b[2], b[3]);
+            let b34 = pair(b[3], b[4]);
+            let b40 = pair(b[4], b[0]);
+            store(v128_xor(b01, v128_andnot(b23, b12)), &mut row[0..2]);
+            store(v128_xor(b23, v128_andnot(b40, b34)), &mut row[2..4]);
+            row[4] = b[4] ^ (!b[0] & b[1]);
+        }
+
+        // ι
+        a[0] ^= rc;
+    }
+}
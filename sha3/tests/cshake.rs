@@ -0,0 +1,91 @@
+use digest::{ExtendableOutput, Update, XofReader};
+use hex_literal::hex;
+use sha3::{CShake128, CShake256, Shake128, Shake256};
+
+// Samples from NIST SP 800-185, Appendix A
+#[test]
+fn cshake128_samples() {
+    let mut out = [0u8; 32];
+
+    let mut h = CShake128::new(b"", b"Email Signature");
+    h.update(b"\x00\x01\x02\x03");
+    h.finalize_xof().read(&mut out);
+    assert_eq!(
+        out,
+        hex!("c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5")
+    );
+
+    let data: Vec<u8> = (0..200).collect();
+    let mut h = CShake128::new(b"", b"Email Signature");
+    h.update(&data);
+    h.finalize_xof().read(&mut out);
+    assert_eq!(
+        out,
+        hex!("c5221d50e4f822d96a2e8881a961420f294b7b24fe3d2094baed2c6524cc166b")
+    );
+}
+
+#[test]
+fn cshake256_samples() {
+    let mut out = [0u8; 64];
+
+    let mut h = CShake256::new(b"", b"Email Signature");
+    h.update(b"\x00\x01\x02\x03");
+    h.finalize_xof().read(&mut out);
+    assert_eq!(
+        out[..],
+        hex!(
+            "d008828e2b80ac9d2218ffee1d070c48b8e4c87bff32c9699d5b6896eee0edd1
+            64020e2be0560858d9c00c037e34a96937c561a74c412bb4c746469527281c8c"
+        )[..]
+    );
+
+    let data: Vec<u8> = (0..200).collect();
+    let mut h = CShake256::new(b"", b"Email Signature");
+    h.update(&data);
+    h.finalize_xof().read(&mut out);
+    assert_eq!(
+        out[..],
+        hex!(
+            "07dc27b11e51fbac75bc7b3c1d983e8b4b85fb1defaf218912ac86430273091
+            727f42b17ed1df63e8ec118f04b23633c1dfb1574c8fb55cb45da8e25afb092bb"
+        )[..]
+    );
+}
+
+#[test]
+fn cshake_without_customization_is_shake() {
+    let mut expected = [0u8; 100];
+    let mut out = [0u8; 100];
+
+    let mut h = Shake128::default();
+    h.update(b"abc");
+    h.finalize_xof().read(&mut expected);
+    let mut h = CShake128::new(b"", b"");
+    h.update(b"abc");
+    h.finalize_xof().read(&mut out);
+    assert_eq!(out[..], expected[..]);
+
+    let mut h = Shake256::default();
+    h.update(b"abc");
+    h.finalize_xof().read(&mut expected);
+    let mut h = CShake256::new(b"", b"");
+    h.update(b"abc");
+    h.finalize_xof().read(&mut out);
+    assert_eq!(out[..], expected[..]);
+}
+
+#[test]
+fn cshake_reset() {
+    let mut h = CShake128::new(b"", b"Email Signature");
+    h.update(b"garbage");
+    digest::Reset::reset(&mut h);
+    h.update(b"\x00\x01\x02\x03");
+
+    let mut out = [0u8; 32];
+    h.finalize_xof().read(&mut out);
+    assert_eq!(
+        out,
+        hex!("c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5")
+    );
+}
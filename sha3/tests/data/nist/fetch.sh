@@ -0,0 +1,15 @@
+#!/bin/sh
+# Download the NIST CAVP SHA-3 and SHAKE byte-oriented test vectors into
+# this directory. Run `cargo test --features nist-vectors --test nist_kat`
+# afterwards.
+set -eu
+
+BASE=https://csrc.nist.gov/CSRC/media/Projects/Cryptographic-Algorithm-Validation-Program/documents/sha3
+DIR=$(cd "$(dirname "$0")" && pwd)
+TMP=$(mktemp -d)
+trap 'rm -rf "$TMP"' EXIT
+
+for zip in sha-3bytetestvectors.zip shakebytetestvectors.zip; do
+    curl -fsSL -o "$TMP/$zip" "$BASE/$zip"
+    unzip -q -j -o "$TMP/$zip" '*.rsp' -d "$DIR"
+done
@@ -0,0 +1,125 @@
+//! HMAC-SHA-3 test vectors from the NIST example values:
+//! <https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values>
+#![cfg(feature = "hmac")]
+
+use hex_literal::hex;
+use sha3::hmac::{Mac, NewMac};
+
+/// The examples use the key bytes 0, 1, 2, ... up to the given length.
+fn check<M: Mac + NewMac>(key_len: usize, data: &[u8], tag: &[u8]) {
+    let key: Vec<u8> = (0..key_len).map(|i| i as u8).collect();
+
+    let mut mac = M::new_from_slice(&key).unwrap();
+    mac.update(data);
+    assert_eq!(mac.finalize().into_bytes()[..], tag[..]);
+
+    let mut mac = M::new_from_slice(&key).unwrap();
+    mac.update(data);
+    mac.verify(tag).unwrap();
+}
+
+#[test]
+fn hmac_sha3_224() {
+    check::<sha3::HmacSha3_224>(
+        28,
+        b"Sample message for keylen<blocklen",
+        &hex!("332cfd59347fdb8e576e77260be4aba2d6dc53117b3bfb52c6d18c04"),
+    );
+    check::<sha3::HmacSha3_224>(
+        144,
+        b"Sample message for keylen=blocklen",
+        &hex!("d8b733bcf66c644a12323d564e24dcf3fc75f231f3b67968359100c7"),
+    );
+    check::<sha3::HmacSha3_224>(
+        172,
+        b"Sample message for keylen>blocklen",
+        &hex!("078695eecc227c636ad31d063a15dd05a7e819a66ec6d8de1e193e59"),
+    );
+}
+
+#[test]
+fn hmac_sha3_256() {
+    check::<sha3::HmacSha3_256>(
+        32,
+        b"Sample message for keylen<blocklen",
+        &hex!("4fe8e202c4f058e8dddc23d8c34e467343e23555e24fc2f025d598f558f67205"),
+    );
+    check::<sha3::HmacSha3_256>(
+        136,
+        b"Sample message for keylen=blocklen",
+        &hex!("68b94e2e538a9be4103bebb5aa016d47961d4d1aa906061313b557f8af2c3faa"),
+    );
+    check::<sha3::HmacSha3_256>(
+        168,
+        b"Sample message for keylen>blocklen",
+        &hex!("9bcf2c238e235c3ce88404e813bd2f3a97185ac6f238c63d6229a00b07974258"),
+    );
+}
+
+#[test]
+fn hmac_sha3_384() {
+    check::<sha3::HmacSha3_384>(
+        48,
+        b"Sample message for keylen<blocklen",
+        &hex!(
+            "
+            d588a3c51f3f2d906e8298c1199aa8ff6296218127f6b38a90b6afe2c5617725
+            bc99987f79b22a557b6520db710b7f42
+        "
+        ),
+    );
+    check::<sha3::HmacSha3_384>(
+        104,
+        b"Sample message for keylen=blocklen",
+        &hex!(
+            "
+            a27d24b592e8c8cbf6d4ce6fc5bf62d8fc98bf2d486640d9eb8099e24047837f
+            5f3bffbe92dcce90b4ed5b1e7e44fa90
+        "
+        ),
+    );
+    check::<sha3::HmacSha3_384>(
+        152,
+        b"Sample message for keylen>blocklen",
+        &hex!(
+            "
+            e5ae4c739f455279368ebf36d4f5354c95aa184c899d3870e460ebc288ef1f94
+            70053f73f7c6da2a71bcaec38ce7d6ac
+        "
+        ),
+    );
+}
+
+#[test]
+fn hmac_sha3_512() {
+    check::<sha3::HmacSha3_512>(
+        64,
+        b"Sample message for keylen<blocklen",
+        &hex!(
+            "
+            4efd629d6c71bf86162658f29943b1c308ce27cdfa6db0d9c3ce81763f9cbce5
+            f7ebe9868031db1a8f8eb7b6b95e5c5e3f657a8996c86a2f6527e307f0213196
+        "
+        ),
+    );
+    check::<sha3::HmacSha3_512>(
+        72,
+        b"Sample message for keylen=blocklen",
+        &hex!(
+            "
+            544e257ea2a3e5ea19a590e6a24b724ce6327757723fe2751b75bf007d80f6b3
+            60744bf1b7a88ea585f9765b47911976d3191cf83c039f5ffab0d29cc9d9b6da
+        "
+        ),
+    );
+    check::<sha3::HmacSha3_512>(
+        136,
+        b"Sample message for keylen>blocklen",
+        &hex!(
+            "
+            5f464f5e5b7848e3885e49b2c385f0694985d0e38966242dc4a5fe3fea4b37d4
+            6b65ceced5dcf59438dd840bab22269f0ba7febdb9fcf74602a35666b2a32915
+        "
+        ),
+    );
+}
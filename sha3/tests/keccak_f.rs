@@ -0,0 +1,88 @@
+#![cfg(feature = "keccak-f")]
+
+use digest::{ExtendableOutput, Update, XofReader};
+use sha3::{keccak_f1600, Shake128, Shake256};
+
+#[test]
+fn keccak_f1600_zero_state() {
+    // from the Keccak team's `KeccakF-1600-IntermediateValues.txt`
+    let mut state = [0u64; 25];
+    keccak_f1600(&mut state);
+    assert_eq!(
+        state,
+        [
+            0xF1258F7940E1DDE7,
+            0x84D5CCF933C0478A,
+            0xD598261EA65AA9EE,
+            0xBD1547306F80494D,
+            0x8B284E056253D057,
+            0xFF97A42D7F8E6FD4,
+            0x90FEE5A0A44647C4,
+            0x8C5BDA0CD6192E76,
+            0xAD30A6F71B19059C,
+            0x30935AB7D08FFC64,
+            0xEB5AA93F2317D635,
+            0xA9A6E6260D712103,
+            0x81A57C16DBCF555F,
+            0x43B831CD0347C826,
+            0x01F22F1A11A5569F,
+            0x05E5635A21D9AE61,
+            0x64BEFEF28CC970F2,
+            0x613670957BC46611,
+            0xB87C5A554FD00ECB,
+            0x8C3EE88A1CCF32C8,
+            0x940C7922AE3A2614,
+            0x1841F924A2C509E4,
+            0x16F53526E70465C2,
+            0x75F644E97F30A13B,
+            0xEAF1FF7B5CECA249,
+        ]
+    );
+}
+
+/// Minimal SHAKE built directly on `keccak_f1600`.
+fn shake(rate: usize, msg: &[u8], out: &mut [u8]) {
+    let mut padded = msg.to_vec();
+    padded.push(0x1F);
+    padded.resize((msg.len() / rate + 1) * rate, 0);
+    *padded.last_mut().unwrap() |= 0x80;
+
+    let mut state = [0u64; 25];
+    for block in padded.chunks(rate) {
+        for (lane, word) in state.iter_mut().zip(block.chunks(8)) {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(word);
+            *lane ^= u64::from_le_bytes(buf);
+        }
+        keccak_f1600(&mut state);
+    }
+    for chunk in out.chunks_mut(rate) {
+        let bytes: Vec<u8> = state
+            .iter()
+            .flat_map(|l| l.to_le_bytes().to_vec())
+            .collect();
+        chunk.copy_from_slice(&bytes[..chunk.len()]);
+        keccak_f1600(&mut state);
+    }
+}
+
+#[test]
+fn keccak_f1600_matches_shake() {
+    for len in [0, 1, 135, 136, 167, 168, 500].iter() {
+        let msg: Vec<u8> = (0..*len).map(|i| i as u8).collect();
+
+        let mut expected = [0u8; 400];
+        let mut out = [0u8; 400];
+        let mut h = Shake128::default();
+        h.update(&msg);
+        h.finalize_xof().read(&mut expected);
+        shake(168, &msg, &mut out);
+        assert_eq!(out[..], expected[..]);
+
+        let mut h = Shake256::default();
+        h.update(&msg);
+        h.finalize_xof().read(&mut expected);
+        shake(136, &msg, &mut out);
+        assert_eq!(out[..], expected[..]);
+    }
+}
@@ -0,0 +1,103 @@
+//! Samples from NIST SP 800-185, Appendix B
+#![cfg(feature = "kmac")]
+
+use hex_literal::hex;
+use sha3::crypto_mac::{InvalidKeyLength, Mac};
+use sha3::{Kmac128, Kmac256};
+
+const KEY: [u8; 32] = hex!("404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f");
+const TAG: &[u8] = b"My Tagged Application";
+
+fn data200() -> Vec<u8> {
+    (0..200).collect()
+}
+
+#[test]
+fn kmac128_samples() {
+    let mut mac = Kmac128::new(&KEY, b"").unwrap();
+    mac.update(b"\x00\x01\x02\x03");
+    assert_eq!(
+        mac.finalize().into_bytes()[..],
+        hex!("e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e")
+    );
+
+    let mut mac = Kmac128::new(&KEY, TAG).unwrap();
+    mac.update(b"\x00\x01\x02\x03");
+    assert_eq!(
+        mac.finalize().into_bytes()[..],
+        hex!("3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5")
+    );
+
+    let mut mac = Kmac128::new(&KEY, TAG).unwrap();
+    mac.update(&data200());
+    mac.verify(&hex!(
+        "1f5b4e6cca02209e0dcb5ca635b89a15e271ecc760071dfd805faa38f9729230"
+    ))
+    .unwrap();
+}
+
+#[test]
+fn kmac256_samples() {
+    let mut mac = Kmac256::new(&KEY, TAG).unwrap();
+    mac.update(b"\x00\x01\x02\x03");
+    assert_eq!(
+        mac.finalize().into_bytes()[..],
+        hex!(
+            "20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7
+            f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd"
+        )[..]
+    );
+
+    let mut mac = Kmac256::new(&KEY, b"").unwrap();
+    mac.update(&data200());
+    assert_eq!(
+        mac.finalize().into_bytes()[..],
+        hex!(
+            "75358cf39e41494e949707927cee0af20a3ff553904c86b08f21cc414bcfd691
+            589d27cf5e15369cbbff8b9a4c2eb17800855d0235ff635da82533ec6b759b69"
+        )[..]
+    );
+
+    let mut mac = Kmac256::new(&KEY, TAG).unwrap();
+    mac.update(&data200());
+    assert_eq!(
+        mac.finalize().into_bytes()[..],
+        hex!(
+            "b58618f71f92e1d56c1b8c55ddd7cd188b97b4ca4d99831eb2699a837da2e4d9
+            70fbacfde50033aea585f1a2708510c32d07880801bd182898fe476876fc8965"
+        )[..]
+    );
+}
+
+#[test]
+fn kmac_output_length() {
+    // `L` is part of the input, so a short tag is not a truncated long one
+    let mut short = [0u8; 16];
+    let mut mac = Kmac128::new(&KEY, b"").unwrap();
+    mac.update(b"\x00\x01\x02\x03");
+    mac.finalize_into(&mut short);
+    assert_ne!(
+        short[..],
+        hex!("e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e")[..16]
+    );
+}
+
+#[test]
+fn kmac_reset() {
+    let mut mac = Kmac128::new(&KEY, TAG).unwrap();
+    mac.update(b"garbage");
+    mac.reset();
+    mac.update(b"\x00\x01\x02\x03");
+    assert_eq!(
+        mac.finalize().into_bytes()[..],
+        hex!("3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5")
+    );
+}
+
+#[test]
+fn kmac_key_length() {
+    assert!(Kmac128::new(&[0; 168], b"").is_ok());
+    assert_eq!(Kmac128::new(&[0; 169], b"").unwrap_err(), InvalidKeyLength);
+    assert!(Kmac256::new(&[0; 136], b"").is_ok());
+    assert_eq!(Kmac256::new(&[0; 137], b"").unwrap_err(), InvalidKeyLength);
+}
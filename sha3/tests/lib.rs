@@ -22,3 +22,288 @@ new_test!(sha3_512, "sha3_512", sha3::Sha3_512, digest_test);
 
 new_test!(shake128, "shake128", sha3::Shake128, xof_test);
 new_test!(shake256, "shake256", sha3::Shake256, xof_test);
+
+#[test]
+fn sha3_alg_names() {
+    assert_eq!(sha3::Keccak224::ALG_NAME, "Keccak-224");
+    assert_eq!(sha3::Keccak256::ALG_NAME, "Keccak-256");
+    assert_eq!(sha3::Keccak384::ALG_NAME, "Keccak-384");
+    assert_eq!(sha3::Keccak512::ALG_NAME, "Keccak-512");
+    assert_eq!(sha3::Keccak256Full::ALG_NAME, "Keccak-256-Full");
+    assert_eq!(sha3::Sha3_224::ALG_NAME, "SHA3-224");
+    assert_eq!(sha3::Sha3_256::ALG_NAME, "SHA3-256");
+    assert_eq!(sha3::Sha3_384::ALG_NAME, "SHA3-384");
+    assert_eq!(sha3::Sha3_512::ALG_NAME, "SHA3-512");
+    assert_eq!(sha3::Shake128::ALG_NAME, "SHAKE128");
+    assert_eq!(sha3::Shake256::ALG_NAME, "SHAKE256");
+    assert_eq!(sha3::TurboShake128::ALG_NAME, "TurboSHAKE128");
+    assert_eq!(sha3::TurboShake256::ALG_NAME, "TurboSHAKE256");
+    assert_eq!(sha3::CShake128::ALG_NAME, "cSHAKE128");
+    assert_eq!(sha3::CShake256::ALG_NAME, "cSHAKE256");
+    assert_eq!(sha3::TupleHash128::ALG_NAME, "TupleHash128");
+    assert_eq!(sha3::TupleHash256::ALG_NAME, "TupleHash256");
+    assert_eq!(sha3::ParallelHash128::ALG_NAME, "ParallelHash128");
+    assert_eq!(sha3::ParallelHash256::ALG_NAME, "ParallelHash256");
+}
+
+#[test]
+fn sha3_display() {
+    extern crate std;
+    use std::format;
+
+    assert_eq!(format!("{}", sha3::Sha3_256::default()), "SHA3-256");
+    assert_eq!(format!("{}", sha3::Shake128::default()), "SHAKE128");
+}
+
+#[test]
+fn shake_fixed() {
+    use digest::{Digest, ExtendableOutput, Update, XofReader};
+    use hex_literal::hex;
+    use sha3::digest::consts::U32;
+
+    let out = sha3::Shake128Fixed::<U32>::digest(b"");
+    assert_eq!(
+        out[..],
+        hex!("7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26")[..]
+    );
+    let mut xof = [0u8; 32];
+    sha3::Shake128::default().finalize_xof().read(&mut xof);
+    assert_eq!(out[..], xof[..]);
+    assert_eq!(sha3::Shake128_256::digest(b"")[..], xof[..]);
+
+    let out = sha3::Shake256_512::digest(b"abc");
+    assert_eq!(
+        out[..],
+        hex!(
+            "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739
+            d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4"
+        )[..]
+    );
+    let mut h = sha3::Shake256::default();
+    h.update(b"abc");
+    let mut xof = [0u8; 64];
+    h.finalize_xof().read(&mut xof);
+    assert_eq!(out[..], xof[..]);
+}
+
+#[test]
+#[cfg(feature = "cryptonight")]
+fn cryptonight_keccak() {
+    use digest::Digest;
+    use hex_literal::hex;
+
+    let out = sha3::CryptoNightKeccak::digest(b"");
+    assert_eq!(out.len(), 200);
+    assert_eq!(
+        out[..32],
+        hex!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")[..]
+    );
+}
+
+#[test]
+fn shake_reader_skip() {
+    use digest::{ExtendableOutput, Update, XofReader};
+
+    let mut h = sha3::Shake128::default();
+    h.update(b"skip test");
+    let mut full = [0u8; 1024];
+    h.clone().finalize_xof().read(&mut full);
+
+    // skip sizes around the rate of 168 bytes
+    for &k in [0, 1, 100, 167, 168, 169, 336, 500, 800].iter() {
+        let mut reader = h.clone().finalize_xof();
+        reader.skip(k as u64);
+        let mut buf = [0u8; 200];
+        reader.read(&mut buf);
+        assert_eq!(buf[..], full[k..k + 200], "skip {}", k);
+    }
+
+    // skipping in several steps from a partially read block
+    let mut reader = h.clone().finalize_xof();
+    let mut buf = [0u8; 10];
+    reader.read(&mut buf);
+    reader.skip(150);
+    reader.skip(200);
+    reader.read(&mut buf);
+    assert_eq!(buf[..], full[360..370]);
+}
+
+#[test]
+fn shake_reader_read_array() {
+    use digest::{ExtendableOutput, Update, XofReader};
+    use sha3::digest::consts::{U16, U32};
+
+    let mut h = sha3::Shake256::default();
+    h.update(b"abc");
+    let mut full = [0u8; 48];
+    h.clone().finalize_xof().read(&mut full);
+
+    let mut reader = h.finalize_xof();
+    let a = reader.read_array::<U16>();
+    let b = reader.read_array::<U32>();
+    assert_eq!(a[..], full[..16]);
+    assert_eq!(b[..], full[16..]);
+}
+
+#[test]
+fn shake_reader_reset() {
+    use digest::{ExtendableOutput, Reset, Update, XofReader};
+
+    let mut h = sha3::Shake128::default();
+    h.update(b"abc");
+    let mut full = [0u8; 400];
+    h.clone().finalize_xof().read(&mut full);
+
+    // rewind from the middle of the first block and from later blocks
+    let mut reader = h.finalize_xof();
+    for &k in [10, 168, 300].iter() {
+        let mut buf = [0u8; 400];
+        reader.read(&mut buf[..k]);
+        reader.reset();
+        reader.read(&mut buf);
+        assert_eq!(buf[..], full[..], "reset after {}", k);
+        reader.reset();
+    }
+}
+
+fn check_block_absorption<D: digest::Digest + Clone>() {
+    // long enough to go through the 4-block batches and their remainder
+    let mut data = [0u8; 2000];
+    for (i, b) in data.iter_mut().enumerate() {
+        *b = (i * 7) as u8;
+    }
+
+    let mut expected = D::new();
+    for b in data.iter() {
+        expected.update(core::slice::from_ref(b));
+    }
+    let expected = expected.finalize();
+
+    for &split in [0, 1, 71, 143, 1000].iter() {
+        let mut h = D::new();
+        h.update(&data[..split]);
+        h.update(&data[split..]);
+        assert_eq!(h.finalize(), expected);
+    }
+}
+
+#[test]
+fn absorb_blocks() {
+    check_block_absorption::<sha3::Keccak224>();
+    check_block_absorption::<sha3::Keccak256>();
+    check_block_absorption::<sha3::Keccak384>();
+    check_block_absorption::<sha3::Keccak512>();
+    check_block_absorption::<sha3::Keccak256Full>();
+    check_block_absorption::<sha3::Sha3_224>();
+    check_block_absorption::<sha3::Sha3_256>();
+    check_block_absorption::<sha3::Sha3_384>();
+    check_block_absorption::<sha3::Sha3_512>();
+    check_block_absorption::<sha3::Shake128_256>();
+    check_block_absorption::<sha3::Shake256_512>();
+}
+
+#[test]
+fn sha3_checkpoint_roundtrip() {
+    use sha3::state::{deserialize_keccak_state, serialize_keccak_state, Sha3StateCheckpoint};
+    use sha3::{Digest, Sha3_256};
+
+    let data = [0x5au8; 1000];
+    // 3 blocks of 136 bytes
+    let (head, tail) = data.split_at(408);
+
+    let mut hasher = Sha3_256::new();
+    hasher.update(&head[..100]);
+    assert!(hasher.checkpoint().is_none());
+    hasher.update(&head[100..]);
+    let bytes = *hasher.checkpoint().unwrap().as_bytes();
+
+    let mut lanes = [0u64; 25];
+    for (i, lane) in lanes.iter_mut().enumerate() {
+        *lane = i as u64 * 0x0101_0101_0101_0101;
+    }
+    assert_eq!(
+        deserialize_keccak_state(&serialize_keccak_state(&lanes)),
+        lanes
+    );
+
+    let checkpoint = Sha3StateCheckpoint::from(bytes);
+    let mut resumed = Sha3_256::from_checkpoint(&checkpoint);
+    resumed.update(tail);
+    assert_eq!(resumed.finalize(), Sha3_256::digest(&data));
+}
+
+#[test]
+fn sha3_serialize_state() {
+    use sha3::state::SHA3_SNAPSHOT_LEN;
+    use sha3::{Digest, Sha3_256};
+
+    let data = [0xa3u8; 300];
+    let expected = Sha3_256::digest(&data);
+
+    // empty buffer, a block missing one byte and exactly one block
+    for &split in [0, 135, 136].iter() {
+        let mut hasher = Sha3_256::new();
+        hasher.update(&data[..split]);
+        let snapshot = hasher.serialize_state();
+        assert_eq!(snapshot.len(), SHA3_SNAPSHOT_LEN);
+
+        let mut resumed = Sha3_256::deserialize_state(&snapshot).unwrap();
+        assert_eq!(resumed.serialize_state()[..], snapshot[..]);
+        resumed.update(&data[split..]);
+        assert_eq!(resumed.finalize(), expected, "split {}", split);
+
+        let mut resumed = Sha3_256::deserialize_state(&snapshot).unwrap();
+        assert_eq!(
+            resumed.finalize_reset(),
+            Sha3_256::digest(&data[..split]),
+            "split {}",
+            split
+        );
+    }
+
+    let snapshot = Sha3_256::new().serialize_state();
+    assert!(Sha3_256::deserialize_state(&snapshot[..200]).is_none());
+    assert!(Sha3_256::deserialize_state(&[0u8; SHA3_SNAPSHOT_LEN + 1]).is_none());
+    let mut bad_pos = snapshot;
+    bad_pos[200] = 136;
+    assert!(Sha3_256::deserialize_state(&bad_pos).is_none());
+}
+
+#[test]
+fn shake_serialize_state() {
+    use digest::{ExtendableOutput, Reset, Update, XofReader};
+    use sha3::state::XOF_READER_SNAPSHOT_LEN;
+    use sha3::{Sha3XofReader, Shake128};
+
+    let mut h = Shake128::default();
+    h.update(&[0x11u8; 200][..]);
+    let mut full = [0u8; 400];
+    h.clone().finalize_xof().read(&mut full);
+
+    let snapshot = h.serialize_state();
+    let resumed = Shake128::deserialize_state(&snapshot).unwrap();
+    let mut out = [0u8; 400];
+    resumed.finalize_xof().read(&mut out);
+    assert_eq!(out[..], full[..]);
+
+    // checkpoint the squeeze position within and across blocks
+    for &k in [0, 10, 168, 200].iter() {
+        let mut reader = h.clone().finalize_xof();
+        reader.read(&mut out[..k]);
+        let snapshot = reader.serialize_state();
+        assert_eq!(snapshot.len(), XOF_READER_SNAPSHOT_LEN);
+
+        let mut resumed = Sha3XofReader::deserialize_state(&snapshot).unwrap();
+        resumed.read(&mut out[k..]);
+        assert_eq!(out[..], full[..], "position {}", k);
+        resumed.reset();
+        resumed.read(&mut out);
+        assert_eq!(out[..], full[..], "reset at position {}", k);
+    }
+
+    let snapshot = h.finalize_xof().serialize_state();
+    assert!(Sha3XofReader::deserialize_state(&snapshot[1..]).is_none());
+    let mut bad_rate = snapshot;
+    bad_rate[401] = 100;
+    assert!(Sha3XofReader::deserialize_state(&bad_rate).is_none());
+}
@@ -0,0 +1,217 @@
+//! NIST CAVP known-answer tests for SHA-3 and SHAKE.
+//!
+//! Runs the byte-oriented `ShortMsg`, `LongMsg` and `Monte` vectors for all
+//! four SHA3 variants, and additionally the `VariableOut` vectors for both
+//! SHAKE variants. The `.rsp` files are not part of the repository; fetch
+//! them with `tests/data/nist/fetch.sh` or point `SHA3_NIST_VECTORS` at a
+//! directory containing them, then run:
+//!
+//! ```text
+//! cargo test --features nist-vectors --test nist_kat
+//! ```
+//!
+//! The tests fail if the feature is enabled but the files are missing.
+#![cfg(feature = "nist-vectors")]
+
+use sha3::digest::{Digest, ExtendableOutput, Update, XofReader};
+use sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512, Shake128, Shake256};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::{env, fs};
+
+/// Contents of a `.rsp` file: `[key = value]` headers and the records that
+/// follow them, each as its list of `key = value` lines.
+struct Rsp {
+    headers: HashMap<String, String>,
+    records: Vec<HashMap<String, String>>,
+}
+
+fn vector_path(name: &str) -> PathBuf {
+    let dir = env::var_os("SHA3_NIST_VECTORS").map_or_else(
+        || PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/data/nist"),
+        PathBuf::from,
+    );
+    dir.join(name)
+}
+
+impl Rsp {
+    fn load(name: &str) -> Rsp {
+        let path = vector_path(name);
+        let text = fs::read_to_string(&path).unwrap_or_else(|e| {
+            panic!(
+                "failed to read {}: {}; run tests/data/nist/fetch.sh first",
+                path.display(),
+                e
+            )
+        });
+
+        let mut headers = HashMap::new();
+        let mut records = Vec::new();
+        let mut record = HashMap::new();
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                if !record.is_empty() {
+                    records.push(std::mem::take(&mut record));
+                }
+            } else if line.starts_with('[') && line.ends_with(']') {
+                if let Some((k, v)) = split(&line[1..line.len() - 1]) {
+                    headers.insert(k, v);
+                }
+            } else if let Some((k, v)) = split(line) {
+                record.insert(k, v);
+            }
+        }
+        if !record.is_empty() {
+            records.push(record);
+        }
+        assert!(!records.is_empty(), "no test vectors in {}", path.display());
+        Rsp { headers, records }
+    }
+
+    fn header(&self, key: &str) -> usize {
+        self.headers[key].parse().unwrap()
+    }
+}
+
+fn split(line: &str) -> Option<(String, String)> {
+    let i = line.find('=')?;
+    Some((line[..i].trim().into(), line[i + 1..].trim().into()))
+}
+
+fn field<'a>(record: &'a HashMap<String, String>, key: &str) -> &'a str {
+    record
+        .get(key)
+        .unwrap_or_else(|| panic!("missing `{}` in record {:?}", key, record))
+}
+
+fn hex(s: &str) -> Vec<u8> {
+    assert_eq!(s.len() % 2, 0, "odd-length hex string");
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Message of a record, honouring `Len = 0` which is encoded as `Msg = 00`.
+fn message(record: &HashMap<String, String>) -> Vec<u8> {
+    let len: usize = field(record, "Len").parse().unwrap();
+    let mut msg = hex(field(record, "Msg"));
+    msg.truncate(len / 8);
+    msg
+}
+
+fn shake<D: Default + Update + ExtendableOutput>(msg: &[u8], out_len: usize) -> Vec<u8> {
+    let mut out = vec![0; out_len];
+    let mut hasher = D::default();
+    hasher.update(msg);
+    hasher.finalize_xof().read(&mut out);
+    out
+}
+
+fn sha3_msg<D: Digest>(name: &str) {
+    let rsp = Rsp::load(name);
+    for record in rsp.records.iter() {
+        let msg = message(record);
+        let md = hex(field(record, "MD"));
+        assert_eq!(
+            D::digest(&msg)[..],
+            md[..],
+            "{}: Len = {}",
+            name,
+            record["Len"]
+        );
+    }
+}
+
+/// SHA3VS §6.2.3: each output is the digest of the previous one.
+fn sha3_monte<D: Digest>(name: &str) {
+    let rsp = Rsp::load(name);
+    let mut md = hex(field(&rsp.records[0], "Seed"));
+    for record in rsp.records[1..].iter() {
+        for _ in 0..1000 {
+            md = D::digest(&md).to_vec();
+        }
+        assert_eq!(
+            md,
+            hex(field(record, "MD")),
+            "{}: COUNT = {}",
+            name,
+            record["COUNT"]
+        );
+    }
+}
+
+fn shake_msg<D: Default + Update + ExtendableOutput>(name: &str) {
+    let rsp = Rsp::load(name);
+    for record in rsp.records.iter() {
+        let msg = message(record);
+        let expected = hex(field(record, "Output"));
+        let out = shake::<D>(&msg, expected.len());
+        assert_eq!(out, expected, "{}: Len = {}", name, record["Len"]);
+    }
+}
+
+fn shake_variable_out<D: Default + Update + ExtendableOutput>(name: &str) {
+    let rsp = Rsp::load(name);
+    for record in rsp.records.iter() {
+        let out_len: usize = field(record, "Outputlen").parse().unwrap();
+        let expected = hex(field(record, "Output"));
+        assert_eq!(expected.len(), out_len / 8);
+        let out = shake::<D>(&hex(field(record, "Msg")), expected.len());
+        assert_eq!(out, expected, "{}: COUNT = {}", name, record["COUNT"]);
+    }
+}
+
+/// SHAKEVS §6.2.3: each message is the leftmost 128 bits of the previous
+/// output, and the next output length is derived from its rightmost 16 bits.
+fn shake_monte<D: Default + Update + ExtendableOutput>(name: &str) {
+    let rsp = Rsp::load(name);
+    let min = rsp.header("Minimum Output Length (bits)") / 8;
+    let max = rsp.header("Maximum Output Length (bits)") / 8;
+    let range = max - min + 1;
+
+    let mut output = hex(field(&rsp.records[0], "Msg"));
+    let mut out_len = max;
+    for record in rsp.records[1..].iter() {
+        for _ in 0..1000 {
+            let mut msg = [0u8; 16];
+            let n = output.len().min(16);
+            msg[..n].copy_from_slice(&output[..n]);
+            output = shake::<D>(&msg, out_len);
+            let tail = usize::from(output[out_len - 2]) << 8 | usize::from(output[out_len - 1]);
+            out_len = min + tail % range;
+        }
+        let expected = hex(field(record, "Output"));
+        assert_eq!(output, expected, "{}: COUNT = {}", name, record["COUNT"]);
+    }
+}
+
+macro_rules! sha3_kat {
+    ($name:ident, $hasher:ty, $prefix:expr) => {
+        #[test]
+        fn $name() {
+            sha3_msg::<$hasher>(concat!($prefix, "ShortMsg.rsp"));
+            sha3_msg::<$hasher>(concat!($prefix, "LongMsg.rsp"));
+            sha3_monte::<$hasher>(concat!($prefix, "Monte.rsp"));
+        }
+    };
+}
+
+macro_rules! shake_kat {
+    ($name:ident, $hasher:ty, $prefix:expr) => {
+        #[test]
+        fn $name() {
+            shake_msg::<$hasher>(concat!($prefix, "ShortMsg.rsp"));
+            shake_msg::<$hasher>(concat!($prefix, "LongMsg.rsp"));
+            shake_variable_out::<$hasher>(concat!($prefix, "VariableOut.rsp"));
+            shake_monte::<$hasher>(concat!($prefix, "Monte.rsp"));
+        }
+    };
+}
+
+sha3_kat!(sha3_224, Sha3_224, "SHA3_224");
+sha3_kat!(sha3_256, Sha3_256, "SHA3_256");
+sha3_kat!(sha3_384, Sha3_384, "SHA3_384");
+sha3_kat!(sha3_512, Sha3_512, "SHA3_512");
+shake_kat!(shake128, Shake128, "SHAKE128");
+shake_kat!(shake256, Shake256, "SHAKE256");
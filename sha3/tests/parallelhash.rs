@@ -0,0 +1,132 @@
+use digest::{Update, XofReader};
+use hex_literal::hex;
+use sha3::{ParallelHash128, ParallelHash256};
+
+const S: &[u8] = b"Parallel Data";
+
+/// 24 bytes of sample data: `00..07 10..17 20..27`
+fn data24() -> Vec<u8> {
+    (0..3u8).flat_map(|i| 16 * i..16 * i + 8).collect()
+}
+
+/// 72 bytes of sample data: `00..0B 10..1B .. 50..5B`
+fn data72() -> Vec<u8> {
+    (0..6u8).flat_map(|i| 16 * i..16 * i + 12).collect()
+}
+
+fn parallel_hash128(data: &[u8], block_size: usize, customization: &[u8]) -> [u8; 32] {
+    let mut h = ParallelHash128::new(block_size, customization);
+    h.update(data);
+    let mut out = [0u8; 32];
+    h.finalize_into(&mut out);
+    out
+}
+
+fn parallel_hash256(data: &[u8], block_size: usize, customization: &[u8]) -> [u8; 64] {
+    let mut h = ParallelHash256::new(block_size, customization);
+    h.update(data);
+    let mut out = [0u8; 64];
+    h.finalize_into(&mut out);
+    out
+}
+
+// Samples from NIST SP 800-185, Appendix D
+#[test]
+fn parallel_hash128_samples() {
+    assert_eq!(
+        parallel_hash128(&data24(), 8, b""),
+        hex!("ba8dc1d1d979331d3f813603c67f72609ab5e44b94a0b8f9af46514454a2b4f5")
+    );
+    assert_eq!(
+        parallel_hash128(&data24(), 8, S),
+        hex!("fc484dcb3f84dceedc353438151bee58157d6efed0445a81f165e495795b7206")
+    );
+    assert_eq!(
+        parallel_hash128(&data72(), 12, S),
+        hex!("f7fd5312896c6685c828af7e2adb97e393e7f8d54e3c2ea4b95e5aca3796e8fc")
+    );
+}
+
+#[test]
+fn parallel_hash256_samples() {
+    assert_eq!(
+        parallel_hash256(&data24(), 8, b"")[..],
+        hex!(
+            "bc1ef124da34495e948ead207dd9842235da432d2bbc54b4c110e64c45110553
+            1b7f2a3e0ce055c02805e7c2de1fb746af97a1dd01f43b824e31b87612410429"
+        )[..]
+    );
+    assert_eq!(
+        parallel_hash256(&data24(), 8, S)[..],
+        hex!(
+            "cdf15289b54f6212b4bc270528b49526006dd9b54e2b6add1ef6900dda3963bb
+            33a72491f236969ca8afaea29c682d47a393c065b38e29fae651a2091c833110"
+        )[..]
+    );
+    assert_eq!(
+        parallel_hash256(&data72(), 12, S)[..],
+        hex!(
+            "69d0fcb764ea055dd09334bc6021cb7e4b61348dff375da262671cdec3effa8d
+            1b4568a6cce16b1cad946ddde27f6ce2b8dee4cd1b24851ebf00eb90d43813e9"
+        )[..]
+    );
+}
+
+#[test]
+fn parallel_hash_xof_samples() {
+    let mut h = ParallelHash128::new(8, b"");
+    h.update(data24());
+    let mut out = [0u8; 32];
+    h.finalize_xof().read(&mut out);
+    assert_eq!(
+        out,
+        hex!("fe47d661e49ffe5b7d999922c062356750caf552985b8e8ce6667f2727c3c8d3")
+    );
+
+    let mut h = ParallelHash256::new(12, S);
+    h.update(data72());
+    let mut out = [0u8; 64];
+    h.finalize_xof().read(&mut out);
+    assert_eq!(
+        out[..],
+        hex!(
+            "6b3e790b330c889a204c2fbc728d809f19367328d852f4002dc829f73afd6bce
+            fb7fe5b607b13a801c0be5c1170bdb794e339458fdb0e62a6af3d42558970249"
+        )[..]
+    );
+}
+
+#[test]
+fn parallel_hash_partial_block() {
+    let data: Vec<u8> = (0..20).collect();
+    let expected = hex!("c64e32ec154b6fe852a0d050ce052151d5403fb4af6b7d4790015a11c9250f47");
+    assert_eq!(parallel_hash128(&data, 8, b""), expected);
+
+    // block boundaries do not depend on how the input is split
+    let mut h = ParallelHash128::new(8, b"");
+    for chunk in data.chunks(3) {
+        h.update(chunk);
+    }
+    let mut out = [0u8; 32];
+    h.finalize_into(&mut out);
+    assert_eq!(out, expected);
+
+    assert_eq!(
+        parallel_hash128(b"", 8, b""),
+        hex!("96427c30224408859f95e89e4fa84e1c7a1478dbf2008ac982ce61a77f37a272")
+    );
+}
+
+#[test]
+fn parallel_hash_reset() {
+    let mut h = ParallelHash128::new(8, S);
+    h.update(b"garbage, spanning more than one block");
+    digest::Reset::reset(&mut h);
+    h.update(data24());
+    let mut out = [0u8; 32];
+    h.finalize_into(&mut out);
+    assert_eq!(
+        out,
+        hex!("fc484dcb3f84dceedc353438151bee58157d6efed0445a81f165e495795b7206")
+    );
+}
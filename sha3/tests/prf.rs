@@ -0,0 +1,165 @@
+//! HKDF-SHA-3 test vectors computed over the RFC 5869 inputs
+#![cfg(feature = "hkdf")]
+
+use hex_literal::hex;
+use sha3::digest::consts::{U42, U82};
+use sha3::prf::{HkdfSha3_256, HkdfSha3_512, Prf};
+use sha3::{Sha3_256, Sha3_512};
+
+struct Case<'a> {
+    ikm: &'a [u8],
+    salt: &'a [u8],
+    info: &'a [u8],
+}
+
+fn cases() -> [Case<'static>; 3] {
+    const IKM1: [u8; 22] = [0x0b; 22];
+    const SALT1: [u8; 13] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c,
+    ];
+    const INFO1: [u8; 10] = [0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9];
+    const IKM2: [u8; 80] = hex!(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f
+        202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f
+        404142434445464748494a4b4c4d4e4f"
+    );
+    const SALT2: [u8; 80] = hex!(
+        "606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f
+        808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f
+        a0a1a2a3a4a5a6a7a8a9aaabacadaeaf"
+    );
+    const INFO2: [u8; 80] = hex!(
+        "b0b1b2b3b4b5b6b7b8b9babbbcbdbebfc0c1c2c3c4c5c6c7c8c9cacbcccdcecf
+        d0d1d2d3d4d5d6d7d8d9dadbdcdddedfe0e1e2e3e4e5e6e7e8e9eaebecedeeef
+        f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff"
+    );
+    [
+        Case {
+            ikm: &IKM1,
+            salt: &SALT1,
+            info: &INFO1,
+        },
+        Case {
+            ikm: &IKM2,
+            salt: &SALT2,
+            info: &INFO2,
+        },
+        Case {
+            ikm: &IKM1,
+            salt: &[],
+            info: &[],
+        },
+    ]
+}
+
+#[test]
+fn hkdf_sha3_256() {
+    let [c1, c2, c3] = cases();
+
+    let (prk, _) = HkdfSha3_256::extract(Some(c1.salt), c1.ikm);
+    assert_eq!(
+        prk[..],
+        hex!("7d4194836f7a113a44677abc825640ade07af1c1d69a9a4b109b280a8fe54ef0")[..]
+    );
+    let okm = Prf::<Sha3_256, U42>::new(Some(c1.salt), c1.ikm)
+        .expand(c1.info)
+        .unwrap();
+    assert_eq!(
+        okm[..],
+        hex!(
+            "0c5160501d65021deaf2c14f5abce04c5bd2635abceeba61c2edb6e8ed726749
+            00557728f2c9f2c4c179"
+        )[..]
+    );
+
+    let okm = Prf::<Sha3_256, U82>::new(Some(c2.salt), c2.ikm)
+        .expand(c2.info)
+        .unwrap();
+    assert_eq!(
+        okm[..],
+        hex!(
+            "3dc251e66c75da6560405ec5ac10e17d851eedfbfdc13feafbec16964c25d021
+            bd971465a3e9c615f27769019e3f0407d84986fb0ba24e729c99834624baa21c
+            b623dc0098f430d52e18bbdf694df4edd8b2"
+        )[..]
+    );
+
+    let prk = hex!("b899e6e4b88a35f9f5d618f48b424c313f9704012763eb6295414d673365928a");
+    let okm = Prf::<Sha3_256, U42>::from_prk(&prk)
+        .unwrap()
+        .expand(c3.info)
+        .unwrap();
+    assert_eq!(
+        okm[..],
+        hex!(
+            "bc1342cdd75c05e8b0c3ae609ce4410684d197232875073499b30cdfe2de2853
+            c1c1bed63d725e885e78"
+        )[..]
+    );
+    let okm = Prf::<Sha3_256, U42>::new(None, c3.ikm)
+        .expand(c3.info)
+        .unwrap();
+    assert_eq!(
+        okm[..],
+        hex!(
+            "bc1342cdd75c05e8b0c3ae609ce4410684d197232875073499b30cdfe2de2853
+            c1c1bed63d725e885e78"
+        )[..]
+    );
+}
+
+#[test]
+fn hkdf_sha3_512() {
+    let [c1, c2, c3] = cases();
+
+    let (prk, _) = HkdfSha3_512::extract(Some(c1.salt), c1.ikm);
+    assert_eq!(
+        prk[..],
+        hex!(
+            "e1c543094f64f3d6c6658a94a94e3818ba13d0b3e77074b80f88f32e6b8433b7
+            03536cb500753967fae2ea977e11e4dd4f45389807cdf255b395e46807c87d5d"
+        )[..]
+    );
+    let okm = Prf::<Sha3_512, U42>::new(Some(c1.salt), c1.ikm)
+        .expand(c1.info)
+        .unwrap();
+    assert_eq!(
+        okm[..],
+        hex!(
+            "40e9f17e9bf2ef99425c2b23ccdf20a018ea5513f9ae68e1ea8c626deb57dfa4
+            d56c27ccf2a2a24488a5"
+        )[..]
+    );
+
+    let okm = Prf::<Sha3_512, U82>::new(Some(c2.salt), c2.ikm)
+        .expand(c2.info)
+        .unwrap();
+    assert_eq!(
+        okm[..],
+        hex!(
+            "3adf31011245f82cc6b5c3b2ea31fe2a9b855b425c3ecdd8da4a3fc5d0c3563f
+            63bbdedf7ca912d2e98cbc853d978066ab177f19a7349e3982549b82a307e211
+            3891691f2536ce45eb5ddf9b5175859ce8d5"
+        )[..]
+    );
+
+    let okm = Prf::<Sha3_512, U42>::new(None, c3.ikm)
+        .expand(c3.info)
+        .unwrap();
+    assert_eq!(
+        okm[..],
+        hex!(
+            "38bd71e45b397b775b563365a33258a6fd83abc1e86acf042f0723c2b68ebf07
+            3a75c34c69328835ee4c"
+        )[..]
+    );
+}
+
+#[test]
+fn output_too_long() {
+    use sha3::digest::consts::U8192;
+
+    // maximum output is 255 * 32 = 8160 bytes
+    let prf = Prf::<Sha3_256, U8192>::new(None, b"ikm");
+    assert!(prf.expand(b"").is_err());
+}
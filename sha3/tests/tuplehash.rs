@@ -0,0 +1,124 @@
+use digest::XofReader;
+use hex_literal::hex;
+use sha3::{TupleHash128, TupleHash256};
+
+const A: &[u8] = &hex!("000102");
+const B: &[u8] = &hex!("101112131415");
+const C: &[u8] = &hex!("202122232425262728");
+const S: &[u8] = b"My Tuple App";
+
+fn tuple_hash128(elements: &[&[u8]], customization: &[u8]) -> [u8; 32] {
+    let mut h = TupleHash128::new(customization);
+    for e in elements {
+        h.input(e);
+    }
+    let mut out = [0u8; 32];
+    h.finalize_into(&mut out);
+    out
+}
+
+fn tuple_hash256(elements: &[&[u8]], customization: &[u8]) -> [u8; 64] {
+    let mut h = TupleHash256::new(customization);
+    for e in elements {
+        h.input(e);
+    }
+    let mut out = [0u8; 64];
+    h.finalize_into(&mut out);
+    out
+}
+
+// Samples from NIST SP 800-185, Appendix C
+#[test]
+fn tuple_hash128_samples() {
+    assert_eq!(
+        tuple_hash128(&[A, B], b""),
+        hex!("c5d8786c1afb9b82111ab34b65b2c0048fa64e6d48e263264ce1707d3ffc8ed1")
+    );
+    assert_eq!(
+        tuple_hash128(&[A, B], S),
+        hex!("75cdb20ff4db1154e841d758e24160c54bae86eb8c13e7f5f40eb35588e96dfb")
+    );
+    assert_eq!(
+        tuple_hash128(&[A, B, C], S),
+        hex!("e60f202c89a2631eda8d4c588ca5fd07f39e5151998deccf973adb3804bb6e84")
+    );
+}
+
+#[test]
+fn tuple_hash256_samples() {
+    assert_eq!(
+        tuple_hash256(&[A, B], b"")[..],
+        hex!(
+            "cfb7058caca5e668f81a12a20a2195ce97a925f1dba3e7449a56f82201ec6073
+            11ac2696b1ab5ea2352df1423bde7bd4bb78c9aed1a853c78672f9eb23bbe194"
+        )[..]
+    );
+    assert_eq!(
+        tuple_hash256(&[A, B], S)[..],
+        hex!(
+            "147c2191d5ed7efd98dbd96d7ab5a11692576f5fe2a5065f3e33de6bba9f3aa1
+            c4e9a068a289c61c95aab30aee1e410b0b607de3620e24a4e3bf9852a1d4367e"
+        )[..]
+    );
+    assert_eq!(
+        tuple_hash256(&[A, B, C], S)[..],
+        hex!(
+            "45000be63f9b6bfd89f54717670f69a9bc763591a4f05c50d68891a744bcc6e7
+            d6d5b5e82c018da999ed35b0bb49c9678e526abd8e85c13ed254021db9e790ce"
+        )[..]
+    );
+}
+
+#[test]
+fn tuple_hash_xof_samples() {
+    let mut h = TupleHash128::new(b"");
+    h.input(A);
+    h.input(B);
+    let mut out = [0u8; 32];
+    h.finalize_xof().read(&mut out);
+    assert_eq!(
+        out,
+        hex!("2f103cd7c32320353495c68de1a8129245c6325f6f2a3d608d92179c96e68488")
+    );
+
+    let mut h = TupleHash256::new(S);
+    h.input(A);
+    h.input(B);
+    h.input(C);
+    let mut out = [0u8; 64];
+    h.finalize_xof().read(&mut out);
+    assert_eq!(
+        out[..],
+        hex!(
+            "0c59b11464f2336c34663ed51b2b950bec743610856f36c28d1d088d8a244628
+            4dd09830a6a178dc752376199fae935d86cfdee5913d4922dfd369b66a53c897"
+        )[..]
+    );
+}
+
+#[test]
+fn tuple_hash_element_framing() {
+    assert_ne!(
+        tuple_hash128(&[b"abc", b"d"], b""),
+        tuple_hash128(&[b"ab", b"cd"], b"")
+    );
+    assert_ne!(
+        tuple_hash256(&[b"abc", b"d"], b"")[..],
+        tuple_hash256(&[b"ab", b"cd"], b"")[..]
+    );
+}
+
+#[test]
+fn tuple_hash_reset() {
+    let mut h = TupleHash128::new(S);
+    h.input(b"garbage");
+    digest::Reset::reset(&mut h);
+    h.input(A);
+    h.input(B);
+    let mut out = [0u8; 32];
+    h.finalize_into(&mut out);
+    assert_eq!(
+        out,
+        hex!("75cdb20ff4db1154e841d758e24160c54bae86eb8c13e7f5f40eb35588e96dfb")
+    );
+}
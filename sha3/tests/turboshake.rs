@@ -0,0 +1,98 @@
+use digest::{ExtendableOutput, Update, XofReader};
+use hex_literal::hex;
+use sha3::{TurboShake128, TurboShake256};
+
+/// `ptn(n)` from the KangarooTwelve specification: `00 01 .. FA` repeated.
+fn ptn(n: usize) -> Vec<u8> {
+    (0..n).map(|i| (i % 251) as u8).collect()
+}
+
+fn turbo_shake128(msg: &[u8], domain_sep: u8, out: &mut [u8]) {
+    let mut h = TurboShake128::new(domain_sep);
+    h.update(msg);
+    h.finalize_xof().read(out);
+}
+
+fn turbo_shake256(msg: &[u8], domain_sep: u8, out: &mut [u8]) {
+    let mut h = TurboShake256::new(domain_sep);
+    h.update(msg);
+    h.finalize_xof().read(out);
+}
+
+#[test]
+fn turbo_shake128_vectors() {
+    let mut out = [0u8; 32];
+    turbo_shake128(b"", 0x1F, &mut out);
+    assert_eq!(
+        out,
+        hex!("1e415f1c5983aff2169217277d17bb538cd945a397ddec541f1ce41af2c1b74c")
+    );
+
+    let mut long = vec![0u8; 10032];
+    turbo_shake128(b"", 0x1F, &mut long);
+    assert_eq!(
+        long[10000..],
+        hex!("a3b9b0385900ce761f22aed548e754da10a5242d62e8c658e3f3a923a7555607")
+    );
+
+    turbo_shake128(&[0xFF; 3], 0x01, &mut out);
+    assert_eq!(
+        out,
+        hex!("bf323f940494e88ee1c540fe660be8a0c93f43d15ec006998462fa994eed5dab")
+    );
+
+    // KangarooTwelve of the empty message with no customization is a single
+    // TurboSHAKE128 call over `right_encode(0)` with domain byte 0x07
+    turbo_shake128(&[0x00], 0x07, &mut out);
+    assert_eq!(
+        out,
+        hex!("1ac2d450fc3b4205d19da7bfca1b37513c0803577ac7167f06fe2ce1f0ef39e5")
+    );
+
+    // longer than one block
+    turbo_shake128(&ptn(17 * 17), 0x1F, &mut out);
+    assert_eq!(
+        out,
+        hex!("96c77c279e0126f7fc07c9b07f5cdae1e0be60bdbe10620040e75d7223a624d2")
+    );
+}
+
+#[test]
+fn turbo_shake256_vectors() {
+    let mut out = [0u8; 64];
+    turbo_shake256(b"", 0x1F, &mut out);
+    assert_eq!(
+        out[..],
+        hex!(
+            "367a329dafea871c7802ec67f905ae13c57695dc2c6663c61035f59a18f8e7db
+            11edc0e12e91ea60eb6b32df06dd7f002fbafabb6e13ec1cc20d995547600db0"
+        )[..]
+    );
+
+    let mut out = [0u8; 32];
+    turbo_shake256(&ptn(17 * 17), 0x1F, &mut out);
+    assert_eq!(
+        out,
+        hex!("66b810db8e90780424c0847372fdc95710882fde31c6df75beb9d4cd9305cfca")
+    );
+}
+
+#[test]
+fn turbo_shake_reset() {
+    let mut h = TurboShake128::new(0x0B);
+    h.update(b"garbage");
+    digest::Reset::reset(&mut h);
+    h.update(b"abc");
+
+    let mut expected = [0u8; 32];
+    turbo_shake128(b"abc", 0x0B, &mut expected);
+    let mut out = [0u8; 32];
+    h.finalize_xof().read(&mut out);
+    assert_eq!(out, expected);
+}
+
+#[test]
+#[should_panic]
+fn turbo_shake_invalid_domain_sep() {
+    TurboShake128::new(0x80);
+}
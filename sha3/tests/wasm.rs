@@ -0,0 +1,70 @@
+//! Browser tests run via `wasm-pack test --headless --chrome`.
+//!
+//! `wasm-bindgen-test` does not pick up `#[test]` functions, so the
+//! known-answer suites from `tests/lib.rs` are repeated here. Build with
+//! `RUSTFLAGS="-C target-feature=+simd128"` to exercise the SIMD128
+//! permutation; `sha3_256_throughput` logs MB/s so both builds can be
+//! compared in the CI output.
+#![cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+
+use digest::dev::{digest_test, xof_test};
+use sha3::Digest;
+use wasm_bindgen_test::{console_log, wasm_bindgen_test, wasm_bindgen_test_configure};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! wasm_test {
+    ($name:ident, $test_name:expr, $hasher:ty, $test_func:ident) => {
+        #[wasm_bindgen_test]
+        fn $name() {
+            use digest::dev::blobby::Blob2Iterator;
+            let data = include_bytes!(concat!("data/", $test_name, ".blb"));
+
+            for (i, row) in Blob2Iterator::new(data).unwrap().enumerate() {
+                if let Some(desc) = $test_func::<$hasher>(row[0], row[1]) {
+                    panic!("Failed test №{}: {}", i, desc);
+                }
+            }
+        }
+    };
+}
+
+wasm_test!(keccak_224, "keccak_224", sha3::Keccak224, digest_test);
+wasm_test!(keccak_256, "keccak_256", sha3::Keccak256, digest_test);
+wasm_test!(keccak_384, "keccak_384", sha3::Keccak384, digest_test);
+wasm_test!(keccak_512, "keccak_512", sha3::Keccak512, digest_test);
+wasm_test!(
+    keccak_256_full,
+    "keccak_256_full",
+    sha3::Keccak256Full,
+    digest_test
+);
+wasm_test!(sha3_224, "sha3_224", sha3::Sha3_224, digest_test);
+wasm_test!(sha3_256, "sha3_256", sha3::Sha3_256, digest_test);
+wasm_test!(sha3_384, "sha3_384", sha3::Sha3_384, digest_test);
+wasm_test!(sha3_512, "sha3_512", sha3::Sha3_512, digest_test);
+wasm_test!(shake128, "shake128", sha3::Shake128, xof_test);
+wasm_test!(shake256, "shake256", sha3::Shake256, xof_test);
+
+#[wasm_bindgen_test]
+fn sha3_256_throughput() {
+    const LEN: usize = 1 << 20;
+    const ROUNDS: usize = 16;
+
+    let data = vec![0x5au8; LEN];
+    let start = js_sys::Date::now();
+    for _ in 0..ROUNDS {
+        let _ = sha3::Sha3_256::digest(&data);
+    }
+    let elapsed = js_sys::Date::now() - start;
+    let backend = if cfg!(target_feature = "simd128") {
+        "simd128"
+    } else {
+        "scalar"
+    };
+    console_log!(
+        "SHA3-256 ({}): {:.1} MB/s",
+        backend,
+        (LEN * ROUNDS) as f64 / elapsed / 1e3
+    );
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "zeroize")]
+
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::state::{SHA3_SNAPSHOT_LEN, XOF_READER_SNAPSHOT_LEN};
+use sha3::zeroize::Zeroize;
+use sha3::{Sha3_256, Shake128};
+
+/// Covers a whole block and part of the next one for both hashers, so that
+/// the state and the buffer hold input.
+const INPUT: [u8; 200] = [0x5a; 200];
+
+#[test]
+fn sha3_256_zeroize() {
+    let mut hasher = Sha3_256::default();
+    hasher.update(&INPUT[..]);
+    hasher.zeroize();
+    assert_eq!(hasher.serialize_state()[..], [0u8; SHA3_SNAPSHOT_LEN][..]);
+}
+
+#[test]
+fn shake128_reader_zeroize() {
+    let mut hasher = Shake128::default();
+    hasher.update(&INPUT[..]);
+    let mut reader = hasher.finalize_xof();
+    reader.read(&mut [0u8; 10]);
+    reader.zeroize();
+    assert_eq!(
+        reader.serialize_state()[..],
+        [0u8; XOF_READER_SNAPSHOT_LEN][..]
+    );
+}
@@ -2,6 +2,7 @@
 use block_buffer::block_padding::Iso7816;
 use block_buffer::BlockBuffer;
 use core::convert::TryInto;
+use core::fmt;
 use digest::{
     consts::{U24, U28, U32, U48, U64},
     generic_array::GenericArray,
@@ -269,6 +270,17 @@ impl Default for Shabal512 {
     }
 }
 
+impl Shabal512 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "Shabal-512";
+}
+
+impl fmt::Display for Shabal512 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Shabal512 {
     type BlockSize = BlockSize;
 }
@@ -312,6 +324,17 @@ impl Default for Shabal384 {
     }
 }
 
+impl Shabal384 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "Shabal-384";
+}
+
+impl fmt::Display for Shabal384 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Shabal384 {
     type BlockSize = BlockSize;
 }
@@ -355,6 +378,17 @@ impl Default for Shabal256 {
     }
 }
 
+impl Shabal256 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "Shabal-256";
+}
+
+impl fmt::Display for Shabal256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Shabal256 {
     type BlockSize = BlockSize;
 }
@@ -398,6 +432,17 @@ impl Default for Shabal224 {
     }
 }
 
+impl Shabal224 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "Shabal-224";
+}
+
+impl fmt::Display for Shabal224 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Shabal224 {
     type BlockSize = BlockSize;
 }
@@ -441,6 +486,17 @@ impl Default for Shabal192 {
     }
 }
 
+impl Shabal192 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "Shabal-192";
+}
+
+impl fmt::Display for Shabal192 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Shabal192 {
     type BlockSize = BlockSize;
 }
@@ -38,3 +38,20 @@ fn sha512_1million_a() {
     let output = include_bytes!("data/shabal512_one_million_a.bin");
     one_million_a::<shabal::Shabal512>(output);
 }
+
+#[test]
+fn shabal_alg_name() {
+    assert_eq!(shabal::Shabal192::ALG_NAME, "Shabal-192");
+    assert_eq!(shabal::Shabal224::ALG_NAME, "Shabal-224");
+    assert_eq!(shabal::Shabal256::ALG_NAME, "Shabal-256");
+    assert_eq!(shabal::Shabal384::ALG_NAME, "Shabal-384");
+    assert_eq!(shabal::Shabal512::ALG_NAME, "Shabal-512");
+}
+
+#[test]
+fn shabal_display() {
+    extern crate std;
+    use std::format;
+
+    assert_eq!(format!("{}", shabal::Shabal256::default()), "Shabal-256");
+}
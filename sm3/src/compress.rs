@@ -0,0 +1,20 @@
+use digest::consts::U64;
+use digest::generic_array::GenericArray;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "sm4"))]
+mod aarch64;
+#[cfg(any(test, not(all(target_arch = "aarch64", target_feature = "sm4"))))]
+mod soft;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "sm4"))]
+use aarch64::compress;
+#[cfg(not(all(target_arch = "aarch64", target_feature = "sm4")))]
+use soft::compress;
+
+pub fn sm3_compress(state: &mut [u32; 8], blocks: &[GenericArray<u8, U64>]) {
+    // SAFETY: GenericArray<u8, U64> and [u8; 64] have
+    // exactly the same memory layout
+    #[allow(unsafe_code)]
+    let blocks = unsafe { &*(blocks as *const _ as *const [[u8; 64]]) };
+    compress(state, blocks);
+}
@@ -0,0 +1,166 @@
+//! SM3 compression using the ARMv8.2 SM3 instructions.
+//!
+//! The chaining value is kept in two vectors holding `A, B, C, D` and
+//! `E, F, G, H` in reverse lane order, which is the layout `sm3tt1*` and
+//! `sm3tt2*` operate on: `sm3ss1` computes `SS1` of a round, after which
+//! these two instructions update the first and second half of the state.
+//! The message expansion produces four words at a time with `sm3partw1` and
+//! `sm3partw2`.
+//!
+//! The intrinsics for these instructions are unstable, so they are emitted
+//! with inline assembly. Only compiled when the `sm4` target feature, which
+//! covers both SM3 and SM4, is enabled at build time (e.g. with
+//! `RUSTFLAGS="-C target-feature=+sm4"`).
+#![allow(unsafe_code)]
+
+use crate::consts::T32;
+use core::arch::aarch64::*;
+use core::arch::asm;
+
+/// `sm3partw1` or `sm3partw2` with `$d` as the destination and first source.
+macro_rules! sm3partw {
+    ($insn:literal, $d:ident, $n:expr, $m:expr) => {
+        asm!(
+            concat!($insn, " {d:v}.4s, {n:v}.4s, {m:v}.4s"),
+            d = inout(vreg) $d,
+            n = in(vreg) $n,
+            m = in(vreg) $m,
+            options(pure, nomem, nostack, preserves_flags),
+        )
+    };
+}
+
+/// `sm3tt*` updating `$d` with `SS1` in `$n` and lane `$i` of `$m`.
+macro_rules! sm3tt {
+    ($insn:expr, $d:ident, $n:expr, $m:expr, $i:literal) => {
+        asm!(
+            concat!($insn, " {d:v}.4s, {n:v}.4s, {m:v}.s[", $i, "]"),
+            d = inout(vreg) $d,
+            n = in(vreg) $n,
+            m = in(vreg) $m,
+            options(pure, nomem, nostack, preserves_flags),
+        )
+    };
+}
+
+/// Round `$j`, which uses lane `$i` of the message words `$w` and of
+/// `$wp = W[j] ^ W[j + 4]`.
+macro_rules! round {
+    ($ab:literal, $abcd:ident, $efgh:ident, $w:ident, $wp:ident, $j:expr, $i:literal) => {{
+        let t = vdupq_n_u32(T32[$j]);
+        let ss1: uint32x4_t;
+        asm!(
+            "sm3ss1 {ss1:v}.4s, {a:v}.4s, {e:v}.4s, {t:v}.4s",
+            ss1 = lateout(vreg) ss1,
+            a = in(vreg) $abcd,
+            e = in(vreg) $efgh,
+            t = in(vreg) t,
+            options(pure, nomem, nostack, preserves_flags),
+        );
+        sm3tt!(concat!("sm3tt1", $ab), $abcd, ss1, $wp, $i);
+        sm3tt!(concat!("sm3tt2", $ab), $efgh, ss1, $w, $i);
+    }};
+}
+
+/// Rounds `$j` to `$j + 3`.
+macro_rules! qround {
+    ($ab:literal, $abcd:ident, $efgh:ident, $w:ident, $wp:ident, $j:expr) => {
+        round!($ab, $abcd, $efgh, $w, $wp, $j, "0");
+        round!($ab, $abcd, $efgh, $w, $wp, $j + 1, "1");
+        round!($ab, $abcd, $efgh, $w, $wp, $j + 2, "2");
+        round!($ab, $abcd, $efgh, $w, $wp, $j + 3, "3");
+    };
+}
+
+/// Reverse the order of the lanes.
+#[inline(always)]
+unsafe fn rev(x: uint32x4_t) -> uint32x4_t {
+    let x = vrev64q_u32(x);
+    vextq_u32::<2>(x, x)
+}
+
+#[inline(always)]
+unsafe fn load_be(bytes: &[u8]) -> uint32x4_t {
+    vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(bytes.as_ptr())))
+}
+
+unsafe fn compress_sm3(state: &mut [u32; 8], blocks: &[[u8; 64]]) {
+    let mut abcd = rev(vld1q_u32(state.as_ptr()));
+    let mut efgh = rev(vld1q_u32(state[4..].as_ptr()));
+
+    for block in blocks {
+        let abcd_orig = abcd;
+        let efgh_orig = efgh;
+
+        // `W[4 * i..4 * i + 16]` in group `i` of four rounds
+        let mut w = [
+            load_be(&block[0..16]),
+            load_be(&block[16..32]),
+            load_be(&block[32..48]),
+            load_be(&block[48..64]),
+        ];
+        for i in 0..16 {
+            let w0 = w[0];
+            let wp = veorq_u32(w[0], w[1]);
+            if i < 4 {
+                qround!("a", abcd, efgh, w0, wp, 4 * i);
+            } else {
+                qround!("b", abcd, efgh, w0, wp, 4 * i);
+            }
+
+            // the rounds use `W[0..68]`, so the last three groups do not
+            // need to expand the message any further
+            let mut next = vdupq_n_u32(0);
+            if i < 13 {
+                // W[j - 16] ^ W[j - 9] ^ (W[j - 3] <<< 15) for `j` in
+                // `4 * i + 16..4 * i + 20`, where `sm3partw1` computes the
+                // last word from the first one for `j - 3`
+                next = vextq_u32::<3>(w[1], w[2]);
+                sm3partw!("sm3partw1", next, w[0], w[3]);
+                // P1 of the above, and ^ (W[j - 13] <<< 7) ^ W[j - 6]
+                let w6 = vextq_u32::<2>(w[2], w[3]);
+                let w13 = vextq_u32::<3>(w[0], w[1]);
+                sm3partw!("sm3partw2", next, w6, w13);
+            }
+            w = [w[1], w[2], w[3], next];
+        }
+
+        abcd = veorq_u32(abcd, abcd_orig);
+        efgh = veorq_u32(efgh, efgh_orig);
+    }
+
+    vst1q_u32(state.as_mut_ptr(), rev(abcd));
+    vst1q_u32(state[4..].as_mut_ptr(), rev(efgh));
+}
+
+pub(crate) fn compress(state: &mut [u32; 8], blocks: &[[u8; 64]]) {
+    // SAFETY: the `sm4` target feature is enabled at compile time
+    unsafe { compress_sm3(state, blocks) }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn sm3_matches_soft() {
+        let mut x = 0x9E37_79B9u32;
+        let mut next = || {
+            x ^= x << 13;
+            x ^= x >> 17;
+            x ^= x << 5;
+            x
+        };
+        for n in 0..8 {
+            let mut state = [0u32; 8];
+            state.iter_mut().for_each(|w| *w = next());
+            let mut blocks = [[0u8; 64]; 8];
+            for b in blocks.iter_mut().flat_map(|b| b.iter_mut()) {
+                *b = next() as u8;
+            }
+
+            let mut expected = state;
+            super::super::soft::compress(&mut expected, &blocks[..n]);
+            super::compress(&mut state, &blocks[..n]);
+            assert_eq!(state, expected);
+        }
+    }
+}
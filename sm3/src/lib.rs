@@ -39,15 +39,19 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+mod compress;
 mod consts;
 mod sm3;
 
+#[cfg(feature = "serde")]
+mod serde_impls;
+
 pub use digest::{self, Digest};
 
 #[cfg(feature = "std")]
 use digest::Update;
 
-pub use crate::sm3::Sm3;
+pub use crate::sm3::{Sm3, SM3_SNAPSHOT_LEN};
 
 opaque_debug::implement!(Sm3);
 
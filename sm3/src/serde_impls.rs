@@ -0,0 +1,50 @@
+//! `serde` support for checkpointing hasher state.
+//!
+//! The state is encoded as the byte string created by
+//! [`Sm3::serialize_state`], so input which does not fill a whole block yet
+//! is preserved.
+use crate::{Sm3, SM3_SNAPSHOT_LEN};
+use core::fmt;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for Sm3 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.serialize_state())
+    }
+}
+
+impl<'de> Deserialize<'de> for Sm3 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(SnapshotVisitor)
+    }
+}
+
+/// Accepts the snapshot as bytes or, for formats without a byte string
+/// type, as a sequence of bytes.
+struct SnapshotVisitor;
+
+impl<'de> de::Visitor<'de> for SnapshotVisitor {
+    type Value = Sm3;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "an SM3 snapshot of {} bytes", SM3_SNAPSHOT_LEN)
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Sm3, E> {
+        Sm3::deserialize_state(bytes)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Bytes(bytes), &self))
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Sm3, A::Error> {
+        let mut bytes = [0u8; SM3_SNAPSHOT_LEN];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(de::Error::invalid_length(SM3_SNAPSHOT_LEN + 1, &self));
+        }
+        self.visit_bytes(&bytes)
+    }
+}
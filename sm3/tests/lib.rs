@@ -26,3 +26,13 @@ fn sm3_tests() {
     assert_eq!(hash.finalize_reset().as_slice(),
         hex!("b80fe97a4da24afc277564f66a359ef440462ad28dcc6d63adb24d5c20a61595"));
 }
+
+#[test]
+fn sm3_alg_name() {
+    assert_eq!(sm3::Sm3::ALG_NAME, "SM3");
+}
+
+#[test]
+fn sm3_display() {
+    assert_eq!(format!("{}", sm3::Sm3::default()), "SM3");
+}
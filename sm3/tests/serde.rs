@@ -0,0 +1,34 @@
+#![cfg(feature = "serde")]
+
+use sm3::{Digest, Sm3};
+
+#[test]
+fn sm3_serde_roundtrip() {
+    let data = [0x42u8; 192];
+
+    // on a block boundary and with part of a block buffered
+    for &split in [128, 100].iter() {
+        let mut h = Sm3::new();
+        h.update(&data[..split]);
+        let encoded = bincode::serialize(&h).unwrap();
+
+        let mut h: Sm3 = bincode::deserialize(&encoded).unwrap();
+        h.update(&data[split..]);
+        assert_eq!(h.finalize(), Sm3::digest(&data));
+
+        let res: Result<Sm3, _> = bincode::deserialize(&encoded[..encoded.len() - 1]);
+        assert!(res.is_err());
+    }
+}
+
+#[test]
+fn sm3_serde_reject_malformed() {
+    let mut h = Sm3::new();
+    h.update(b"abc");
+    let mut encoded = bincode::serialize(&h).unwrap();
+    // buffer position past the end of the block
+    let pos = encoded.len() - 65;
+    encoded[pos] = 64;
+    let res: Result<Sm3, _> = bincode::deserialize(&encoded);
+    assert!(res.is_err());
+}
@@ -0,0 +1,31 @@
+use sm3::{Digest, Sm3, SM3_SNAPSHOT_LEN};
+
+#[test]
+fn sm3_serialize_state() {
+    let data: Vec<u8> = (0..600u32).map(|i| i as u8).collect();
+    // empty, the last byte which fits next to the length padding, the first
+    // which does not, and several whole blocks
+    for &split in &[0, 55, 56, 512] {
+        let mut hasher = Sm3::new();
+        hasher.update(&data[..split]);
+        let snapshot = hasher.serialize_state();
+        assert_eq!(snapshot.len(), SM3_SNAPSHOT_LEN);
+
+        let resumed = Sm3::deserialize_state(&snapshot).unwrap();
+        assert_eq!(resumed.serialize_state()[..], snapshot[..]);
+        assert_eq!(resumed.clone().finalize(), Sm3::digest(&data[..split]));
+
+        let mut resumed = resumed;
+        resumed.update(&data[split..]);
+        assert_eq!(resumed.finalize(), Sm3::digest(&data));
+    }
+
+    let snapshot = Sm3::new().serialize_state();
+    assert!(Sm3::deserialize_state(&snapshot[1..]).is_none());
+    let mut bad_pos = snapshot;
+    bad_pos[SM3_SNAPSHOT_LEN - 65] = 64;
+    assert!(Sm3::deserialize_state(&bad_pos).is_none());
+    let mut bad_len = snapshot;
+    bad_len[32..40].copy_from_slice(&(!0u64).to_be_bytes());
+    assert!(Sm3::deserialize_state(&bad_len).is_none());
+}
@@ -33,6 +33,10 @@
 //! ")[..]);
 //! ```
 //!
+//! With the `hmac` feature enabled, the HMAC type aliases
+//! [`HmacStreebog256`] and [`HmacStreebog512`] are available, along with the
+//! RFC 7836 key derivation function in the [`vko`] module.
+//!
 //! See [RustCrypto/hashes][1] readme for additional examples.
 //!
 //! [Streebog]: https://en.wikipedia.org/wiki/Streebog
@@ -53,6 +57,13 @@ mod consts;
 mod streebog;
 mod table;
 
+#[cfg(feature = "hmac")]
+pub use hmac;
+
+#[cfg(feature = "hmac")]
+pub mod vko;
+
+use core::fmt;
 use digest::consts::{U32, U64};
 pub use digest::{self, Digest};
 
@@ -65,6 +76,35 @@ pub type Streebog256 = streebog::Streebog<U32>;
 /// Streebog-512 cryptographic hash function
 pub type Streebog512 = streebog::Streebog<U64>;
 
+impl Streebog256 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "Streebog-256";
+}
+
+impl fmt::Display for Streebog256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
+impl Streebog512 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "Streebog-512";
+}
+
+impl fmt::Display for Streebog512 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
+/// HMAC-Streebog-256 (`HMAC_GOSTR3411_2012_256` in RFC 7836)
+#[cfg(feature = "hmac")]
+pub type HmacStreebog256 = hmac::Hmac<Streebog256>;
+/// HMAC-Streebog-512 (`HMAC_GOSTR3411_2012_512` in RFC 7836)
+#[cfg(feature = "hmac")]
+pub type HmacStreebog512 = hmac::Hmac<Streebog512>;
+
 opaque_debug::implement!(Streebog512);
 opaque_debug::implement!(Streebog256);
 
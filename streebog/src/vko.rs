@@ -0,0 +1,34 @@
+//! Key derivation functions from [RFC 7836][1].
+//!
+//! [`kdf_256`] implements `KDF_GOSTR3411_2012_256` on top of HMAC-Streebog-256.
+//! The VKO key agreement of GOST R 34.10-2012 needs elliptic curve arithmetic
+//! and is left to a crate that provides it.
+//!
+//! ```rust
+//! use streebog::vko::kdf_256;
+//! use hex_literal::hex;
+//!
+//! let key = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+//! let kdf = kdf_256(&key, &hex!("26bdb878"), &hex!("af21434145656378"));
+//! assert_eq!(kdf, hex!("
+//!     a1aa5f7de402d7b3d323f2991c8d4534013137010a83754fd0af6d7cd4922ed9
+//! "));
+//! ```
+//!
+//! [1]: https://tools.ietf.org/html/rfc7836
+use crate::HmacStreebog256;
+use hmac::{Mac, NewMac};
+
+/// `KDF_GOSTR3411_2012_256` (RFC 7836 §4.5):
+/// `HMAC256(key, 0x01 | label | 0x00 | seed | 0x01 | 0x00)`.
+pub fn kdf_256(key: &[u8], label: &[u8], seed: &[u8]) -> [u8; 32] {
+    let mut mac = HmacStreebog256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(&[0x01]);
+    mac.update(label);
+    mac.update(&[0x00]);
+    mac.update(seed);
+    mac.update(&[0x01, 0x00]);
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&mac.finalize().into_bytes());
+    out
+}
@@ -81,3 +81,15 @@ fn gost_engine_tests() {
         "),
     );
 }
+
+#[test]
+fn streebog_alg_name() {
+    assert_eq!(Streebog256::ALG_NAME, "Streebog-256");
+    assert_eq!(Streebog512::ALG_NAME, "Streebog-512");
+}
+
+#[test]
+fn streebog_display() {
+    assert_eq!(format!("{}", Streebog256::default()), "Streebog-256");
+    assert_eq!(format!("{}", Streebog512::default()), "Streebog-512");
+}
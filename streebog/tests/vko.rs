@@ -0,0 +1,43 @@
+#![cfg(feature = "hmac")]
+
+use hex_literal::hex;
+use streebog::hmac::{Mac, NewMac};
+use streebog::vko::kdf_256;
+use streebog::{HmacStreebog256, HmacStreebog512};
+
+const KEY: [u8; 32] = hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+const DATA: [u8; 16] = hex!("0126bdb87800af214341456563780100");
+
+/// RFC 7836 Appendix A.1.1
+#[test]
+fn hmac_streebog256() {
+    let mut mac = HmacStreebog256::new_from_slice(&KEY).unwrap();
+    mac.update(&DATA);
+    assert_eq!(
+        mac.finalize().into_bytes()[..],
+        hex!("a1aa5f7de402d7b3d323f2991c8d4534013137010a83754fd0af6d7cd4922ed9")[..]
+    );
+}
+
+/// RFC 7836 Appendix A.1.2
+#[test]
+fn hmac_streebog512() {
+    let mut mac = HmacStreebog512::new_from_slice(&KEY).unwrap();
+    mac.update(&DATA);
+    assert_eq!(
+        mac.finalize().into_bytes()[..],
+        hex!(
+            "a59bab22ecae19c65fbde6e5f4e9f5d8549d31f037f9df9b905500e171923a77
+            3d5f1530f2ed7e964cb2eedc29e9ad2f3afe93b2814f79f5000ffc0366c251e6"
+        )[..]
+    );
+}
+
+/// RFC 7836 Appendix A.1.3
+#[test]
+fn kdf_streebog256() {
+    assert_eq!(
+        kdf_256(&KEY, &hex!("26bdb878"), &hex!("af21434145656378")),
+        hex!("a1aa5f7de402d7b3d323f2991c8d4534013137010a83754fd0af6d7cd4922ed9")
+    );
+}
@@ -12,22 +12,41 @@
 #![warn(missing_docs, rust_2018_idioms)]
 
 pub use digest;
+#[cfg(feature = "zeroize")]
+pub use zeroize_crate as zeroize;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod tth;
+
+pub use tth::TigerTreeHash;
 
 use block_buffer::{
-    generic_array::typenum::consts::U64 as BB_U64, generic_array::GenericArray as BB_GenericArray,
-    BlockBuffer,
+    block_padding::ZeroPadding, generic_array::typenum::consts::U64 as BB_U64,
+    generic_array::GenericArray as BB_GenericArray, BlockBuffer,
 };
 use byteorder::{ByteOrder, LE};
-use digest::generic_array::typenum::{U24, U64};
+use core::fmt;
+use digest::generic_array::typenum::{U16, U20, U24, U64};
 use digest::generic_array::GenericArray;
 
 type BlockBuffer512 = BlockBuffer<BB_U64>;
 
+/// Size in bytes of a snapshot created by [`Tiger::serialize_state`] or
+/// [`Tiger2::serialize_state`].
+pub const TIGER_SNAPSHOT_LEN: usize = 8 * 3 + 8 + 1 + 64;
+
 const PADDING_BLOCK: [u8; 64] = [
     1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 ];
 
+const PADDING_BLOCK2: [u8; 64] = [
+    0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0,
+];
+
 const H0: [u64; 3] = [
     0x0123_4567_89AB_CDEF,
     0xFEDC_BA98_7654_3210,
@@ -53,6 +72,52 @@ impl Default for Tiger {
 }
 
 impl Tiger {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "Tiger";
+
+    /// Serialize the hasher state, including input which does not fill a
+    /// complete block yet.
+    ///
+    /// The snapshot consists of the three state words and the number of
+    /// processed blocks as little-endian `u64`s, the number of buffered
+    /// bytes as a single byte and the buffered bytes zero-padded to the
+    /// block size.
+    pub fn serialize_state(&self) -> [u8; TIGER_SNAPSHOT_LEN] {
+        let mut out = [0u8; TIGER_SNAPSHOT_LEN];
+        LE::write_u64_into(&self.state, &mut out[..24]);
+        LE::write_u64(&mut out[24..32], self.length_bytes / 64);
+        out[32] = self.buffer.position() as u8;
+        let mut buffer = self.buffer.clone();
+        // the buffer is never full, so padding can not fail
+        if let Ok(block) = buffer.pad_with::<ZeroPadding>() {
+            out[33..].copy_from_slice(block);
+        }
+        out
+    }
+
+    /// Restore a hasher from a snapshot created by `serialize_state`.
+    ///
+    /// Returns `None` if `bytes` is not [`TIGER_SNAPSHOT_LEN`] bytes long or
+    /// is malformed.
+    pub fn deserialize_state(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != TIGER_SNAPSHOT_LEN {
+            return None;
+        }
+        let pos = bytes[32] as usize;
+        if pos >= 64 {
+            return None;
+        }
+        let length_bytes = LE::read_u64(&bytes[24..32])
+            .checked_mul(64)?
+            .checked_add(pos as u64)?;
+        let mut hasher = Tiger::default();
+        LE::read_u64_into(&bytes[..24], &mut hasher.state);
+        // less than a block, so nothing gets compressed
+        hasher.consume(&bytes[33..33 + pos]);
+        hasher.length_bytes = length_bytes;
+        Some(hasher)
+    }
+
     #[inline]
     fn fill_up_to(from: usize, to: usize, modulo: usize) -> usize {
         let _from = from + 1;
@@ -61,13 +126,12 @@ impl Tiger {
     }
 
     #[inline]
-    fn finalize(&mut self) -> [u64; 3] {
+    fn finalize(&mut self, padding: &[u8; 64]) -> [u64; 3] {
         {
             let self_state = &mut self.state;
             let pad_size = Self::fill_up_to(self.buffer.position(), 64 - 8, 64);
-            self.buffer.input_block(&PADDING_BLOCK[..pad_size], |d| {
-                Self::compress(self_state, d)
-            });
+            self.buffer
+                .input_block(&padding[..pad_size], |d| Self::compress(self_state, d));
 
             let mut length: [u8; 8] = [0, 0, 0, 0, 0, 0, 0, 0];
             LE::write_u64(&mut length, (self.length_bytes << 3) as u64);
@@ -155,6 +219,12 @@ impl Tiger {
     }
 }
 
+impl fmt::Display for Tiger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl digest::BlockInput for Tiger {
     type BlockSize = U64;
 }
@@ -179,14 +249,184 @@ impl digest::FixedOutputDirty for Tiger {
     type OutputSize = U24;
 
     fn finalize_into_dirty(&mut self, out: &mut GenericArray<u8, Self::OutputSize>) {
-        LE::write_u64_into(&self.finalize(), &mut out.as_mut_slice());
+        LE::write_u64_into(&self.finalize(&PADDING_BLOCK), &mut out.as_mut_slice());
+    }
+}
+
+/// Overwrites the chaining value, the length and the buffered input with
+/// zeros.
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::Zeroize for Tiger {
+    fn zeroize(&mut self) {
+        use block_buffer::block_padding::ZeroPadding;
+
+        self.state.zeroize();
+        self.length_bytes.zeroize();
+        // the buffer is never full, so padding can not fail
+        if let Ok(block) = self.buffer.pad_with::<ZeroPadding>() {
+            block.as_mut_slice().zeroize();
+        }
+        self.buffer.reset();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Tiger {
+    fn drop(&mut self) {
+        zeroize_crate::Zeroize::zeroize(self);
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::ZeroizeOnDrop for Tiger {}
+
+/// The Tiger2 hasher
+///
+/// Tiger2 is identical to Tiger except for the padding, which starts with
+/// `0x80` as in MD4 and SHA-1 instead of `0x01`.
+#[derive(Clone, Default)]
+pub struct Tiger2 {
+    inner: Tiger,
+}
+
+impl Tiger2 {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "Tiger2";
+
+    /// Serialize the hasher state in the same format as
+    /// [`Tiger::serialize_state`].
+    pub fn serialize_state(&self) -> [u8; TIGER_SNAPSHOT_LEN] {
+        self.inner.serialize_state()
+    }
+
+    /// Restore a hasher from a snapshot created by `serialize_state`.
+    ///
+    /// Returns `None` if `bytes` is not [`TIGER_SNAPSHOT_LEN`] bytes long or
+    /// is malformed.
+    pub fn deserialize_state(bytes: &[u8]) -> Option<Self> {
+        Some(Tiger2 {
+            inner: Tiger::deserialize_state(bytes)?,
+        })
+    }
+}
+
+impl fmt::Display for Tiger2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
+impl digest::BlockInput for Tiger2 {
+    type BlockSize = U64;
+}
+
+impl digest::Reset for Tiger2 {
+    fn reset(&mut self) {
+        digest::Reset::reset(&mut self.inner);
+    }
+}
+
+impl digest::Update for Tiger2 {
+    #[inline]
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.inner.consume(data.as_ref());
+    }
+}
+
+impl digest::FixedOutputDirty for Tiger2 {
+    type OutputSize = U24;
+
+    fn finalize_into_dirty(&mut self, out: &mut GenericArray<u8, Self::OutputSize>) {
+        LE::write_u64_into(&self.inner.finalize(&PADDING_BLOCK2), out.as_mut_slice());
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::Zeroize for Tiger2 {
+    fn zeroize(&mut self) {
+        self.inner.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize_crate::ZeroizeOnDrop for Tiger2 {}
+
+macro_rules! tiger_truncated {
+    ($name:ident, $output_size:ident, $alg_name:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone, Default)]
+        pub struct $name {
+            inner: Tiger,
+        }
+
+        impl $name {
+            /// Canonical name of the algorithm, e.g. for logging.
+            pub const ALG_NAME: &'static str = $alg_name;
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(Self::ALG_NAME)
+            }
+        }
+
+        impl digest::BlockInput for $name {
+            type BlockSize = U64;
+        }
+
+        impl digest::Reset for $name {
+            fn reset(&mut self) {
+                digest::Reset::reset(&mut self.inner);
+            }
+        }
+
+        impl digest::Update for $name {
+            #[inline]
+            fn update(&mut self, data: impl AsRef<[u8]>) {
+                self.inner.consume(data.as_ref());
+            }
+        }
+
+        impl digest::FixedOutputDirty for $name {
+            type OutputSize = $output_size;
+
+            fn finalize_into_dirty(&mut self, out: &mut GenericArray<u8, Self::OutputSize>) {
+                let mut full = [0u8; 24];
+                LE::write_u64_into(&self.inner.finalize(&PADDING_BLOCK), &mut full);
+                let n = out.len();
+                out.copy_from_slice(&full[..n]);
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize_crate::Zeroize for $name {
+            fn zeroize(&mut self) {
+                self.inner.zeroize();
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize_crate::ZeroizeOnDrop for $name {}
+    };
+}
+
+tiger_truncated!(
+    Tiger128,
+    U16,
+    "Tiger-128",
+    "The Tiger hasher with its output truncated to 128 bits"
+);
+tiger_truncated!(
+    Tiger160,
+    U20,
+    "Tiger-160",
+    "The Tiger hasher with its output truncated to 160 bits"
+);
+
 // Conditionally compile the module `test` only when the test-suite is run.
 #[cfg(test)]
 mod test {
-    use super::Tiger;
+    use super::{Tiger, Tiger128, Tiger160, Tiger2};
     use digest::Digest;
     use hex_literal::hex;
 
@@ -228,6 +468,60 @@ mod test {
             assert_eq!(*result, answer);
         }
     }
+
+    #[test]
+    fn test_vectors_tiger2() {
+        let examples = [
+            ("", hex!("4441be75f6018773c206c22745374b924aa8313fef919f41")),
+            (
+                "abc",
+                hex!("f68d7bc5af4b43a06e048d7829560d4a9415658bb0b1f3bf"),
+            ),
+        ];
+
+        for &(input, answer) in examples.iter() {
+            let result = Tiger2::digest(input.as_bytes());
+
+            assert_eq!(*result, answer);
+        }
+    }
+
+    #[test]
+    fn test_vectors_truncated() {
+        assert_eq!(
+            *Tiger128::digest(b""),
+            hex!("3293ac630c13f0245f92bbb1766e1616")
+        );
+        assert_eq!(
+            *Tiger128::digest(b"abc"),
+            hex!("2aab1484e8c158f2bfb8c5ff41b57a52")
+        );
+        assert_eq!(
+            *Tiger160::digest(b""),
+            hex!("3293ac630c13f0245f92bbb1766e16167a4e5849")
+        );
+        assert_eq!(
+            *Tiger160::digest(b"abc"),
+            hex!("2aab1484e8c158f2bfb8c5ff41b57a525129131c")
+        );
+    }
+
+    #[test]
+    fn test_alg_name() {
+        assert_eq!(Tiger::ALG_NAME, "Tiger");
+        assert_eq!(Tiger2::ALG_NAME, "Tiger2");
+        assert_eq!(Tiger128::ALG_NAME, "Tiger-128");
+        assert_eq!(Tiger160::ALG_NAME, "Tiger-160");
+    }
+
+    #[test]
+    fn test_display() {
+        extern crate std;
+        use std::format;
+
+        assert_eq!(format!("{}", Tiger::default()), "Tiger");
+        assert_eq!(format!("{}", Tiger2::default()), "Tiger2");
+    }
 }
 
 static T1: [u64; 256] = [
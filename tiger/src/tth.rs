@@ -0,0 +1,124 @@
+//! Tiger Tree Hash (TTH) as used by DC++ and other file sharing clients.
+//!
+//! The input is split into 1024-byte leaves, the last of which may be
+//! shorter. Leaves are hashed as `Tiger(0x00 || leaf)` and pairs of child
+//! digests as `Tiger(0x01 || left || right)`. A node without a right sibling
+//! is promoted to the next level unchanged. The root of an empty input is
+//! the hash of a single empty leaf.
+use crate::Tiger;
+use core::fmt;
+use digest::generic_array::typenum::{U1024, U24};
+use digest::generic_array::GenericArray;
+use digest::{BlockInput, Digest, FixedOutputDirty, Reset, Update};
+
+const LEAF_SIZE: usize = 1024;
+
+type Node = [u8; 24];
+
+/// Tiger Tree Hash over 1024-byte leaves, producing the 24-byte root.
+#[derive(Clone)]
+pub struct TigerTreeHash {
+    leaf: Tiger,
+    leaf_len: usize,
+    /// Number of leaves hashed so far.
+    leaves: u64,
+    /// Root of a complete subtree for every bit set in `leaves`, indexed by
+    /// level.
+    stack: [Node; 64],
+}
+
+impl TigerTreeHash {
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "TTH";
+
+    fn new_leaf() -> Tiger {
+        let mut leaf = Tiger::default();
+        Update::update(&mut leaf, [0u8]);
+        leaf
+    }
+
+    fn node(left: &Node, right: &Node) -> Node {
+        let mut h = Tiger::default();
+        Update::update(&mut h, [1u8]);
+        Update::update(&mut h, left);
+        Update::update(&mut h, right);
+        h.finalize().into()
+    }
+
+    fn push_leaf(&mut self) {
+        let mut node = core::mem::replace(&mut self.leaf, Self::new_leaf())
+            .finalize()
+            .into();
+        self.leaf_len = 0;
+        let mut level = 0;
+        while self.leaves >> level & 1 == 1 {
+            node = Self::node(&self.stack[level], &node);
+            level += 1;
+        }
+        self.stack[level] = node;
+        self.leaves += 1;
+    }
+}
+
+impl Default for TigerTreeHash {
+    fn default() -> Self {
+        TigerTreeHash {
+            leaf: Self::new_leaf(),
+            leaf_len: 0,
+            leaves: 0,
+            stack: [[0; 24]; 64],
+        }
+    }
+}
+
+impl fmt::Display for TigerTreeHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
+impl BlockInput for TigerTreeHash {
+    type BlockSize = U1024;
+}
+
+impl Update for TigerTreeHash {
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        let mut data = data.as_ref();
+        while !data.is_empty() {
+            // a full leaf is only hashed once more input arrives, so that
+            // the last leaf is always pending at finalization
+            if self.leaf_len == LEAF_SIZE {
+                self.push_leaf();
+            }
+            let n = core::cmp::min(LEAF_SIZE - self.leaf_len, data.len());
+            Update::update(&mut self.leaf, &data[..n]);
+            self.leaf_len += n;
+            data = &data[n..];
+        }
+    }
+}
+
+impl FixedOutputDirty for TigerTreeHash {
+    type OutputSize = U24;
+
+    fn finalize_into_dirty(&mut self, out: &mut GenericArray<u8, U24>) {
+        self.push_leaf();
+        let mut root: Option<Node> = None;
+        for (level, node) in self.stack.iter().enumerate() {
+            if self.leaves >> level & 1 == 1 {
+                root = Some(match root {
+                    Some(right) => Self::node(node, &right),
+                    None => *node,
+                });
+            }
+        }
+        // at least one leaf has been pushed above
+        out.copy_from_slice(&root.unwrap());
+    }
+}
+
+impl Reset for TigerTreeHash {
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
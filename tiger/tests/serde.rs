@@ -0,0 +1,52 @@
+#![cfg(feature = "serde")]
+
+use digest::{Digest, Update};
+use tiger::{Tiger, Tiger2};
+
+fn roundtrip<D>()
+where
+    D: Digest + Update + Default + serde::Serialize + serde::de::DeserializeOwned,
+{
+    let data = [0x5au8; 192];
+    // on a block boundary and with part of a block buffered
+    for &split in [128, 100].iter() {
+        let mut h = D::default();
+        Update::update(&mut h, &data[..split]);
+        let encoded = bincode::serialize(&h).unwrap();
+
+        let mut h: D = bincode::deserialize(&encoded).unwrap();
+        Update::update(&mut h, &data[split..]);
+        assert_eq!(h.finalize(), D::digest(&data));
+    }
+}
+
+#[test]
+fn tiger_roundtrip() {
+    roundtrip::<Tiger>();
+}
+
+#[test]
+fn tiger2_roundtrip() {
+    roundtrip::<Tiger2>();
+}
+
+#[test]
+fn reject_truncated() {
+    let mut h = Tiger::default();
+    Update::update(&mut h, &[0u8; 64][..]);
+    let encoded = bincode::serialize(&h).unwrap();
+    let res: Result<Tiger, _> = bincode::deserialize(&encoded[..encoded.len() - 1]);
+    assert!(res.is_err());
+}
+
+#[test]
+fn reject_malformed() {
+    let mut h = Tiger::default();
+    Update::update(&mut h, b"abc");
+    let mut encoded = bincode::serialize(&h).unwrap();
+    // buffer position past the end of the block
+    let pos = encoded.len() - 65;
+    encoded[pos] = 64;
+    let res: Result<Tiger, _> = bincode::deserialize(&encoded);
+    assert!(res.is_err());
+}
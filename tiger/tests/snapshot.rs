@@ -0,0 +1,30 @@
+use digest::Digest;
+use tiger::{Tiger, Tiger2, TIGER_SNAPSHOT_LEN};
+
+macro_rules! snapshot_test {
+    ($name:ident, $hasher:ty) => {
+        #[test]
+        fn $name() {
+            let data = [0x5au8; 200];
+            for &split in &[0, 63, 64, 127] {
+                let mut hasher = <$hasher>::new();
+                hasher.update(&data[..split]);
+                let snapshot = hasher.serialize_state();
+                assert_eq!(snapshot.len(), TIGER_SNAPSHOT_LEN);
+
+                let mut resumed = <$hasher>::deserialize_state(&snapshot).unwrap();
+                resumed.update(&data[split..]);
+                assert_eq!(resumed.finalize(), <$hasher>::digest(&data));
+            }
+
+            let snapshot = <$hasher>::new().serialize_state();
+            assert!(<$hasher>::deserialize_state(&snapshot[1..]).is_none());
+            let mut bad_pos = snapshot;
+            bad_pos[TIGER_SNAPSHOT_LEN - 64 - 1] = 64;
+            assert!(<$hasher>::deserialize_state(&bad_pos).is_none());
+        }
+    };
+}
+
+snapshot_test!(tiger_serialize_state, Tiger);
+snapshot_test!(tiger2_serialize_state, Tiger2);
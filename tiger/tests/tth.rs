@@ -0,0 +1,38 @@
+use digest::Digest;
+use hex_literal::hex;
+use tiger::{Tiger, TigerTreeHash};
+
+#[test]
+fn tth_vectors() {
+    // Vectors from the THEX specification, given there in base32
+    assert_eq!(
+        TigerTreeHash::digest(b"")[..],
+        hex!("5d9ed00a030e638bdb753a6a24fb900e5a63b8e73e6c25b6")
+    );
+    assert_eq!(
+        TigerTreeHash::digest(&[b'A'; 1024][..])[..],
+        hex!("5fbd0e62ad016d596b77d1d28883b94fed78ecbaf4640914")
+    );
+    assert_eq!(
+        TigerTreeHash::digest(&[b'A'; 1025][..])[..],
+        hex!("7e591c1cd8f2e6121fdbcd8071ba279626b771642d10a3db")
+    );
+}
+
+#[test]
+fn tth_promotes_unpaired_node() {
+    let data = [0x5au8; 2 * 1024 + 100];
+    let leaf = |data: &[u8]| Tiger::new().chain([0u8]).chain(data).finalize();
+    let node = |l: &[u8], r: &[u8]| Tiger::new().chain([1u8]).chain(l).chain(r).finalize();
+
+    let left = node(&leaf(&data[..1024]), &leaf(&data[1024..2048]));
+    let expected = node(&left, &leaf(&data[2048..]));
+    assert_eq!(TigerTreeHash::digest(&data[..]), expected);
+
+    let mut h = TigerTreeHash::new();
+    for chunk in data.chunks(333) {
+        h.update(chunk);
+    }
+    assert_eq!(h.finalize_reset(), expected);
+    assert_eq!(h.finalize(), TigerTreeHash::digest(b""));
+}
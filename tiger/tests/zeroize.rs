@@ -0,0 +1,24 @@
+#![cfg(feature = "zeroize")]
+
+use tiger::digest::Digest;
+use tiger::zeroize::Zeroize;
+use tiger::{Tiger, Tiger2, TIGER_SNAPSHOT_LEN};
+
+/// A whole block and part of the next one.
+const INPUT: [u8; 100] = [0x5a; 100];
+
+#[test]
+fn tiger_zeroize() {
+    let mut hasher = Tiger::new();
+    hasher.update(&INPUT[..]);
+    hasher.zeroize();
+    assert_eq!(hasher.serialize_state()[..], [0u8; TIGER_SNAPSHOT_LEN][..]);
+}
+
+#[test]
+fn tiger2_zeroize() {
+    let mut hasher = Tiger2::new();
+    hasher.update(&INPUT[..]);
+    hasher.zeroize();
+    assert_eq!(hasher.serialize_state()[..], [0u8; TIGER_SNAPSHOT_LEN][..]);
+}
@@ -60,6 +60,7 @@ pub use digest::Digest;
 use crate::utils::compress;
 
 use block_buffer::{block_padding::Iso7816, BlockBuffer};
+use core::fmt;
 use digest::{consts::U64, generic_array::GenericArray};
 use digest::{BlockInput, FixedOutputDirty, Reset, Update};
 
@@ -101,6 +102,10 @@ impl Whirlpool {
         feature = "cargo-clippy",
         allow(clippy::identity_op, clippy::double_parens)
     )]
+
+    /// Canonical name of the algorithm, e.g. for logging.
+    pub const ALG_NAME: &'static str = "Whirlpool";
+
     fn update_len(&mut self, len: u64) {
         let len_bits = [
             (len >> (56 + 5)) as u8,
@@ -153,6 +158,12 @@ impl Whirlpool {
     }
 }
 
+impl fmt::Display for Whirlpool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(Self::ALG_NAME)
+    }
+}
+
 impl BlockInput for Whirlpool {
     type BlockSize = BlockSize;
 }
@@ -15,3 +15,16 @@ fn whirlpool_1million_a() {
     let output = include_bytes!("data/one_million_a.bin");
     one_million_a::<whirlpool::Whirlpool>(output);
 }
+
+#[test]
+fn whirlpool_alg_name() {
+    assert_eq!(whirlpool::Whirlpool::ALG_NAME, "Whirlpool");
+}
+
+#[test]
+fn whirlpool_display() {
+    extern crate std;
+    use std::format;
+
+    assert_eq!(format!("{}", whirlpool::Whirlpool::default()), "Whirlpool");
+}